@@ -11,11 +11,11 @@ fn bench_clones(c: &mut Criterion) {
         ("a"),
         ("acb"),
         ("abcdefg"),
-        (core::str::from_utf8(&['a' as u8; 15]).unwrap()),
-        (core::str::from_utf8(&['b' as u8; 31]).unwrap()),
-        (core::str::from_utf8(&['c' as u8; 63]).unwrap()),
-        (core::str::from_utf8(&['d' as u8; 127]).unwrap()),
-        (core::str::from_utf8(&['e' as u8; 255]).unwrap()),
+        (core::str::from_utf8(&[b'a'; 15]).unwrap()),
+        (core::str::from_utf8(&[b'b'; 31]).unwrap()),
+        (core::str::from_utf8(&[b'c'; 63]).unwrap()),
+        (core::str::from_utf8(&[b'd'; 127]).unwrap()),
+        (core::str::from_utf8(&[b'e'; 255]).unwrap()),
     ];
     let mut group = c.benchmark_group("clone");
     // for ns time ops one second is plenty
@@ -60,11 +60,11 @@ fn bench_try_from(c: &mut Criterion) {
         ("a"),
         ("acb"),
         ("abcdefg"),
-        (core::str::from_utf8(&['a' as u8; 15]).unwrap()),
-        (core::str::from_utf8(&['b' as u8; 31]).unwrap()),
-        (core::str::from_utf8(&['c' as u8; 63]).unwrap()),
-        (core::str::from_utf8(&['d' as u8; 127]).unwrap()),
-        (core::str::from_utf8(&['e' as u8; 255]).unwrap()),
+        (core::str::from_utf8(&[b'a'; 15]).unwrap()),
+        (core::str::from_utf8(&[b'b'; 31]).unwrap()),
+        (core::str::from_utf8(&[b'c'; 63]).unwrap()),
+        (core::str::from_utf8(&[b'd'; 127]).unwrap()),
+        (core::str::from_utf8(&[b'e'; 255]).unwrap()),
     ];
     let mut group = c.benchmark_group("try_from");
     // for ns time ops 0.1 second is plenty
@@ -109,10 +109,10 @@ fn bench_push(c: &mut Criterion) {
         ("a"),
         ("acb"),
         ("abcdefg"),
-        (core::str::from_utf8(&['b' as u8; 31]).unwrap()),
-        (core::str::from_utf8(&['c' as u8; 63]).unwrap()),
-        (core::str::from_utf8(&['d' as u8; 127]).unwrap()),
-        (core::str::from_utf8(&['e' as u8; 255]).unwrap()),
+        (core::str::from_utf8(&[b'b'; 31]).unwrap()),
+        (core::str::from_utf8(&[b'c'; 63]).unwrap()),
+        (core::str::from_utf8(&[b'd'; 127]).unwrap()),
+        (core::str::from_utf8(&[b'e'; 255]).unwrap()),
     ];
     let mut group = c.benchmark_group("push_str");
     // for ns time ops 0.1 second is plenty
@@ -164,6 +164,110 @@ fn bench_push(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(string, bench_clones, bench_try_from, bench_push);
+fn bench_eq_cmp(c: &mut Criterion) {
+    let params = [
+        ("a", "b"),
+        ("abcdefg", "abcdefh"),
+        (
+            core::str::from_utf8(&[b'c'; 31]).unwrap(),
+            core::str::from_utf8(&[b'c'; 31]).unwrap(),
+        ),
+        (
+            core::str::from_utf8(&[b'c'; 63]).unwrap(),
+            core::str::from_utf8(&[b'c'; 63]).unwrap(),
+        ),
+    ];
+    let mut group = c.benchmark_group("eq_cmp");
+    // for ns time ops 0.1 second is plenty
+    group.measurement_time(Duration::from_millis(TIME));
+    group.warm_up_time(Duration::from_millis(TIME));
+    for (left, right) in params {
+        macro_rules! build {
+            ($($str:path),*$(,)*) => {
+                $(
+                    let pair = (<$str>::try_from(left).unwrap(), <$str>::try_from(right).unwrap());
+                    group.bench_with_input(
+                        BenchmarkId::new(concat!(stringify!($str), "::eq"), left.len()),
+                        &pair,
+                        |b, (l, r)| b.iter(|| criterion::black_box(l) == criterion::black_box(r)),
+                    );
+                    group.bench_with_input(
+                        BenchmarkId::new(concat!(stringify!($str), "::cmp"), left.len()),
+                        &pair,
+                        |b, (l, r)| b.iter(|| criterion::black_box(l).cmp(criterion::black_box(r))),
+                    );
+                    // Variable length comparison of the string slices, like before the zeroed tail
+                    group.bench_with_input(
+                        BenchmarkId::new(concat!(stringify!($str), "::as_str::eq"), left.len()),
+                        &pair,
+                        |b, (l, r)| b.iter(|| criterion::black_box(l).as_str() == criterion::black_box(r).as_str()),
+                    );
+                    group.bench_with_input(
+                        BenchmarkId::new(concat!(stringify!($str), "::as_str::cmp"), left.len()),
+                        &pair,
+                        |b, (l, r)| b.iter(|| criterion::black_box(l).as_str().cmp(criterion::black_box(r).as_str())),
+                    );
+                )*
+            };
+        }
+        build!(
+            std::string::String,
+            arrayvec::ArrayString<63>,
+            ArrayString<63>,
+            CacheString,
+        );
+    }
+    group.finish();
+}
+
+fn bench_hash(c: &mut Criterion) {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(value: &impl Hash) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let params = [
+        ("a"),
+        ("abcdefg"),
+        (core::str::from_utf8(&[b'c'; 31]).unwrap()),
+        (core::str::from_utf8(&[b'c'; 63]).unwrap()),
+    ];
+    let mut group = c.benchmark_group("hash");
+    // for ns time ops 0.1 second is plenty
+    group.measurement_time(Duration::from_millis(TIME));
+    group.warm_up_time(Duration::from_millis(TIME));
+    for param in params {
+        let string = CacheString::try_from_str(param).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("String", param.len()),
+            &param.to_owned(),
+            |b, p| b.iter(|| hash(criterion::black_box(p))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("CacheString", param.len()),
+            &string,
+            |b, p| b.iter(|| hash(criterion::black_box(p))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("FastHash<CacheString>", param.len()),
+            &FastHash(string),
+            |b, p| b.iter(|| hash(criterion::black_box(p))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    string,
+    bench_clones,
+    bench_try_from,
+    bench_push,
+    bench_eq_cmp,
+    bench_hash
+);
 
 criterion_main!(string);
@@ -0,0 +1,667 @@
+//! Derive macro for newtypes around `arraystring::ArrayString`
+//!
+//! Use it through the `arraystring` re-export, the generated code refers to the `arraystring` crate:
+//!
+//! ```rust,ignore
+//! use arraystring::{ArrayString, ArrayStringNewtype};
+//!
+//! #[derive(Copy, Clone, Default, ArrayStringNewtype)]
+//! pub struct Email(ArrayString<64>);
+//! ```
+//!
+//! Attributes:
+//!
+//! - `#[arraystring(validator = Type)]` runs the `arraystring::validated::Validator` on construction, decoding and every mutation, mutable access to the inner string isn't generated
+//! - `#[arraystring(align = 64)]` checks at compile time that the struct has that alignment (add `#[repr(align(64))]` to it)
+//! - `#[arraystring(crate = "path")]` changes the path of the `arraystring` crate, for renamed dependencies
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, GenericArgument};
+use syn::{Ident, LitInt, LitStr, Path, PathArguments, Type};
+
+/// Parsed `#[arraystring(...)]` attributes
+#[derive(Default)]
+struct Options {
+    krate: Option<Path>,
+    validator: Option<Type>,
+    align: Option<LitInt>,
+}
+
+/// Generates the full forwarding surface of `CacheString` for a single field tuple struct around `ArrayString<N>`
+#[proc_macro_derive(ArrayStringNewtype, attributes(arraystring))]
+pub fn derive_array_string_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "ArrayStringNewtype doesn't support generic structs",
+        ));
+    }
+    let inner = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => None.ok_or_else(|| {
+                Error::new_spanned(
+                    &input.ident,
+                    "ArrayStringNewtype requires a tuple struct with a single ArrayString<N> field",
+                )
+            })?,
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "ArrayStringNewtype can only be derived for structs",
+            ))
+        }
+    };
+    let capacity = capacity(inner)?;
+    let options = options(input)?;
+    let krate = options
+        .krate
+        .unwrap_or_else(|| syn::parse_quote!(::arraystring));
+    let name = &input.ident;
+
+    let common = common(name, inner, &capacity);
+    let (surface, mode) = match &options.validator {
+        Some(validator) => (
+            validated(&krate, name, inner, &capacity, validator),
+            "validated",
+        ),
+        None => (plain(&krate, name, inner, &capacity), "plain"),
+    };
+    let mode = format_ident!("{}", mode);
+    let align = options.align.map(|align| {
+        let message = format!("{name} must be #[repr(align({align}))]");
+        quote! {
+            const _: () = ::core::assert!(::core::mem::align_of::<#name>() == #align, #message);
+        }
+    });
+    // Impls that depend on `arraystring`'s features are generated by its hidden macros
+    let integrations = [
+        "lossy",
+        "alloc_try_from",
+        "cmp",
+        "index",
+        "serde",
+        "diesel",
+        "arbitrary",
+        "proptest",
+        "quickcheck",
+        "schemars",
+        "defmt",
+        "ufmt",
+        "zeroize",
+        "subtle",
+        "unicode_segmentation",
+        "unicode_width",
+    ]
+    .map(|integration| {
+        let mac = format_ident!("__newtype_{}", integration);
+        quote! { #krate::#mac!(#mode, #name, #inner); }
+    });
+
+    Ok(quote! {
+        #common
+        #surface
+        #align
+        #(#integrations)*
+    })
+}
+
+/// Extracts `N` from `ArrayString<N>`
+fn capacity(inner: &Type) -> syn::Result<TokenStream2> {
+    let error = || Error::new_spanned(inner, "ArrayStringNewtype field must be ArrayString<N>");
+    let Type::Path(path) = inner else {
+        return Err(error());
+    };
+    let segment = path.path.segments.last().ok_or_else(error)?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return Err(error());
+    };
+    match args.args.first() {
+        Some(GenericArgument::Const(expr)) => Ok(quote! { #expr }),
+        // A named constant is parsed as a type
+        Some(GenericArgument::Type(ty @ Type::Path(_))) => Ok(quote! { #ty }),
+        _ => Err(error()),
+    }
+}
+
+fn options(input: &DeriveInput) -> syn::Result<Options> {
+    let mut options = Options::default();
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("arraystring"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                let path: LitStr = meta.value()?.parse()?;
+                options.krate = Some(path.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("validator") {
+                options.validator = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("align") {
+                options.align = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `validator = Type`, `align = N` or `crate = \"path\"`"))
+            }
+        })?;
+    }
+    Ok(options)
+}
+
+/// Read-only access, shared by plain and validated newtypes
+fn common(name: &Ident, inner: &Type, capacity: &TokenStream2) -> TokenStream2 {
+    let name_str = name.to_string();
+    let capacity_doc =
+        format!("Returns maximum `{name}` capacity, defined at compile time, it will never change");
+    quote! {
+        impl #name {
+            #[doc = #capacity_doc]
+            #[inline]
+            pub const fn capacity() -> usize {
+                #capacity
+            }
+        }
+
+        impl ::core::fmt::Debug for #name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.debug_tuple(#name_str).field(&self.0).finish()
+            }
+        }
+
+        impl ::core::fmt::Display for #name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::hash::Hash for #name {
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, hasher: &mut H) {
+                ::core::hash::Hash::hash(&self.0, hasher);
+            }
+        }
+
+        impl ::core::cmp::PartialEq for #name {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl ::core::cmp::Eq for #name {}
+
+        impl ::core::cmp::Ord for #name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.0, &other.0)
+            }
+        }
+
+        impl ::core::cmp::PartialOrd for #name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl ::core::ops::Deref for #name {
+            type Target = #inner;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #name {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                self.0.as_str()
+            }
+        }
+
+        impl ::core::convert::AsRef<[u8]> for #name {
+            #[inline]
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_bytes()
+            }
+        }
+
+        impl ::core::borrow::Borrow<str> for #name {
+            #[inline]
+            fn borrow(&self) -> &str {
+                self.0.as_str()
+            }
+        }
+
+        impl ::core::iter::IntoIterator for #name {
+            type Item = char;
+            type IntoIter = <#inner as ::core::iter::IntoIterator>::IntoIter;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(self.0)
+            }
+        }
+
+        impl<'a> ::core::iter::IntoIterator for &'a #name {
+            type Item = char;
+            type IntoIter = <&'a #inner as ::core::iter::IntoIterator>::IntoIter;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(&self.0)
+            }
+        }
+
+    }
+}
+
+/// Forwards every constructor and mutable access
+fn plain(krate: &Path, name: &Ident, inner: &Type, capacity: &TokenStream2) -> TokenStream2 {
+    let doc = |text: &str| text.replace("{name}", &name.to_string());
+    let new = doc("Creates new empty `{name}`.");
+    let try_from_str = doc("Creates new `{name}` from string slice if length is lower or equal to capacity, otherwise returns an error.");
+    let from_str_truncate =
+        doc("Creates new `{name}` from string slice truncating size if bigger than capacity.");
+    let try_from_iterator = doc("Creates new `{name}` from string slice iterator if total length is lower or equal to capacity, otherwise returns an error.\n\nThe error hands back the string built so far and the items that weren't consumed, starting with the one that didn't fit");
+    let from_iterator_truncate = doc(
+        "Creates new `{name}` from string slice iterator truncating size if bigger than capacity.",
+    );
+    let try_from_chars = doc("Creates new `{name}` from char iterator if total length is lower or equal to capacity, otherwise returns an error.\n\nThe error hands back the string built so far and the chars that weren't consumed, starting with the one that didn't fit");
+    let from_chars_truncate =
+        doc("Creates new `{name}` from char iterator truncating size if bigger than capacity.");
+    let try_from_utf16 = doc("Creates new `{name}` from `u16` slice, returning `Utf16` on invalid utf-16 data or `OutOfBounds` if bigger than capacity");
+    let from_utf16_truncate = doc("Creates new `{name}` from `u16` slice, returning `Utf16` on invalid utf-16 data, truncating if bigger than capacity.");
+    let from_utf16_lossy_truncate = doc("Creates new `{name}` from `u16` slice, replacing invalid utf-16 data with `REPLACEMENT_CHARACTER` (\\u{FFFD}) and truncating size if bigger than capacity");
+    let split_off = doc("Splits `{name}` in two if `at` is smaller than `self.len()`.\n\nReturns `Utf8` if `at` does not lie at a valid utf-8 char boundary and `Index` if it's out of bounds");
+    quote! {
+        impl #name {
+            #[doc = #new]
+            #[inline]
+            pub const fn new() -> Self {
+                Self(<#inner>::new())
+            }
+
+            #[doc = #try_from_str]
+            #[inline]
+            pub fn try_from_str(
+                string: impl ::core::convert::AsRef<str>,
+            ) -> ::core::result::Result<Self, #krate::error::OutOfBounds> {
+                ::core::result::Result::Ok(Self(<#inner>::try_from_str(string)?))
+            }
+
+            #[doc = #from_str_truncate]
+            #[inline]
+            pub fn from_str_truncate(string: impl ::core::convert::AsRef<str>) -> Self {
+                Self(<#inner>::from_str_truncate(string))
+            }
+
+            #[doc = #try_from_iterator]
+            #[inline]
+            pub fn try_from_iterator<I>(
+                iter: I,
+            ) -> ::core::result::Result<
+                Self,
+                #krate::error::FromIterError<Self, I::IntoIter>,
+            >
+            where
+                I: ::core::iter::IntoIterator,
+                I::Item: ::core::convert::AsRef<str>,
+            {
+                <#inner>::try_from_iterator(iter)
+                    .map(Self)
+                    .map_err(|err| err.map(|(string, rest)| (Self(string), rest)))
+            }
+
+            #[doc = #from_iterator_truncate]
+            #[inline]
+            pub fn from_iterator_truncate(
+                iter: impl ::core::iter::IntoIterator<Item = impl ::core::convert::AsRef<str>>,
+            ) -> Self {
+                Self(<#inner>::from_iterator_truncate(iter))
+            }
+
+            #[doc = #try_from_chars]
+            #[inline]
+            pub fn try_from_chars<I>(
+                iter: I,
+            ) -> ::core::result::Result<
+                Self,
+                #krate::error::FromIterError<Self, I::IntoIter>,
+            >
+            where
+                I: ::core::iter::IntoIterator<Item = char>,
+            {
+                <#inner>::try_from_chars(iter)
+                    .map(Self)
+                    .map_err(|err| err.map(|(string, rest)| (Self(string), rest)))
+            }
+
+            #[doc = #from_chars_truncate]
+            #[inline]
+            pub fn from_chars_truncate(iter: impl ::core::iter::IntoIterator<Item = char>) -> Self {
+                Self(<#inner>::from_chars_truncate(iter))
+            }
+
+            #[doc = #try_from_utf16]
+            #[inline]
+            pub fn try_from_utf16(
+                slice: impl ::core::convert::AsRef<[u16]>,
+            ) -> ::core::result::Result<Self, #krate::Error> {
+                ::core::result::Result::Ok(Self(<#inner>::try_from_utf16(slice)?))
+            }
+
+            #[doc = #from_utf16_truncate]
+            #[inline]
+            pub fn from_utf16_truncate(
+                slice: impl ::core::convert::AsRef<[u16]>,
+            ) -> ::core::result::Result<Self, #krate::error::Utf16> {
+                ::core::result::Result::Ok(Self(<#inner>::from_utf16_truncate(slice)?))
+            }
+
+            #[doc = #from_utf16_lossy_truncate]
+            #[inline]
+            pub fn from_utf16_lossy_truncate(slice: impl ::core::convert::AsRef<[u16]>) -> Self {
+                Self(<#inner>::from_utf16_lossy_truncate(slice))
+            }
+
+            #[doc = #split_off]
+            #[inline]
+            pub fn split_off(&mut self, at: usize) -> ::core::result::Result<Self, #krate::Error> {
+                ::core::result::Result::Ok(Self(self.0.split_off(at)?))
+            }
+        }
+
+        impl ::core::ops::DerefMut for #name {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut #inner {
+                &mut self.0
+            }
+        }
+
+        impl<'a> ::core::iter::IntoIterator for &'a mut #name {
+            type Item = char;
+            type IntoIter = <&'a mut #inner as ::core::iter::IntoIterator>::IntoIter;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(&mut self.0)
+            }
+        }
+
+        impl ::core::convert::AsMut<str> for #name {
+            #[inline]
+            fn as_mut(&mut self) -> &mut str {
+                self.0.as_mut_str()
+            }
+        }
+
+        impl ::core::str::FromStr for #name {
+            type Err = #krate::error::OutOfBounds;
+
+            #[inline]
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::try_from_str(s)
+            }
+        }
+
+        impl ::core::borrow::BorrowMut<str> for #name {
+            #[inline]
+            fn borrow_mut(&mut self) -> &mut str {
+                self.0.as_mut_str()
+            }
+        }
+
+        impl ::core::fmt::Write for #name {
+            #[inline]
+            fn write_str(&mut self, slice: &str) -> ::core::fmt::Result {
+                ::core::fmt::Write::write_str(&mut self.0, slice)
+            }
+        }
+
+        /// Widens smaller strings, a capacity bigger than the newtype's fails to compile
+        impl<const M: usize> ::core::convert::From<#krate::ArrayString<M>> for #name
+        where
+            #krate::ArrayString<M>: #krate::__private::ValidCapacity,
+        {
+            #[inline]
+            fn from(string: #krate::ArrayString<M>) -> Self {
+                // Without the turbofish the `where` clause makes inference pick `M`
+                Self(string.resize::<{ #capacity }>())
+            }
+        }
+
+        /// Widens into bigger strings, a capacity smaller than the newtype's fails to compile
+        impl<const M: usize> ::core::convert::From<#name> for #krate::ArrayString<M>
+        where
+            #krate::ArrayString<M>: #krate::__private::ValidCapacity,
+        {
+            #[inline]
+            fn from(string: #name) -> Self {
+                string.0.resize::<M>()
+            }
+        }
+
+        /// Returns `Error::Utf8` on invalid utf-8 data and `Error::OutOfBounds` if it doesn't fit
+        impl ::core::convert::TryFrom<&[u8]> for #name {
+            type Error = #krate::Error;
+
+            #[inline]
+            fn try_from(bytes: &[u8]) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self(<#inner>::try_from(bytes)?))
+            }
+        }
+
+        /// Returns `Error::Utf16` on invalid utf-16 data and `Error::OutOfBounds` if it doesn't fit
+        impl ::core::convert::TryFrom<&[u16]> for #name {
+            type Error = #krate::Error;
+
+            #[inline]
+            fn try_from(slice: &[u16]) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self(<#inner>::try_from(slice)?))
+            }
+        }
+
+        impl ::core::convert::TryFrom<char> for #name {
+            type Error = #krate::error::CapacityError<char>;
+
+            #[inline]
+            fn try_from(ch: char) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self(<#inner>::try_from(ch)?))
+            }
+        }
+
+    }
+}
+
+/// Constructors that validate and mutations applied to a copy, that replaces the string only if it's still valid
+fn validated(
+    krate: &Path,
+    name: &Ident,
+    inner: &Type,
+    capacity: &TokenStream2,
+    validator: &Type,
+) -> TokenStream2 {
+    let doc = |text: &str| text.replace("{name}", &name.to_string());
+    let result = quote! { ::core::result::Result };
+    let error = quote! { #krate::Error };
+    let mutations = [
+        (
+            "try_push_str",
+            "Pushes string slice to the end of the `{name}` if it fits and the result is valid, otherwise returns an error.",
+            quote! { string: impl ::core::convert::AsRef<str> },
+            quote! { () },
+            quote! { inner.try_push_str(string) },
+        ),
+        (
+            "try_push",
+            "Pushes character to the end of the `{name}` if it fits and the result is valid, otherwise returns an error.",
+            quote! { ch: char },
+            quote! { () },
+            quote! { inner.try_push(ch) },
+        ),
+        (
+            "try_insert_str",
+            "Inserts string slice at specified index if it fits and the result is valid, otherwise returns an error.",
+            quote! { idx: usize, string: impl ::core::convert::AsRef<str> },
+            quote! { () },
+            quote! { inner.try_insert_str(idx, string) },
+        ),
+        (
+            "replace_range",
+            "Replaces range with string slice if it fits and the result is valid, otherwise returns an error.",
+            quote! { range: impl ::core::ops::RangeBounds<usize>, with: impl ::core::convert::AsRef<str> },
+            quote! { () },
+            quote! { inner.replace_range(range, with) },
+        ),
+        (
+            "truncate",
+            "Truncates `{name}` to specified size if it's a valid utf-8 char index and the result is valid, otherwise returns an error.",
+            quote! { size: usize },
+            quote! { () },
+            quote! { inner.truncate(size) },
+        ),
+        (
+            "pop",
+            "Removes last character, if any and if the result is valid, otherwise returns an error.",
+            quote! {},
+            quote! { ::core::option::Option<char> },
+            quote! { #result::<_, #error>::Ok(inner.pop()) },
+        ),
+        (
+            "remove",
+            "Removes character at specified index if the result is valid, otherwise returns an error.",
+            quote! { idx: usize },
+            quote! { char },
+            quote! { inner.remove(idx) },
+        ),
+        (
+            "drain",
+            "Creates a draining iterator that removes the specified range and yields the removed chars, if the result is valid, otherwise returns an error.",
+            quote! { range: impl ::core::ops::RangeBounds<usize> },
+            quote! { #krate::Drain<{ #capacity }> },
+            quote! { inner.drain(range) },
+        ),
+        (
+            "clear",
+            "Empties `{name}` if the empty string is valid, otherwise returns an error.",
+            quote! {},
+            quote! { () },
+            quote! { #result::<_, #error>::Ok(inner.clear()) },
+        ),
+    ]
+    .map(|(method, text, params, output, call)| {
+        let method = format_ident!("{}", method);
+        let text = doc(text);
+        quote! {
+            #[doc = #text]
+            #[inline]
+            pub fn #method(&mut self, #params) -> #result<#output, #error> {
+                let mut inner = self.0;
+                let out = (#call).map_err(#error::from)?;
+                *self = Self::try_from_array_string(inner)?;
+                #result::Ok(out)
+            }
+        }
+    });
+    let try_from_str = doc("Creates new `{name}` from string slice if it fits in capacity and is valid, otherwise returns an error.");
+    let try_from_array_string =
+        doc("Creates new `{name}` from `ArrayString` if it's valid, otherwise returns an error.");
+    let try_from_iterator = doc("Creates new `{name}` from string slice iterator if total length fits in capacity and is valid, otherwise returns an error.");
+    let try_from_chars = doc("Creates new `{name}` from char iterator if total length fits in capacity and is valid, otherwise returns an error.");
+    let try_from_utf16 = doc("Creates new `{name}` from `u16` slice if it's valid utf-16, fits in capacity and is valid, otherwise returns an error.");
+    let into_inner = doc("Extracts the inner `ArrayString` of `{name}`");
+    quote! {
+        impl #name {
+            #[doc = #try_from_str]
+            #[inline]
+            pub fn try_from_str(string: impl ::core::convert::AsRef<str>) -> #result<Self, #error> {
+                Self::try_from_array_string(<#inner>::try_from_str(string)?)
+            }
+
+            #[doc = #try_from_array_string]
+            #[inline]
+            pub fn try_from_array_string(string: #inner) -> #result<Self, #error> {
+                <#validator as #krate::validated::Validator>::validate(string.as_str())?;
+                #result::Ok(Self(string))
+            }
+
+            #[doc = #try_from_iterator]
+            #[inline]
+            pub fn try_from_iterator(
+                iter: impl ::core::iter::IntoIterator<Item = impl ::core::convert::AsRef<str>>,
+            ) -> #result<Self, #error> {
+                Self::try_from_array_string(<#inner>::try_from_iterator(iter)?)
+            }
+
+            #[doc = #try_from_chars]
+            #[inline]
+            pub fn try_from_chars(iter: impl ::core::iter::IntoIterator<Item = char>) -> #result<Self, #error> {
+                Self::try_from_array_string(<#inner>::try_from_chars(iter)?)
+            }
+
+            #[doc = #try_from_utf16]
+            #[inline]
+            pub fn try_from_utf16(slice: impl ::core::convert::AsRef<[u16]>) -> #result<Self, #error> {
+                Self::try_from_array_string(<#inner>::try_from_utf16(slice)?)
+            }
+
+            #[doc = #into_inner]
+            #[inline]
+            pub fn into_inner(self) -> #inner {
+                self.0
+            }
+
+            #(#mutations)*
+        }
+
+        impl ::core::str::FromStr for #name {
+            type Err = #error;
+
+            #[inline]
+            fn from_str(s: &str) -> #result<Self, Self::Err> {
+                Self::try_from_str(s)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for #name {
+            type Error = #error;
+
+            #[inline]
+            fn try_from(s: &str) -> #result<Self, Self::Error> {
+                Self::try_from_str(s)
+            }
+        }
+
+        impl ::core::convert::TryFrom<#inner> for #name {
+            type Error = #error;
+
+            #[inline]
+            fn try_from(s: #inner) -> #result<Self, Self::Error> {
+                Self::try_from_array_string(s)
+            }
+        }
+
+        impl ::core::convert::From<#name> for #inner {
+            #[inline]
+            fn from(s: #name) -> Self {
+                s.0
+            }
+        }
+    }
+}
@@ -0,0 +1,473 @@
+#ifndef ARRAYSTRING_H
+#define ARRAYSTRING_H
+
+/* Generated by cbindgen, run `ARRAYSTRING_BLESS=1 cargo test -p arraystring-ffi --test header` to update */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+#define ARRAYSTRING_ALIGNED(n) __attribute__((aligned(n)))
+
+/**
+ * Status code returned by every fallible function
+ */
+enum ArrayStringStatus
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : int32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * Operation succeeded
+   */
+  ARRAY_STRING_STATUS_OK = 0,
+  /**
+   * Invalid UTF-8 data, index that is not at a char boundary or invalid unicode scalar value
+   */
+  ARRAY_STRING_STATUS_UTF8 = 1,
+  /**
+   * Invalid UTF-16 data
+   */
+  ARRAY_STRING_STATUS_UTF16 = 2,
+  /**
+   * Out of bounds access or not enough capacity
+   */
+  ARRAY_STRING_STATUS_OUT_OF_BOUNDS = 3,
+  /**
+   * A required pointer was null
+   */
+  ARRAY_STRING_STATUS_NULL_POINTER = 4,
+  /**
+   * Text failed a validation rule
+   */
+  ARRAY_STRING_STATUS_INVALID = 5,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum ArrayStringStatus ArrayStringStatus;
+#else
+typedef int32_t ArrayStringStatus;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+/**
+ * Handle to a string with 63 bytes of capacity, occupies a full cache line (`CacheString`)
+ */
+typedef struct ARRAYSTRING_ALIGNED(64) ArrayStringCache {
+  uint8_t len;
+  uint8_t data[63];
+} ArrayStringCache;
+
+/**
+ * Handle to a string with 32 bytes of capacity
+ */
+typedef struct ArrayString32 {
+  uint8_t len;
+  uint8_t data[32];
+} ArrayString32;
+
+/**
+ * Handle to a string with 255 bytes of capacity (`MaxString`)
+ */
+typedef struct ArrayString255 {
+  uint8_t len;
+  uint8_t data[255];
+} ArrayString255;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates new empty string
+ */
+struct ArrayStringCache arraystring_cache_new(void);
+
+/**
+ * Returns maximum string capacity in bytes
+ */
+size_t arraystring_cache_capacity(void);
+
+/**
+ * Creates string from UTF-8 data, erroring if it's bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_try_from_str(const uint8_t *ptr,
+                                                 size_t len,
+                                                 struct ArrayStringCache *out);
+
+/**
+ * Creates string from UTF-8 data, truncating it if it's bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_from_str_truncate(const uint8_t *ptr,
+                                                      size_t len,
+                                                      struct ArrayStringCache *out);
+
+/**
+ * Creates string from UTF-16 data, erroring if it's invalid or bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_try_from_utf16(const uint16_t *ptr,
+                                                   size_t len,
+                                                   struct ArrayStringCache *out);
+
+/**
+ * Creates string from UTF-16 data, replacing invalid data and truncating it if it's bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_from_utf16_lossy_truncate(const uint16_t *ptr,
+                                                              size_t len,
+                                                              struct ArrayStringCache *out);
+
+/**
+ * Returns pointer to the UTF-8 data (not nul terminated), valid while the handle is alive and unchanged
+ */
+const uint8_t *arraystring_cache_as_ptr(const struct ArrayStringCache *s);
+
+/**
+ * Returns string length in bytes
+ */
+size_t arraystring_cache_len(const struct ArrayStringCache *s);
+
+/**
+ * Pushes UTF-8 data to the end of the string, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_try_push_str(struct ArrayStringCache *s,
+                                                 const uint8_t *ptr,
+                                                 size_t len);
+
+/**
+ * Pushes UTF-8 data to the end of the string, truncating total size if bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_push_str_truncate(struct ArrayStringCache *s,
+                                                      const uint8_t *ptr,
+                                                      size_t len);
+
+/**
+ * Pushes char to the end of the string, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_try_push(struct ArrayStringCache *s, uint32_t ch);
+
+/**
+ * Removes last char, writing it to `out` (errors with `OutOfBounds` if empty)
+ */
+ArrayStringStatus arraystring_cache_pop(struct ArrayStringCache *s, uint32_t *out);
+
+/**
+ * Truncates string to specified size, erroring if it's not at a char boundary
+ */
+ArrayStringStatus arraystring_cache_truncate(struct ArrayStringCache *s, size_t size);
+
+/**
+ * Removes whitespaces from the beginning and end of the string
+ */
+ArrayStringStatus arraystring_cache_trim(struct ArrayStringCache *s);
+
+/**
+ * Removes char at byte index `idx`, writing it to `out`
+ */
+ArrayStringStatus arraystring_cache_remove(struct ArrayStringCache *s, size_t idx, uint32_t *out);
+
+/**
+ * Inserts char at byte index `idx`, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_try_insert(struct ArrayStringCache *s, size_t idx, uint32_t ch);
+
+/**
+ * Inserts UTF-8 data at byte index `idx`, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_try_insert_str(struct ArrayStringCache *s,
+                                                   size_t idx,
+                                                   const uint8_t *ptr,
+                                                   size_t len);
+
+/**
+ * Inserts UTF-8 data at byte index `idx`, truncating total size if bigger than capacity
+ */
+ArrayStringStatus arraystring_cache_insert_str_truncate(struct ArrayStringCache *s,
+                                                        size_t idx,
+                                                        const uint8_t *ptr,
+                                                        size_t len);
+
+/**
+ * Splits string at byte index `at`, writing the right side to `out`
+ */
+ArrayStringStatus arraystring_cache_split_off(struct ArrayStringCache *s,
+                                              size_t at,
+                                              struct ArrayStringCache *out);
+
+/**
+ * Replaces the byte range `start..end` with UTF-8 data
+ */
+ArrayStringStatus arraystring_cache_replace_range(struct ArrayStringCache *s,
+                                                  size_t start,
+                                                  size_t end,
+                                                  const uint8_t *ptr,
+                                                  size_t len);
+
+/**
+ * Empties string
+ */
+ArrayStringStatus arraystring_cache_clear(struct ArrayStringCache *s);
+
+/**
+ * Creates new empty string
+ */
+struct ArrayString32 arraystring_32_new(void);
+
+/**
+ * Returns maximum string capacity in bytes
+ */
+size_t arraystring_32_capacity(void);
+
+/**
+ * Creates string from UTF-8 data, erroring if it's bigger than capacity
+ */
+ArrayStringStatus arraystring_32_try_from_str(const uint8_t *ptr,
+                                              size_t len,
+                                              struct ArrayString32 *out);
+
+/**
+ * Creates string from UTF-8 data, truncating it if it's bigger than capacity
+ */
+ArrayStringStatus arraystring_32_from_str_truncate(const uint8_t *ptr,
+                                                   size_t len,
+                                                   struct ArrayString32 *out);
+
+/**
+ * Creates string from UTF-16 data, erroring if it's invalid or bigger than capacity
+ */
+ArrayStringStatus arraystring_32_try_from_utf16(const uint16_t *ptr,
+                                                size_t len,
+                                                struct ArrayString32 *out);
+
+/**
+ * Creates string from UTF-16 data, replacing invalid data and truncating it if it's bigger than capacity
+ */
+ArrayStringStatus arraystring_32_from_utf16_lossy_truncate(const uint16_t *ptr,
+                                                           size_t len,
+                                                           struct ArrayString32 *out);
+
+/**
+ * Returns pointer to the UTF-8 data (not nul terminated), valid while the handle is alive and unchanged
+ */
+const uint8_t *arraystring_32_as_ptr(const struct ArrayString32 *s);
+
+/**
+ * Returns string length in bytes
+ */
+size_t arraystring_32_len(const struct ArrayString32 *s);
+
+/**
+ * Pushes UTF-8 data to the end of the string, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_32_try_push_str(struct ArrayString32 *s,
+                                              const uint8_t *ptr,
+                                              size_t len);
+
+/**
+ * Pushes UTF-8 data to the end of the string, truncating total size if bigger than capacity
+ */
+ArrayStringStatus arraystring_32_push_str_truncate(struct ArrayString32 *s,
+                                                   const uint8_t *ptr,
+                                                   size_t len);
+
+/**
+ * Pushes char to the end of the string, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_32_try_push(struct ArrayString32 *s, uint32_t ch);
+
+/**
+ * Removes last char, writing it to `out` (errors with `OutOfBounds` if empty)
+ */
+ArrayStringStatus arraystring_32_pop(struct ArrayString32 *s, uint32_t *out);
+
+/**
+ * Truncates string to specified size, erroring if it's not at a char boundary
+ */
+ArrayStringStatus arraystring_32_truncate(struct ArrayString32 *s, size_t size);
+
+/**
+ * Removes whitespaces from the beginning and end of the string
+ */
+ArrayStringStatus arraystring_32_trim(struct ArrayString32 *s);
+
+/**
+ * Removes char at byte index `idx`, writing it to `out`
+ */
+ArrayStringStatus arraystring_32_remove(struct ArrayString32 *s, size_t idx, uint32_t *out);
+
+/**
+ * Inserts char at byte index `idx`, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_32_try_insert(struct ArrayString32 *s, size_t idx, uint32_t ch);
+
+/**
+ * Inserts UTF-8 data at byte index `idx`, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_32_try_insert_str(struct ArrayString32 *s,
+                                                size_t idx,
+                                                const uint8_t *ptr,
+                                                size_t len);
+
+/**
+ * Inserts UTF-8 data at byte index `idx`, truncating total size if bigger than capacity
+ */
+ArrayStringStatus arraystring_32_insert_str_truncate(struct ArrayString32 *s,
+                                                     size_t idx,
+                                                     const uint8_t *ptr,
+                                                     size_t len);
+
+/**
+ * Splits string at byte index `at`, writing the right side to `out`
+ */
+ArrayStringStatus arraystring_32_split_off(struct ArrayString32 *s,
+                                           size_t at,
+                                           struct ArrayString32 *out);
+
+/**
+ * Replaces the byte range `start..end` with UTF-8 data
+ */
+ArrayStringStatus arraystring_32_replace_range(struct ArrayString32 *s,
+                                               size_t start,
+                                               size_t end,
+                                               const uint8_t *ptr,
+                                               size_t len);
+
+/**
+ * Empties string
+ */
+ArrayStringStatus arraystring_32_clear(struct ArrayString32 *s);
+
+/**
+ * Creates new empty string
+ */
+struct ArrayString255 arraystring_255_new(void);
+
+/**
+ * Returns maximum string capacity in bytes
+ */
+size_t arraystring_255_capacity(void);
+
+/**
+ * Creates string from UTF-8 data, erroring if it's bigger than capacity
+ */
+ArrayStringStatus arraystring_255_try_from_str(const uint8_t *ptr,
+                                               size_t len,
+                                               struct ArrayString255 *out);
+
+/**
+ * Creates string from UTF-8 data, truncating it if it's bigger than capacity
+ */
+ArrayStringStatus arraystring_255_from_str_truncate(const uint8_t *ptr,
+                                                    size_t len,
+                                                    struct ArrayString255 *out);
+
+/**
+ * Creates string from UTF-16 data, erroring if it's invalid or bigger than capacity
+ */
+ArrayStringStatus arraystring_255_try_from_utf16(const uint16_t *ptr,
+                                                 size_t len,
+                                                 struct ArrayString255 *out);
+
+/**
+ * Creates string from UTF-16 data, replacing invalid data and truncating it if it's bigger than capacity
+ */
+ArrayStringStatus arraystring_255_from_utf16_lossy_truncate(const uint16_t *ptr,
+                                                            size_t len,
+                                                            struct ArrayString255 *out);
+
+/**
+ * Returns pointer to the UTF-8 data (not nul terminated), valid while the handle is alive and unchanged
+ */
+const uint8_t *arraystring_255_as_ptr(const struct ArrayString255 *s);
+
+/**
+ * Returns string length in bytes
+ */
+size_t arraystring_255_len(const struct ArrayString255 *s);
+
+/**
+ * Pushes UTF-8 data to the end of the string, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_255_try_push_str(struct ArrayString255 *s,
+                                               const uint8_t *ptr,
+                                               size_t len);
+
+/**
+ * Pushes UTF-8 data to the end of the string, truncating total size if bigger than capacity
+ */
+ArrayStringStatus arraystring_255_push_str_truncate(struct ArrayString255 *s,
+                                                    const uint8_t *ptr,
+                                                    size_t len);
+
+/**
+ * Pushes char to the end of the string, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_255_try_push(struct ArrayString255 *s, uint32_t ch);
+
+/**
+ * Removes last char, writing it to `out` (errors with `OutOfBounds` if empty)
+ */
+ArrayStringStatus arraystring_255_pop(struct ArrayString255 *s, uint32_t *out);
+
+/**
+ * Truncates string to specified size, erroring if it's not at a char boundary
+ */
+ArrayStringStatus arraystring_255_truncate(struct ArrayString255 *s, size_t size);
+
+/**
+ * Removes whitespaces from the beginning and end of the string
+ */
+ArrayStringStatus arraystring_255_trim(struct ArrayString255 *s);
+
+/**
+ * Removes char at byte index `idx`, writing it to `out`
+ */
+ArrayStringStatus arraystring_255_remove(struct ArrayString255 *s, size_t idx, uint32_t *out);
+
+/**
+ * Inserts char at byte index `idx`, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_255_try_insert(struct ArrayString255 *s, size_t idx, uint32_t ch);
+
+/**
+ * Inserts UTF-8 data at byte index `idx`, erroring if total size is bigger than capacity
+ */
+ArrayStringStatus arraystring_255_try_insert_str(struct ArrayString255 *s,
+                                                 size_t idx,
+                                                 const uint8_t *ptr,
+                                                 size_t len);
+
+/**
+ * Inserts UTF-8 data at byte index `idx`, truncating total size if bigger than capacity
+ */
+ArrayStringStatus arraystring_255_insert_str_truncate(struct ArrayString255 *s,
+                                                      size_t idx,
+                                                      const uint8_t *ptr,
+                                                      size_t len);
+
+/**
+ * Splits string at byte index `at`, writing the right side to `out`
+ */
+ArrayStringStatus arraystring_255_split_off(struct ArrayString255 *s,
+                                            size_t at,
+                                            struct ArrayString255 *out);
+
+/**
+ * Replaces the byte range `start..end` with UTF-8 data
+ */
+ArrayStringStatus arraystring_255_replace_range(struct ArrayString255 *s,
+                                                size_t start,
+                                                size_t end,
+                                                const uint8_t *ptr,
+                                                size_t len);
+
+/**
+ * Empties string
+ */
+ArrayStringStatus arraystring_255_clear(struct ArrayString255 *s);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* ARRAYSTRING_H */
@@ -1,204 +1,968 @@
-use arraystring::{Error, prelude::*, 63};
+//! C ABI for [`arraystring`]
+//!
+//! Every string type is exposed as a `#[repr(C)]` handle that can live in the C stack, it should be treated as opaque and only be manipulated through the functions of this crate
+//!
+//! String inputs are passed as a pointer plus a length in bytes (they don't need to be nul terminated) and must be valid UTF-8
+//!
+//! Chars are passed as `uint32_t` unicode scalar values
+//!
+//! Every fallible function returns an [`ArrayStringStatus`], output parameters are only written on success
+//!
+//! The header is generated by `cbindgen` and lives in `include/arraystring.h`
+
+#![warn(missing_docs, missing_debug_implementations, unused_results)]
+#![allow(clippy::missing_safety_doc)]
+
+use arraystring::{ArrayString, Error};
+use core::{slice, str};
+
+/// Status code returned by every fallible function
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArrayStringStatus {
+    /// Operation succeeded
+    Ok = 0,
+    /// Invalid UTF-8 data, index that is not at a char boundary or invalid unicode scalar value
+    Utf8 = 1,
+    /// Invalid UTF-16 data
+    Utf16 = 2,
+    /// Out of bounds access or not enough capacity
+    OutOfBounds = 3,
+    /// A required pointer was null
+    NullPointer = 4,
+    /// Text failed a validation rule
+    Invalid = 5,
+}
+
+impl From<Error> for ArrayStringStatus {
+    #[inline]
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Utf8 { .. } => ArrayStringStatus::Utf8,
+            Error::Utf16 => ArrayStringStatus::Utf16,
+            Error::OutOfBounds { .. } | Error::CharLimit { .. } | Error::Index { .. } => {
+                ArrayStringStatus::OutOfBounds
+            }
+            Error::Invalid { .. } => ArrayStringStatus::Invalid,
+            _ => ArrayStringStatus::Invalid,
+        }
+    }
+}
+
+impl<T> From<Result<T, ArrayStringStatus>> for ArrayStringStatus {
+    #[inline]
+    fn from(res: Result<T, ArrayStringStatus>) -> Self {
+        res.err().unwrap_or(ArrayStringStatus::Ok)
+    }
+}
+
+/// Handle to a string with 63 bytes of capacity, occupies a full cache line (`CacheString`)
+#[repr(C, align(64))]
+#[derive(Copy, Clone, Debug)]
+pub struct ArrayStringCache {
+    len: u8,
+    data: [u8; 63],
+}
+
+/// Handle to a string with 32 bytes of capacity
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct ArrayString32 {
+    len: u8,
+    data: [u8; 32],
+}
+
+/// Handle to a string with 255 bytes of capacity (`MaxString`)
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct ArrayString255 {
+    len: u8,
+    data: [u8; 255],
+}
+
+/// Borrows `len` bytes from `ptr` as a string slice (`ptr` may be null if `len` is 0)
+#[inline]
+unsafe fn str_arg<'a>(ptr: *const u8, len: usize) -> Result<&'a str, ArrayStringStatus> {
+    if len == 0 {
+        return Ok("");
+    }
+    if ptr.is_null() {
+        return Err(ArrayStringStatus::NullPointer);
+    }
+    str::from_utf8(slice::from_raw_parts(ptr, len)).map_err(|_| ArrayStringStatus::Utf8)
+}
+
+/// Borrows `len` code units from `ptr` (`ptr` may be null if `len` is 0)
+#[inline]
+unsafe fn utf16_arg<'a>(ptr: *const u16, len: usize) -> Result<&'a [u16], ArrayStringStatus> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if ptr.is_null() {
+        return Err(ArrayStringStatus::NullPointer);
+    }
+    Ok(slice::from_raw_parts(ptr, len))
+}
+
+#[inline]
+fn char_arg(ch: u32) -> Result<char, ArrayStringStatus> {
+    char::from_u32(ch).ok_or(ArrayStringStatus::Utf8)
+}
+
+#[inline]
+unsafe fn out_arg<'a, T>(ptr: *mut T) -> Result<&'a mut T, ArrayStringStatus> {
+    ptr.as_mut().ok_or(ArrayStringStatus::NullPointer)
+}
+
+/// Generates the conversions between a handle and its `ArrayString` and every operation over it
+///
+/// The exported functions are written by hand below, so `cbindgen` can see them, and delegate to this module
+macro_rules! handle {
+    ($module:ident, $handle:ident, $capacity:literal) => {
+        impl $handle {
+            const EMPTY: Self = Self {
+                len: 0,
+                data: [0; $capacity],
+            };
+
+            #[inline]
+            fn load(&self) -> Result<ArrayString<$capacity>, ArrayStringStatus> {
+                let bytes = self
+                    .data
+                    .get(..self.len as usize)
+                    .ok_or(ArrayStringStatus::OutOfBounds)?;
+                let string = str::from_utf8(bytes).map_err(|_| ArrayStringStatus::Utf8)?;
+                Ok(ArrayString::try_from_str(string).map_err(Error::from)?)
+            }
+
+            #[inline]
+            fn store(string: &ArrayString<$capacity>) -> Self {
+                let mut handle = Self::EMPTY;
+                handle.data[..string.len()].copy_from_slice(string.as_bytes());
+                handle.len = string.len() as u8;
+                handle
+            }
+        }
+
+        mod $module {
+            use super::*;
+
+            type Inner = ArrayString<$capacity>;
+
+            #[inline]
+            unsafe fn with<T>(
+                handle: *mut $handle,
+                f: impl FnOnce(&mut Inner) -> Result<T, ArrayStringStatus>,
+            ) -> Result<T, ArrayStringStatus> {
+                let handle = out_arg(handle)?;
+                let mut string = handle.load()?;
+                let ret = f(&mut string)?;
+                *handle = $handle::store(&string);
+                Ok(ret)
+            }
+
+            #[inline]
+            unsafe fn create(
+                out: *mut $handle,
+                f: impl FnOnce() -> Result<Inner, ArrayStringStatus>,
+            ) -> Result<(), ArrayStringStatus> {
+                let out = out_arg(out)?;
+                *out = $handle::store(&f()?);
+                Ok(())
+            }
+
+            pub(crate) fn new() -> $handle {
+                $handle::EMPTY
+            }
+
+            pub(crate) fn capacity() -> usize {
+                Inner::capacity()
+            }
+
+            pub(crate) unsafe fn try_from_str(
+                ptr: *const u8,
+                len: usize,
+                out: *mut $handle,
+            ) -> Result<(), ArrayStringStatus> {
+                let s = str_arg(ptr, len)?;
+                create(out, || Ok(Inner::try_from_str(s).map_err(Error::from)?))
+            }
+
+            pub(crate) unsafe fn from_str_truncate(
+                ptr: *const u8,
+                len: usize,
+                out: *mut $handle,
+            ) -> Result<(), ArrayStringStatus> {
+                let s = str_arg(ptr, len)?;
+                create(out, || Ok(Inner::from_str_truncate(s)))
+            }
+
+            pub(crate) unsafe fn try_from_utf16(
+                ptr: *const u16,
+                len: usize,
+                out: *mut $handle,
+            ) -> Result<(), ArrayStringStatus> {
+                let s = utf16_arg(ptr, len)?;
+                create(out, || Ok(Inner::try_from_utf16(s)?))
+            }
+
+            pub(crate) unsafe fn from_utf16_lossy_truncate(
+                ptr: *const u16,
+                len: usize,
+                out: *mut $handle,
+            ) -> Result<(), ArrayStringStatus> {
+                let s = utf16_arg(ptr, len)?;
+                create(out, || Ok(Inner::from_utf16_lossy_truncate(s)))
+            }
+
+            pub(crate) unsafe fn as_ptr(handle: *const $handle) -> *const u8 {
+                handle
+                    .as_ref()
+                    .map_or(core::ptr::null(), |h| h.data.as_ptr())
+            }
+
+            pub(crate) unsafe fn len(handle: *const $handle) -> usize {
+                handle
+                    .as_ref()
+                    .and_then(|h| h.load().ok())
+                    .map_or(0, |s| s.len())
+            }
+
+            pub(crate) unsafe fn try_push_str(
+                handle: *mut $handle,
+                ptr: *const u8,
+                len: usize,
+            ) -> Result<(), ArrayStringStatus> {
+                let s = str_arg(ptr, len)?;
+                with(handle, |string| {
+                    Ok(string.try_push_str(s).map_err(Error::from)?)
+                })
+            }
+
+            pub(crate) unsafe fn push_str_truncate(
+                handle: *mut $handle,
+                ptr: *const u8,
+                len: usize,
+            ) -> Result<(), ArrayStringStatus> {
+                let s = str_arg(ptr, len)?;
+                with(handle, |string| {
+                    string.push_str_truncate(s);
+                    Ok(())
+                })
+            }
+
+            pub(crate) unsafe fn try_push(
+                handle: *mut $handle,
+                ch: u32,
+            ) -> Result<(), ArrayStringStatus> {
+                let ch = char_arg(ch)?;
+                with(handle, |string| {
+                    Ok(string.try_push(ch).map_err(Error::from)?)
+                })
+            }
+
+            pub(crate) unsafe fn pop(
+                handle: *mut $handle,
+                out: *mut u32,
+            ) -> Result<(), ArrayStringStatus> {
+                let out = out_arg(out)?;
+                let ch = with(handle, |string| {
+                    string.pop().ok_or(ArrayStringStatus::OutOfBounds)
+                })?;
+                *out = ch as u32;
+                Ok(())
+            }
+
+            pub(crate) unsafe fn truncate(
+                handle: *mut $handle,
+                size: usize,
+            ) -> Result<(), ArrayStringStatus> {
+                with(handle, |string| {
+                    Ok(string.truncate(size).map_err(Error::from)?)
+                })
+            }
+
+            pub(crate) unsafe fn trim(handle: *mut $handle) -> Result<(), ArrayStringStatus> {
+                with(handle, |string| {
+                    string.trim();
+                    Ok(())
+                })
+            }
+
+            pub(crate) unsafe fn remove(
+                handle: *mut $handle,
+                idx: usize,
+                out: *mut u32,
+            ) -> Result<(), ArrayStringStatus> {
+                let out = out_arg(out)?;
+                let ch = with(handle, |string| Ok(string.remove(idx)?))?;
+                *out = ch as u32;
+                Ok(())
+            }
+
+            pub(crate) unsafe fn try_insert(
+                handle: *mut $handle,
+                idx: usize,
+                ch: u32,
+            ) -> Result<(), ArrayStringStatus> {
+                let ch = char_arg(ch)?;
+                with(handle, |string| {
+                    Ok(string.try_insert(idx, ch).map_err(Error::from)?)
+                })
+            }
+
+            pub(crate) unsafe fn try_insert_str(
+                handle: *mut $handle,
+                idx: usize,
+                ptr: *const u8,
+                len: usize,
+            ) -> Result<(), ArrayStringStatus> {
+                let s = str_arg(ptr, len)?;
+                with(handle, |string| {
+                    Ok(string.try_insert_str(idx, s).map_err(Error::from)?)
+                })
+            }
+
+            pub(crate) unsafe fn insert_str_truncate(
+                handle: *mut $handle,
+                idx: usize,
+                ptr: *const u8,
+                len: usize,
+            ) -> Result<(), ArrayStringStatus> {
+                let s = str_arg(ptr, len)?;
+                with(handle, |string| Ok(string.insert_str_truncate(idx, s)?))
+            }
+
+            pub(crate) unsafe fn split_off(
+                handle: *mut $handle,
+                at: usize,
+                out: *mut $handle,
+            ) -> Result<(), ArrayStringStatus> {
+                let out = out_arg(out)?;
+                let split = with(handle, |string| Ok(string.split_off(at)?))?;
+                *out = $handle::store(&split);
+                Ok(())
+            }
+
+            pub(crate) unsafe fn replace_range(
+                handle: *mut $handle,
+                start: usize,
+                end: usize,
+                ptr: *const u8,
+                len: usize,
+            ) -> Result<(), ArrayStringStatus> {
+                let s = str_arg(ptr, len)?;
+                with(handle, |string| Ok(string.replace_range(start..end, s)?))
+            }
+
+            pub(crate) unsafe fn clear(handle: *mut $handle) -> Result<(), ArrayStringStatus> {
+                *out_arg(handle)? = $handle::EMPTY;
+                Ok(())
+            }
+        }
+    };
+}
+
+handle!(cache, ArrayStringCache, 63);
+handle!(array32, ArrayString32, 32);
+handle!(array255, ArrayString255, 255);
+
+/// Creates new empty string
+#[no_mangle]
+pub extern "C" fn arraystring_cache_new() -> ArrayStringCache {
+    cache::new()
+}
+
+/// Returns maximum string capacity in bytes
+#[no_mangle]
+pub extern "C" fn arraystring_cache_capacity() -> usize {
+    cache::capacity()
+}
+
+/// Creates string from UTF-8 data, erroring if it's bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_cache_try_from_str(
+    ptr: *const u8,
+    len: usize,
+    out: *mut ArrayStringCache,
+) -> ArrayStringStatus {
+    cache::try_from_str(ptr, len, out).into()
+}
+
+/// Creates string from UTF-8 data, truncating it if it's bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_cache_from_str_truncate(
+    ptr: *const u8,
+    len: usize,
+    out: *mut ArrayStringCache,
+) -> ArrayStringStatus {
+    cache::from_str_truncate(ptr, len, out).into()
+}
+
+/// Creates string from UTF-16 data, erroring if it's invalid or bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_cache_try_from_utf16(
+    ptr: *const u16,
+    len: usize,
+    out: *mut ArrayStringCache,
+) -> ArrayStringStatus {
+    cache::try_from_utf16(ptr, len, out).into()
+}
+
+/// Creates string from UTF-16 data, replacing invalid data and truncating it if it's bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_cache_from_utf16_lossy_truncate(
+    ptr: *const u16,
+    len: usize,
+    out: *mut ArrayStringCache,
+) -> ArrayStringStatus {
+    cache::from_utf16_lossy_truncate(ptr, len, out).into()
+}
+
+/// Returns pointer to the UTF-8 data (not nul terminated), valid while the handle is alive and unchanged
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_cache_as_ptr(s: *const ArrayStringCache) -> *const u8 {
+    cache::as_ptr(s)
+}
+
+/// Returns string length in bytes
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_cache_len(s: *const ArrayStringCache) -> usize {
+    cache::len(s)
+}
 
-pub type Str = CacheString;
-pub type Len = U63;
+/// Pushes UTF-8 data to the end of the string, erroring if total size is bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_cache_try_push_str(
+    s: *mut ArrayStringCache,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    cache::try_push_str(s, ptr, len).into()
+}
 
+/// Pushes UTF-8 data to the end of the string, truncating total size if bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn new() -> Str {
-    Str::new()
+pub unsafe extern "C" fn arraystring_cache_push_str_truncate(
+    s: *mut ArrayStringCache,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    cache::push_str_truncate(s, ptr, len).into()
 }
 
+/// Pushes char to the end of the string, erroring if total size is bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn try_from_str(s: &str) -> Result<Str, OutOfBounds> {
-    Str::try_from_str(s)
+pub unsafe extern "C" fn arraystring_cache_try_push(
+    s: *mut ArrayStringCache,
+    ch: u32,
+) -> ArrayStringStatus {
+    cache::try_push(s, ch).into()
 }
 
+/// Removes last char, writing it to `out` (errors with `OutOfBounds` if empty)
 #[no_mangle]
-pub unsafe extern "C" fn from_str_truncate(s: &str) -> Str {
-    Str::from_str_truncate(s)
+pub unsafe extern "C" fn arraystring_cache_pop(
+    s: *mut ArrayStringCache,
+    out: *mut u32,
+) -> ArrayStringStatus {
+    cache::pop(s, out).into()
 }
 
+/// Truncates string to specified size, erroring if it's not at a char boundary
 #[no_mangle]
-pub unsafe extern "C" fn from_str_unchecked(s: &str) -> Str {
-    Str::from_str_unchecked(s)
+pub unsafe extern "C" fn arraystring_cache_truncate(
+    s: *mut ArrayStringCache,
+    size: usize,
+) -> ArrayStringStatus {
+    cache::truncate(s, size).into()
 }
 
+/// Removes whitespaces from the beginning and end of the string
 #[no_mangle]
-pub unsafe extern "C" fn try_from_iterator(s: &[&str]) -> Result<Str, OutOfBounds> {
-    Str::try_from_iterator(s)
+pub unsafe extern "C" fn arraystring_cache_trim(s: *mut ArrayStringCache) -> ArrayStringStatus {
+    cache::trim(s).into()
 }
 
+/// Removes char at byte index `idx`, writing it to `out`
 #[no_mangle]
-pub unsafe extern "C" fn from_iterator(s: &[&str]) -> Str {
-    Str::from_iterator(s)
+pub unsafe extern "C" fn arraystring_cache_remove(
+    s: *mut ArrayStringCache,
+    idx: usize,
+    out: *mut u32,
+) -> ArrayStringStatus {
+    cache::remove(s, idx, out).into()
 }
 
+/// Inserts char at byte index `idx`, erroring if total size is bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn from_iterator_unchecked(s: &[&str]) -> Str {
-    Str::from_iterator_unchecked(s)
+pub unsafe extern "C" fn arraystring_cache_try_insert(
+    s: *mut ArrayStringCache,
+    idx: usize,
+    ch: u32,
+) -> ArrayStringStatus {
+    cache::try_insert(s, idx, ch).into()
 }
 
+/// Inserts UTF-8 data at byte index `idx`, erroring if total size is bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn try_from_chars(s: &[char]) -> Result<Str, OutOfBounds> {
-    Str::try_from_chars(s.iter().map(|c| *c))
+pub unsafe extern "C" fn arraystring_cache_try_insert_str(
+    s: *mut ArrayStringCache,
+    idx: usize,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    cache::try_insert_str(s, idx, ptr, len).into()
 }
 
+/// Inserts UTF-8 data at byte index `idx`, truncating total size if bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn from_chars(s: &[char]) -> Str {
-    Str::from_chars(s.iter().map(|c| *c))
+pub unsafe extern "C" fn arraystring_cache_insert_str_truncate(
+    s: *mut ArrayStringCache,
+    idx: usize,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    cache::insert_str_truncate(s, idx, ptr, len).into()
 }
 
+/// Splits string at byte index `at`, writing the right side to `out`
 #[no_mangle]
-pub unsafe extern "C" fn from_chars_unchecked(s: &[char]) -> Str {
-    Str::from_chars_unchecked(s.iter().map(|c| *c))
+pub unsafe extern "C" fn arraystring_cache_split_off(
+    s: *mut ArrayStringCache,
+    at: usize,
+    out: *mut ArrayStringCache,
+) -> ArrayStringStatus {
+    cache::split_off(s, at, out).into()
 }
 
+/// Replaces the byte range `start..end` with UTF-8 data
 #[no_mangle]
-pub unsafe extern "C" fn try_from_utf8(s: &[u8]) -> Result<Str, Error> {
-    Str::try_from_utf8(s)
+pub unsafe extern "C" fn arraystring_cache_replace_range(
+    s: *mut ArrayStringCache,
+    start: usize,
+    end: usize,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    cache::replace_range(s, start, end, ptr, len).into()
 }
 
+/// Empties string
 #[no_mangle]
-pub unsafe extern "C" fn from_utf8(s: &[u8]) -> Result<Str, Utf8> {
-    Str::from_utf8(s)
+pub unsafe extern "C" fn arraystring_cache_clear(s: *mut ArrayStringCache) -> ArrayStringStatus {
+    cache::clear(s).into()
 }
 
+/// Creates new empty string
 #[no_mangle]
-pub unsafe extern "C" fn try_from_utf16(s: &[u16]) -> Result<Str, Error> {
-    Str::try_from_utf16(s)
+pub extern "C" fn arraystring_32_new() -> ArrayString32 {
+    array32::new()
 }
 
+/// Returns maximum string capacity in bytes
 #[no_mangle]
-pub unsafe extern "C" fn from_utf16(s: &[u16]) -> Result<Str, Utf16> {
-    Str::from_utf16(s)
+pub extern "C" fn arraystring_32_capacity() -> usize {
+    array32::capacity()
 }
 
+/// Creates string from UTF-8 data, erroring if it's bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn from_utf16_lossy(s: &[u16]) -> Str {
-    Str::from_utf16_lossy(s)
+pub unsafe extern "C" fn arraystring_32_try_from_str(
+    ptr: *const u8,
+    len: usize,
+    out: *mut ArrayString32,
+) -> ArrayStringStatus {
+    array32::try_from_str(ptr, len, out).into()
 }
 
+/// Creates string from UTF-8 data, truncating it if it's bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn as_str(s: &Str) -> &str {
-    s.as_str()
+pub unsafe extern "C" fn arraystring_32_from_str_truncate(
+    ptr: *const u8,
+    len: usize,
+    out: *mut ArrayString32,
+) -> ArrayStringStatus {
+    array32::from_str_truncate(ptr, len, out).into()
 }
 
+/// Creates string from UTF-16 data, erroring if it's invalid or bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn as_mut_str(s: &mut Str) -> &mut str {
-    s.as_mut()
+pub unsafe extern "C" fn arraystring_32_try_from_utf16(
+    ptr: *const u16,
+    len: usize,
+    out: *mut ArrayString32,
+) -> ArrayStringStatus {
+    array32::try_from_utf16(ptr, len, out).into()
 }
 
+/// Creates string from UTF-16 data, replacing invalid data and truncating it if it's bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn as_bytes(s: &Str) -> &[u8] {
-    s.as_bytes()
+pub unsafe extern "C" fn arraystring_32_from_utf16_lossy_truncate(
+    ptr: *const u16,
+    len: usize,
+    out: *mut ArrayString32,
+) -> ArrayStringStatus {
+    array32::from_utf16_lossy_truncate(ptr, len, out).into()
 }
 
+/// Returns pointer to the UTF-8 data (not nul terminated), valid while the handle is alive and unchanged
 #[no_mangle]
-pub unsafe extern "C" fn as_mut_bytes(s: &mut Str) -> &mut [u8] {
-    s.as_mut_bytes()
+pub unsafe extern "C" fn arraystring_32_as_ptr(s: *const ArrayString32) -> *const u8 {
+    array32::as_ptr(s)
 }
 
+/// Returns string length in bytes
 #[no_mangle]
-pub unsafe extern "C" fn capacity() -> u8 {
-    Str::capacity()
+pub unsafe extern "C" fn arraystring_32_len(s: *const ArrayString32) -> usize {
+    array32::len(s)
 }
 
+/// Pushes UTF-8 data to the end of the string, erroring if total size is bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn try_push_str(st: &mut Str, s: &str) -> Result<(), OutOfBounds> {
-    st.try_push_str(s)
+pub unsafe extern "C" fn arraystring_32_try_push_str(
+    s: *mut ArrayString32,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array32::try_push_str(s, ptr, len).into()
 }
 
+/// Pushes UTF-8 data to the end of the string, truncating total size if bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn push_str(st: &mut Str, s: &str) {
-    st.push_str(s);
+pub unsafe extern "C" fn arraystring_32_push_str_truncate(
+    s: *mut ArrayString32,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array32::push_str_truncate(s, ptr, len).into()
 }
 
+/// Pushes char to the end of the string, erroring if total size is bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn push_str_unchecked(st: &mut Str, s: &str) {
-    st.push_str_unchecked(s)
+pub unsafe extern "C" fn arraystring_32_try_push(
+    s: *mut ArrayString32,
+    ch: u32,
+) -> ArrayStringStatus {
+    array32::try_push(s, ch).into()
 }
 
+/// Removes last char, writing it to `out` (errors with `OutOfBounds` if empty)
 #[no_mangle]
-pub unsafe extern "C" fn try_push(st: &mut Str, s: char) -> Result<(), OutOfBounds> {
-    st.try_push(s)
+pub unsafe extern "C" fn arraystring_32_pop(
+    s: *mut ArrayString32,
+    out: *mut u32,
+) -> ArrayStringStatus {
+    array32::pop(s, out).into()
 }
 
+/// Truncates string to specified size, erroring if it's not at a char boundary
 #[no_mangle]
-pub unsafe extern "C" fn push_unchecked(st: &mut Str, s: char) {
-    st.push_unchecked(s)
+pub unsafe extern "C" fn arraystring_32_truncate(
+    s: *mut ArrayString32,
+    size: usize,
+) -> ArrayStringStatus {
+    array32::truncate(s, size).into()
 }
 
+/// Removes whitespaces from the beginning and end of the string
 #[no_mangle]
-pub unsafe extern "C" fn truncate(st: &mut Str, s: u8) -> Result<(), Utf8> {
-    st.truncate(s)
+pub unsafe extern "C" fn arraystring_32_trim(s: *mut ArrayString32) -> ArrayStringStatus {
+    array32::trim(s).into()
 }
 
+/// Removes char at byte index `idx`, writing it to `out`
 #[no_mangle]
-pub unsafe extern "C" fn pop(st: &mut Str) -> Option<char> {
-    st.pop()
+pub unsafe extern "C" fn arraystring_32_remove(
+    s: *mut ArrayString32,
+    idx: usize,
+    out: *mut u32,
+) -> ArrayStringStatus {
+    array32::remove(s, idx, out).into()
 }
 
+/// Inserts char at byte index `idx`, erroring if total size is bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn trim(st: &mut Str) {
-    st.trim()
+pub unsafe extern "C" fn arraystring_32_try_insert(
+    s: *mut ArrayString32,
+    idx: usize,
+    ch: u32,
+) -> ArrayStringStatus {
+    array32::try_insert(s, idx, ch).into()
 }
 
+/// Inserts UTF-8 data at byte index `idx`, erroring if total size is bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn remove(st: &mut Str, s: u8) -> Result<char, Error> {
-    st.remove(s)
+pub unsafe extern "C" fn arraystring_32_try_insert_str(
+    s: *mut ArrayString32,
+    idx: usize,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array32::try_insert_str(s, idx, ptr, len).into()
 }
 
+/// Inserts UTF-8 data at byte index `idx`, truncating total size if bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn retain(st: &mut Str, f: &mut FnMut(char) -> bool) {
-    st.retain(|el| f(el))
+pub unsafe extern "C" fn arraystring_32_insert_str_truncate(
+    s: *mut ArrayString32,
+    idx: usize,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array32::insert_str_truncate(s, idx, ptr, len).into()
 }
 
+/// Splits string at byte index `at`, writing the right side to `out`
 #[no_mangle]
-pub unsafe extern "C" fn try_insert(st: &mut Str, idx: u8, ch: char) -> Result<(), Error> {
-    st.try_insert(idx, ch)
+pub unsafe extern "C" fn arraystring_32_split_off(
+    s: *mut ArrayString32,
+    at: usize,
+    out: *mut ArrayString32,
+) -> ArrayStringStatus {
+    array32::split_off(s, at, out).into()
 }
 
+/// Replaces the byte range `start..end` with UTF-8 data
 #[no_mangle]
-pub unsafe extern "C" fn try_insert_str(st: &mut Str, idx: u8, ch: &str) -> Result<(), Error> {
-    st.try_insert_str(idx, ch)
+pub unsafe extern "C" fn arraystring_32_replace_range(
+    s: *mut ArrayString32,
+    start: usize,
+    end: usize,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array32::replace_range(s, start, end, ptr, len).into()
 }
 
+/// Empties string
 #[no_mangle]
-pub unsafe extern "C" fn insert_str(st: &mut Str, idx: u8, ch: &str) -> Result<(), Error> {
-    st.insert_str(idx, ch)
+pub unsafe extern "C" fn arraystring_32_clear(s: *mut ArrayString32) -> ArrayStringStatus {
+    array32::clear(s).into()
 }
 
+/// Creates new empty string
 #[no_mangle]
-pub unsafe extern "C" fn insert_str_unchecked(st: &mut Str, idx: u8, ch: &str) {
-    st.insert_str_unchecked(idx, ch)
+pub extern "C" fn arraystring_255_new() -> ArrayString255 {
+    array255::new()
 }
 
+/// Returns maximum string capacity in bytes
 #[no_mangle]
-pub unsafe extern "C" fn len(st: &Str) -> u8 {
-    st.len()
+pub extern "C" fn arraystring_255_capacity() -> usize {
+    array255::capacity()
 }
 
+/// Creates string from UTF-8 data, erroring if it's bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn is_empty(st: &Str) -> bool {
-    st.is_empty()
+pub unsafe extern "C" fn arraystring_255_try_from_str(
+    ptr: *const u8,
+    len: usize,
+    out: *mut ArrayString255,
+) -> ArrayStringStatus {
+    array255::try_from_str(ptr, len, out).into()
 }
 
+/// Creates string from UTF-8 data, truncating it if it's bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn split_off(st: &mut Str, at: u8) -> Result<Str, Error> {
-    st.split_off(at)
+pub unsafe extern "C" fn arraystring_255_from_str_truncate(
+    ptr: *const u8,
+    len: usize,
+    out: *mut ArrayString255,
+) -> ArrayStringStatus {
+    array255::from_str_truncate(ptr, len, out).into()
 }
 
+/// Creates string from UTF-16 data, erroring if it's invalid or bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn clear(st: &mut Str) {
-    st.clear()
+pub unsafe extern "C" fn arraystring_255_try_from_utf16(
+    ptr: *const u16,
+    len: usize,
+    out: *mut ArrayString255,
+) -> ArrayStringStatus {
+    array255::try_from_utf16(ptr, len, out).into()
 }
 
+/// Creates string from UTF-16 data, replacing invalid data and truncating it if it's bigger than capacity
 #[no_mangle]
-pub unsafe extern "C" fn drain(st: &mut Str, start: u8, end: u8) -> Result<Drain<Len>, Error> {
-    st.drain(start..end)
+pub unsafe extern "C" fn arraystring_255_from_utf16_lossy_truncate(
+    ptr: *const u16,
+    len: usize,
+    out: *mut ArrayString255,
+) -> ArrayStringStatus {
+    array255::from_utf16_lossy_truncate(ptr, len, out).into()
 }
 
+/// Returns pointer to the UTF-8 data (not nul terminated), valid while the handle is alive and unchanged
 #[no_mangle]
-pub unsafe extern "C" fn replace_range(st: &mut Str, start: u8, end: u8, with: &str) -> Result<(), Error> {
-    st.replace_range(start..end, with)
+pub unsafe extern "C" fn arraystring_255_as_ptr(s: *const ArrayString255) -> *const u8 {
+    array255::as_ptr(s)
+}
+
+/// Returns string length in bytes
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_len(s: *const ArrayString255) -> usize {
+    array255::len(s)
+}
+
+/// Pushes UTF-8 data to the end of the string, erroring if total size is bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_try_push_str(
+    s: *mut ArrayString255,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array255::try_push_str(s, ptr, len).into()
+}
+
+/// Pushes UTF-8 data to the end of the string, truncating total size if bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_push_str_truncate(
+    s: *mut ArrayString255,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array255::push_str_truncate(s, ptr, len).into()
+}
+
+/// Pushes char to the end of the string, erroring if total size is bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_try_push(
+    s: *mut ArrayString255,
+    ch: u32,
+) -> ArrayStringStatus {
+    array255::try_push(s, ch).into()
+}
+
+/// Removes last char, writing it to `out` (errors with `OutOfBounds` if empty)
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_pop(
+    s: *mut ArrayString255,
+    out: *mut u32,
+) -> ArrayStringStatus {
+    array255::pop(s, out).into()
+}
+
+/// Truncates string to specified size, erroring if it's not at a char boundary
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_truncate(
+    s: *mut ArrayString255,
+    size: usize,
+) -> ArrayStringStatus {
+    array255::truncate(s, size).into()
+}
+
+/// Removes whitespaces from the beginning and end of the string
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_trim(s: *mut ArrayString255) -> ArrayStringStatus {
+    array255::trim(s).into()
+}
+
+/// Removes char at byte index `idx`, writing it to `out`
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_remove(
+    s: *mut ArrayString255,
+    idx: usize,
+    out: *mut u32,
+) -> ArrayStringStatus {
+    array255::remove(s, idx, out).into()
+}
+
+/// Inserts char at byte index `idx`, erroring if total size is bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_try_insert(
+    s: *mut ArrayString255,
+    idx: usize,
+    ch: u32,
+) -> ArrayStringStatus {
+    array255::try_insert(s, idx, ch).into()
+}
+
+/// Inserts UTF-8 data at byte index `idx`, erroring if total size is bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_try_insert_str(
+    s: *mut ArrayString255,
+    idx: usize,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array255::try_insert_str(s, idx, ptr, len).into()
+}
+
+/// Inserts UTF-8 data at byte index `idx`, truncating total size if bigger than capacity
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_insert_str_truncate(
+    s: *mut ArrayString255,
+    idx: usize,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array255::insert_str_truncate(s, idx, ptr, len).into()
+}
+
+/// Splits string at byte index `at`, writing the right side to `out`
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_split_off(
+    s: *mut ArrayString255,
+    at: usize,
+    out: *mut ArrayString255,
+) -> ArrayStringStatus {
+    array255::split_off(s, at, out).into()
+}
+
+/// Replaces the byte range `start..end` with UTF-8 data
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_replace_range(
+    s: *mut ArrayString255,
+    start: usize,
+    end: usize,
+    ptr: *const u8,
+    len: usize,
+) -> ArrayStringStatus {
+    array255::replace_range(s, start, end, ptr, len).into()
+}
+
+/// Empties string
+#[no_mangle]
+pub unsafe extern "C" fn arraystring_255_clear(s: *mut ArrayString255) -> ArrayStringStatus {
+    array255::clear(s).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<ArrayStringCache>(), 64);
+        assert_eq!(core::mem::align_of::<ArrayStringCache>(), 64);
+        assert_eq!(core::mem::size_of::<ArrayString32>(), 33);
+        assert_eq!(core::mem::size_of::<ArrayString255>(), 256);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut s = arraystring_cache_new();
+        let data = "My String";
+        unsafe {
+            assert_eq!(
+                arraystring_cache_try_push_str(&mut s, data.as_ptr(), data.len()),
+                ArrayStringStatus::Ok
+            );
+            let bytes =
+                slice::from_raw_parts(arraystring_cache_as_ptr(&s), arraystring_cache_len(&s));
+            assert_eq!(bytes, data.as_bytes());
+
+            let mut out = 0;
+            assert_eq!(
+                arraystring_cache_remove(&mut s, 1, &mut out),
+                ArrayStringStatus::Ok
+            );
+            assert_eq!(out, 'y' as u32);
+            assert_eq!(
+                arraystring_cache_truncate(core::ptr::null_mut(), 0),
+                ArrayStringStatus::NullPointer
+            );
+        }
+    }
 }
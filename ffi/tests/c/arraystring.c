@@ -0,0 +1,145 @@
+#include "arraystring.h"
+
+#include <stdio.h>
+#include <string.h>
+
+static int failures = 0;
+
+#define CHECK(cond)                                                            \
+  do {                                                                         \
+    if (!(cond)) {                                                             \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+      failures++;                                                              \
+    }                                                                          \
+  } while (0)
+
+#define STR(s) (const uint8_t *)(s), strlen(s)
+
+static int cache_equals(const ArrayStringCache *s, const char *expected) {
+  size_t len = arraystring_cache_len(s);
+  return len == strlen(expected) &&
+         memcmp(arraystring_cache_as_ptr(s), expected, len) == 0;
+}
+
+static int array32_equals(const ArrayString32 *s, const char *expected) {
+  size_t len = arraystring_32_len(s);
+  return len == strlen(expected) &&
+         memcmp(arraystring_32_as_ptr(s), expected, len) == 0;
+}
+
+static void test_layout(void) {
+  CHECK(sizeof(ArrayStringCache) == 64);
+  CHECK(_Alignof(ArrayStringCache) == 64);
+  CHECK(sizeof(ArrayString32) == 33);
+  CHECK(sizeof(ArrayString255) == 256);
+  CHECK(arraystring_cache_capacity() == 63);
+  CHECK(arraystring_32_capacity() == 32);
+  CHECK(arraystring_255_capacity() == 255);
+}
+
+static void test_cache(void) {
+  ArrayStringCache s = arraystring_cache_new();
+  CHECK(arraystring_cache_len(&s) == 0);
+
+  CHECK(arraystring_cache_try_push_str(&s, STR("My String")) ==
+        ARRAY_STRING_STATUS_OK);
+  CHECK(cache_equals(&s, "My String"));
+
+  CHECK(arraystring_cache_try_push(&s, '!') == ARRAY_STRING_STATUS_OK);
+  CHECK(cache_equals(&s, "My String!"));
+
+  uint32_t ch = 0;
+  CHECK(arraystring_cache_pop(&s, &ch) == ARRAY_STRING_STATUS_OK);
+  CHECK(ch == '!');
+
+  CHECK(arraystring_cache_remove(&s, 1, &ch) == ARRAY_STRING_STATUS_OK);
+  CHECK(ch == 'y');
+  CHECK(cache_equals(&s, "M String"));
+
+  CHECK(arraystring_cache_try_insert_str(&s, 1, STR("y")) ==
+        ARRAY_STRING_STATUS_OK);
+  CHECK(arraystring_cache_replace_range(&s, 0, 2, STR("Your")) ==
+        ARRAY_STRING_STATUS_OK);
+  CHECK(cache_equals(&s, "Your String"));
+
+  ArrayStringCache right;
+  CHECK(arraystring_cache_split_off(&s, 4, &right) == ARRAY_STRING_STATUS_OK);
+  CHECK(cache_equals(&s, "Your"));
+  CHECK(cache_equals(&right, " String"));
+
+  CHECK(arraystring_cache_trim(&right) == ARRAY_STRING_STATUS_OK);
+  CHECK(cache_equals(&right, "String"));
+
+  CHECK(arraystring_cache_clear(&s) == ARRAY_STRING_STATUS_OK);
+  CHECK(arraystring_cache_len(&s) == 0);
+  CHECK(arraystring_cache_pop(&s, &ch) == ARRAY_STRING_STATUS_OUT_OF_BOUNDS);
+}
+
+static void test_errors(void) {
+  ArrayString32 s;
+  char big[64];
+  memset(big, 'a', sizeof(big));
+
+  CHECK(arraystring_32_try_from_str((const uint8_t *)big, sizeof(big), &s) ==
+        ARRAY_STRING_STATUS_OUT_OF_BOUNDS);
+  CHECK(arraystring_32_from_str_truncate((const uint8_t *)big, sizeof(big),
+                                         &s) == ARRAY_STRING_STATUS_OK);
+  CHECK(arraystring_32_len(&s) == 32);
+  CHECK(arraystring_32_try_push(&s, 'a') == ARRAY_STRING_STATUS_OUT_OF_BOUNDS);
+
+  /* "🤔" */
+  CHECK(arraystring_32_try_from_str(STR("\xF0\x9F\xA4\x94"), &s) ==
+        ARRAY_STRING_STATUS_OK);
+  CHECK(arraystring_32_truncate(&s, 1) == ARRAY_STRING_STATUS_UTF8);
+  CHECK(arraystring_32_try_insert(&s, 2, 'a') == ARRAY_STRING_STATUS_UTF8);
+  CHECK(arraystring_32_try_insert(&s, 20, 'a') ==
+        ARRAY_STRING_STATUS_OUT_OF_BOUNDS);
+  CHECK(arraystring_32_try_push(&s, 0xD800) == ARRAY_STRING_STATUS_UTF8);
+
+  const uint8_t invalid[] = {0xC3, 0x28};
+  CHECK(arraystring_32_try_from_str(invalid, sizeof(invalid), &s) ==
+        ARRAY_STRING_STATUS_UTF8);
+
+  const uint16_t music[] = {0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069,
+                            0x0063};
+  CHECK(arraystring_32_try_from_utf16(music, 7, &s) == ARRAY_STRING_STATUS_OK);
+  CHECK(array32_equals(&s, "\xF0\x9D\x84\x9Emusic"));
+
+  const uint16_t invalid_utf16[] = {0xD834, 0xDD1E, 0x006d, 0xD800, 0x0069};
+  CHECK(arraystring_32_try_from_utf16(invalid_utf16, 5, &s) ==
+        ARRAY_STRING_STATUS_UTF16);
+  CHECK(arraystring_32_from_utf16_lossy_truncate(invalid_utf16, 5, &s) ==
+        ARRAY_STRING_STATUS_OK);
+  CHECK(array32_equals(&s, "\xF0\x9D\x84\x9Em\xEF\xBF\xBDi"));
+
+  CHECK(arraystring_32_try_push_str(NULL, STR("a")) ==
+        ARRAY_STRING_STATUS_NULL_POINTER);
+  CHECK(arraystring_32_try_push_str(&s, NULL, 1) ==
+        ARRAY_STRING_STATUS_NULL_POINTER);
+  CHECK(arraystring_32_try_push_str(&s, NULL, 0) == ARRAY_STRING_STATUS_OK);
+  CHECK(arraystring_32_len(NULL) == 0);
+}
+
+static void test_max(void) {
+  ArrayString255 s = arraystring_255_new();
+  for (int i = 0; i < 255; i++) {
+    CHECK(arraystring_255_try_push(&s, 'a') == ARRAY_STRING_STATUS_OK);
+  }
+  CHECK(arraystring_255_len(&s) == 255);
+  CHECK(arraystring_255_try_push(&s, 'a') == ARRAY_STRING_STATUS_OUT_OF_BOUNDS);
+  CHECK(arraystring_255_push_str_truncate(&s, STR("b")) ==
+        ARRAY_STRING_STATUS_OK);
+  CHECK(arraystring_255_len(&s) == 255);
+}
+
+int main(void) {
+  test_layout();
+  test_cache();
+  test_errors();
+  test_max();
+  if (failures != 0) {
+    fprintf(stderr, "%d checks failed\n", failures);
+    return 1;
+  }
+  return 0;
+}
@@ -0,0 +1,41 @@
+//! Compiles the C tests with the system `cc` against the static library and runs them
+
+use std::{env, path::Path, path::PathBuf, process::Command};
+
+/// Static library built by cargo alongside this test (`target/<profile>/deps`), or uplifted to `target/<profile>`
+fn static_lib() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let deps = exe.parent().unwrap();
+    let lib = deps.join("libarraystring_ffi.a");
+    if lib.exists() {
+        return lib;
+    }
+    deps.parent().unwrap().join("libarraystring_ffi.a")
+}
+
+#[test]
+#[cfg(unix)]
+fn c_tests() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("arraystring_c_tests");
+    let lib = static_lib();
+    assert!(
+        lib.exists(),
+        "static library not found at {}",
+        lib.display()
+    );
+
+    let status = Command::new(env::var_os("CC").unwrap_or_else(|| "cc".into()))
+        .args(["-std=c11", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(crate_dir.join("include"))
+        .arg(crate_dir.join("tests").join("c").join("arraystring.c"))
+        .arg(&lib)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&out)
+        .status()
+        .expect("unable to run the C compiler");
+    assert!(status.success(), "failed to compile C tests");
+
+    let status = Command::new(&out).status().unwrap();
+    assert!(status.success(), "C tests failed");
+}
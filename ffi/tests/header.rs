@@ -0,0 +1,29 @@
+//! Ensures the checked-in header matches the exported functions
+//!
+//! Run with `ARRAYSTRING_BLESS=1` to regenerate `include/arraystring.h`
+
+use std::{env, fs, path::Path};
+
+#[test]
+fn header_is_up_to_date() {
+    let crate_dir = env!("CARGO_MANIFEST_DIR");
+    let config = cbindgen::Config::from_root_or_default(crate_dir);
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_crate(crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("unable to generate bindings")
+        .write(&mut generated);
+
+    let path = Path::new(crate_dir).join("include").join("arraystring.h");
+    if env::var_os("ARRAYSTRING_BLESS").is_some() {
+        fs::write(&path, &generated).unwrap();
+        return;
+    }
+    let checked_in = fs::read(&path).unwrap_or_default();
+    assert!(
+        checked_in == generated,
+        "include/arraystring.h is outdated, run `ARRAYSTRING_BLESS=1 cargo test -p arraystring-ffi --test header`"
+    );
+}
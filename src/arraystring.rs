@@ -2,11 +2,16 @@
 #![allow(clippy::len_without_is_empty)]
 
 use crate::arraystring::sealed::ValidCapacity;
-use crate::utils::{is_char_boundary, is_inside_boundary};
+use crate::error::{ExtendError, FromIterError, Index};
+use crate::policy::{OverflowPolicy, Reject, Truncate};
+use crate::utils::{is_char_boundary, is_inside_boundary, is_inside_len};
 use crate::utils::{truncate_str, IntoLossy};
 use crate::{prelude::*, Error};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
 use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
-use core::{cmp::min, ops::*};
+use core::{cmp::min, ops::*, slice::SliceIndex};
+use core::{iter::once, marker::PhantomData};
 #[cfg(feature = "logs")]
 use log::{debug, trace};
 #[cfg(all(feature = "no-panic", not(debug_assertions)))]
@@ -18,21 +23,46 @@ use no_panic::no_panic;
 ///
 /// *Doesn't allocate memory on the heap and never panics (all panic branches are stripped at compile time)*
 ///
+/// `P` is the [`OverflowPolicy`] followed by [`push_str`], [`push`] and the traits that can't return an error, it defaults to [`Truncate`]
+///
+/// Without the `strict` feature lossy policies implement `From<&str>`, so their `TryFrom<&str>` is std's blanket impl: its error is `Infallible` and it truncates like `from`. Use [`try_from_str`], `parse` or [`Reject`] to get an error for text that doesn't fit
+///
+/// ```rust
+/// # use arraystring::{policy::Reject, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// # #[cfg(not(feature = "strict"))]
+/// assert_eq!(ArrayString::<4>::try_from("toolong"), Ok("tool".into()));
+/// assert_eq!(ArrayString::<4>::try_from_str("toolong"), Err(OutOfBounds::new(7, 4)));
+/// assert_eq!("toolong".parse::<ArrayString<4>>(), Err(OutOfBounds::new(7, 4)));
+/// assert_eq!(ArrayString::<4, Reject>::try_from("toolong"), Err(OutOfBounds::new(7, 4)));
+/// ```
+///
 /// [`capacity`]: ./struct.ArrayString.html#method.capacity
-#[derive(Copy, Clone)]
+/// [`OverflowPolicy`]: ./policy/trait.OverflowPolicy.html
+/// [`push_str`]: ./struct.ArrayString.html#method.push_str
+/// [`push`]: ./struct.ArrayString.html#method.push
+/// [`Truncate`]: ./policy/struct.Truncate.html
+/// [`try_from_str`]: ./struct.ArrayString.html#method.try_from_str
+/// [`Reject`]: ./policy/struct.Reject.html
 #[cfg_attr(
     feature = "diesel-traits",
     derive(diesel::AsExpression, diesel::FromSqlRow)
 )]
 #[cfg_attr(feature = "diesel-traits", diesel(sql_type = diesel::sql_types::Text))]
-pub struct ArrayString<const N: usize> {
+pub struct ArrayString<const N: usize, P = Truncate> {
     /// Array type corresponding to specified `SIZE`
+    ///
+    /// Bytes after `size` are always zero, so equality and ordering can compare the whole array
     pub(crate) array: [u8; N],
     /// Current string size
     pub(crate) size: u8,
+    /// [`OverflowPolicy`] used by the traits that can't fail, it doesn't take any space
+    ///
+    /// [`OverflowPolicy`]: ./policy/trait.OverflowPolicy.html
+    pub(crate) policy: PhantomData<fn() -> P>,
 }
 
-impl<const N: usize> ArrayString<N>
+impl<const N: usize, P> ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -49,6 +79,7 @@ where
         Self {
             array: [0; N],
             size: 0,
+            policy: PhantomData,
         }
     }
 
@@ -105,6 +136,8 @@ where
 
     /// Creates new `ArrayString` from string slice iterator if total length is lower or equal to [`capacity`], otherwise returns an error.
     ///
+    /// The error hands back the string built so far and the items that weren't consumed, starting with the one that didn't fit
+    ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
     ///
     /// ```rust
@@ -113,20 +146,28 @@ where
     /// let string = ArrayString::<255>::try_from_iterator(&["My String", " My Other String"][..])?;
     /// assert_eq!(string.as_str(), "My String My Other String");
     ///
-    /// let out_of_bounds = (0..100).map(|_| "000");
-    /// assert!(ArrayString::<23>::try_from_iterator(out_of_bounds).is_err());
+    /// let err = ArrayString::<8>::try_from_iterator(["abc", "def", "ghi", "jkl"]).unwrap_err();
+    /// assert_eq!(err.error(), OutOfBounds::new(9, 8));
+    /// let (partial, rest) = err.into_inner();
+    /// assert_eq!(partial.as_str(), "abcdef");
+    /// assert_eq!(rest.collect::<Vec<_>>(), ["ghi", "jkl"]);
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn try_from_iterator(
-        iter: impl IntoIterator<Item = impl AsRef<str>>,
-    ) -> Result<Self, OutOfBounds> {
+    pub fn try_from_iterator<I>(iter: I) -> Result<Self, FromIterError<Self, I::IntoIter>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
         trace!("FromIterator");
         let mut out = Self::new();
-        for s in iter {
-            out.try_push_str(s)?;
+        let mut iter = iter.into_iter();
+        while let Some(s) = iter.next() {
+            if let Err(err) = out.try_push_str(s) {
+                return Err(err.map(|s| (out, once(s).chain(iter))));
+            }
         }
         Ok(out)
     }
@@ -156,8 +197,8 @@ where
         trace!("FromIterator truncate");
         let mut out = Self::new();
         for s in iter {
-            if out.try_push_str(s.as_ref()).is_err() {
-                out.push_str_truncate(s);
+            if let Err(err) = out.try_push_str(s) {
+                out.push_str_truncate(err.into_inner());
                 break;
             }
         }
@@ -166,6 +207,8 @@ where
 
     /// Creates new `ArrayString` from char iterator if total length is lower or equal to [`capacity`], otherwise returns an error.
     ///
+    /// The error hands back the string built so far and the chars that weren't consumed, starting with the one that didn't fit
+    ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
     ///
     /// ```rust
@@ -175,18 +218,26 @@ where
     /// let string = ArrayString::<23>::try_from_chars("My String".chars())?;
     /// assert_eq!(string.as_str(), "My String");
     ///
-    /// let out_of_bounds = "0".repeat(ArrayString::<23>::capacity() + 1);
-    /// assert!(ArrayString::<23>::try_from_chars(out_of_bounds.chars()).is_err());
+    /// let err = ArrayString::<4>::try_from_chars("ab🤔".chars()).unwrap_err();
+    /// let (partial, rest) = err.into_inner();
+    /// assert_eq!(partial.as_str(), "ab");
+    /// assert_eq!(rest.collect::<String>(), "🤔");
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn try_from_chars(iter: impl IntoIterator<Item = char>) -> Result<Self, OutOfBounds> {
+    pub fn try_from_chars<I>(iter: I) -> Result<Self, FromIterError<Self, I::IntoIter>>
+    where
+        I: IntoIterator<Item = char>,
+    {
         trace!("TryFrom chars");
         let mut out = Self::new();
-        for c in iter {
-            out.try_push(c)?;
+        let mut iter = iter.into_iter();
+        while let Some(c) = iter.next() {
+            if let Err(err) = out.try_push(c) {
+                return Err(err.map(|c| (out, once(c).chain(iter))));
+            }
         }
         Ok(out)
     }
@@ -238,7 +289,7 @@ where
     /// assert_eq!(ArrayString::<23>::try_from_utf16(invalid_utf16), Err(Error::Utf16));
     ///
     /// let out_of_bounds: Vec<_> = (0..300).map(|_| 0).collect();
-    /// assert_eq!(ArrayString::<23>::try_from_utf16(out_of_bounds), Err(Error::OutOfBounds));
+    /// assert_eq!(ArrayString::<23>::try_from_utf16(out_of_bounds), Err(Error::OutOfBounds { needed: 24, capacity: 23 }));
     /// # Ok(())
     /// # }
     /// ```
@@ -358,6 +409,48 @@ where
         self.as_mut()
     }
 
+    /// Returns a subslice of the string, or `None` if the range is out of bounds or not at utf-8 char boundaries
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<23>::try_from_str("AB🤔CD")?;
+    /// let idx = s.find('🤔').unwrap_or_default();
+    /// assert_eq!(s.get(..idx), Some("AB"));
+    /// assert_eq!(s.get(idx + 4..), Some("CD"));
+    /// assert_eq!(s.get(3..), None);
+    /// assert_eq!(s.get(..20), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn get<I: SliceIndex<str>>(&self, range: I) -> Option<&I::Output> {
+        trace!("Get");
+        self.as_str().get(range)
+    }
+
+    /// Returns a mutable subslice of the string, or `None` if the range is out of bounds or not at utf-8 char boundaries
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<23>::try_from_str("ab🤔cd")?;
+    /// s.get_mut(..2).map(str::make_ascii_uppercase);
+    /// assert_eq!(s.as_str(), "AB🤔cd");
+    /// assert!(s.get_mut(..3).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn get_mut<I: SliceIndex<str>>(&mut self, range: I) -> Option<&mut I::Output> {
+        trace!("Get mut");
+        self.as_mut_str().get_mut(range)
+    }
+
     /// Returns maximum string capacity, defined at compile time, it will never change
     ///
     /// ```rust
@@ -372,6 +465,8 @@ where
 
     /// Pushes string slice to the end of the `ArrayString` if total size is lower or equal to [`capacity`], otherwise returns an error.
     ///
+    /// The error hands back `string`, so it can be retried or routed elsewhere
+    ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
     ///
     /// ```rust
@@ -382,17 +477,18 @@ where
     /// s.try_push_str(" My other String")?;
     /// assert_eq!(s.as_str(), "My String My other String");
     ///
-    /// assert!(s.try_push_str("0".repeat(ArrayString::<255>::capacity())).is_err());
+    /// let err = s.try_push_str("0".repeat(ArrayString::<255>::capacity())).unwrap_err();
+    /// assert_eq!(err.into_inner(), "0".repeat(ArrayString::<255>::capacity()));
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn try_push_str(&mut self, string: impl AsRef<str>) -> Result<(), OutOfBounds> {
-        trace!("Push str: {}", string.as_ref());
-        let len = self.len();
-        self.replace_range(len..len, string)
-            .map_err(|_| OutOfBounds)
+    pub fn try_push_str<S: AsRef<str>>(&mut self, string: S) -> Result<(), CapacityError<S>> {
+        match Reject::push_str(self, string.as_ref()) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(CapacityError::new(string, err)),
+        }
     }
 
     /// Pushes string slice to the end of the `ArrayString` truncating total size if bigger than [`capacity`].
@@ -416,8 +512,7 @@ where
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     pub fn push_str_truncate(&mut self, string: impl AsRef<str>) {
-        trace!("Push str truncate: {}", string.as_ref());
-        let _ = self.try_push_str(truncate_str(string.as_ref(), Self::capacity() - self.len()));
+        let _ = Truncate::push_str(self, string.as_ref());
     }
 
     /// Inserts character to the end of the `ArrayString` erroring if total size if bigger than [`capacity`].
@@ -433,16 +528,54 @@ where
     /// assert_eq!(s.as_str(), "My String!");
     ///
     /// let mut s = ArrayString::<23>::try_from_str(&"0".repeat(ArrayString::<23>::capacity()))?;
-    /// assert!(s.try_push('!').is_err());
+    /// assert_eq!(s.try_push('!').unwrap_err().into_inner(), '!');
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn try_push(&mut self, ch: char) -> Result<(), OutOfBounds> {
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError<char>> {
         trace!("Push: {}", ch);
         let mut buf = [0; 4];
         self.try_push_str(ch.encode_utf8(&mut buf))
+            .map_err(|err| err.map(|_| ch))
+    }
+
+    /// Pushes every item of the iterator, chars or string slices, until one doesn't fit in [`capacity`], then returns an error.
+    ///
+    /// Items are pushed whole, so the string keeps everything before the item that didn't fit and the error hands back the rest of the iterator, starting with that item
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<8>::try_from_str("ab")?;
+    /// s.try_extend(['c', 'd'])?;
+    ///
+    /// let err = s.try_extend(["ef", "ghi", "j"]).unwrap_err();
+    /// assert_eq!(err.error(), OutOfBounds::new(9, 8));
+    /// assert_eq!(s.as_str(), "abcdef");
+    /// assert_eq!(err.into_inner().collect::<Vec<_>>(), ["ghi", "j"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), ExtendError<I::IntoIter>>
+    where
+        I: IntoIterator,
+        I::Item: ExtendItem,
+    {
+        trace!("Try extend");
+        let mut iter = iter.into_iter();
+        while let Some(item) = iter.next() {
+            if let Err(err) = item.try_push_to(self) {
+                return Err(CapacityError::new(once(item).chain(iter), err));
+            }
+        }
+        Ok(())
     }
 
     /// Truncates `ArrayString` to specified size (if smaller than current size and a valid utf-8 char index).
@@ -470,7 +603,7 @@ where
     pub fn truncate(&mut self, size: usize) -> Result<(), Utf8> {
         debug!("Truncate: {}", size);
         let len = min(self.len(), size);
-        is_char_boundary(self, len).map(|()| self.size = len.into_lossy())
+        is_char_boundary(self, len).map(|()| self.set_len(len))
     }
 
     /// Removes last character from `ArrayString`, if any.
@@ -490,10 +623,10 @@ where
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     pub fn pop(&mut self) -> Option<char> {
         debug!("Pop");
-        self.as_str().chars().last().map(|ch| {
-            self.size -= ch.len_utf8().into_lossy();
-            ch
-        })
+        self.as_str()
+            .chars()
+            .last()
+            .inspect(|ch| self.set_len(self.len() - ch.len_utf8()))
     }
 
     /// Removes whitespaces from the beggining and end of the string
@@ -526,7 +659,7 @@ where
         let mut end = self.len();
         for (pos, char) in self.as_str().char_indices().rev() {
             if pos < start {
-                self.size = 0;
+                self.clear();
                 return;
             }
             if !char.is_whitespace() {
@@ -546,9 +679,9 @@ where
     /// # fn main() -> Result<(), Error> {
     /// # #[cfg(not(miri))] let _ = env_logger::try_init();
     /// let mut s = ArrayString::<23>::try_from_str("ABCD🤔")?;
-    /// assert_eq!(s.remove("ABCD🤔".len()), Err(Error::OutOfBounds));
-    /// assert_eq!(s.remove(10), Err(Error::OutOfBounds));
-    /// assert_eq!(s.remove(6), Err(Error::Utf8));
+    /// assert_eq!(s.remove("ABCD🤔".len()), Err(Error::Index { index: 8, len: 8 }));
+    /// assert_eq!(s.remove(10), Err(Error::Index { index: 10, len: 8 }));
+    /// assert_eq!(s.remove(6), Err(Error::Utf8 { index: 6, valid_up_to: 4 }));
     /// assert_eq!(s.remove(0), Ok('A'));
     /// assert_eq!(s.as_str(), "BCD🤔");
     /// assert_eq!(s.remove(2), Ok('D'));
@@ -560,7 +693,9 @@ where
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     pub fn remove(&mut self, idx: usize) -> Result<char, Error> {
         debug!("Remove: {}", idx);
-        is_inside_boundary(idx.saturating_add(1), self.len())?;
+        if idx >= self.len() {
+            return Err(Index::new(idx, self.len()).into());
+        }
         is_char_boundary(self, idx)?;
 
         let mut end = if idx == self.len() { idx } else { idx + 1 };
@@ -571,10 +706,10 @@ where
         let ch = self
             .as_str()
             .get(idx..end)
-            .ok_or(Error::OutOfBounds)?
+            .ok_or(Index::new(end, self.len()))?
             .chars()
             .next()
-            .ok_or(Error::Utf8)?;
+            .ok_or(Utf8::new(idx, idx))?;
         self.replace_range(idx..idx + ch.len_utf8(), "")?;
         Ok(ch)
     }
@@ -601,10 +736,10 @@ where
 
     /// Inserts character at specified index, returning error if total length is bigger than [`capacity`].
     ///
-    /// Returns [`OutOfBounds`] if `idx` is out of bounds and [`Utf8`] if `idx` is not a char position
+    /// Returns [`Index`] if `idx` is out of bounds and [`Utf8`] if `idx` is not a char position, the error hands back `ch`
     ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
-    /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
+    /// [`Index`]: ./error/enum.Error.html#variant.Index
     /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
     ///
     /// ```rust
@@ -615,28 +750,31 @@ where
     /// s.try_insert(1, 'A')?;
     /// s.try_insert(2, 'B')?;
     /// assert_eq!(s.as_str(), "AABBCD🤔");
-    /// assert_eq!(s.try_insert(20, 'C'), Err(Error::OutOfBounds));
-    /// assert_eq!(s.try_insert(8, 'D'), Err(Error::Utf8));
+    /// assert_eq!(s.try_insert(20, 'C'), Err(CapacityError::new('C', Error::Index { index: 20, len: 10 })));
+    /// assert_eq!(s.try_insert(8, 'D'), Err(CapacityError::new('D', Error::Utf8 { index: 8, valid_up_to: 6 })));
     ///
     /// let mut s = ArrayString::<23>::try_from_str(&"0".repeat(ArrayString::<23>::capacity()))?;
-    /// assert_eq!(s.try_insert(0, 'C'), Err(Error::OutOfBounds));
+    /// assert_eq!(s.try_insert(0, 'C'), Err(CapacityError::new('C', Error::OutOfBounds { needed: 24, capacity: 23 })));
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn try_insert(&mut self, idx: usize, ch: char) -> Result<(), Error> {
+    pub fn try_insert(&mut self, idx: usize, ch: char) -> Result<(), CapacityError<char, Error>> {
         let mut buf = [0; 4];
         self.try_insert_str(idx, ch.encode_utf8(&mut buf))
+            .map_err(|err| err.map(|_| ch))
     }
 
     /// Inserts string slice at specified index, returning error if total length is bigger than [`capacity`].
     ///
-    /// Returns [`OutOfBounds`] if `idx` is out of bounds
+    /// Returns [`Index`] if `idx` is out of bounds
     /// Returns [`Utf8`] if `idx` is not a char position
     ///
+    /// The error hands back `string`, so it can be retried or routed elsewhere
+    ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
-    /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
+    /// [`Index`]: ./error/enum.Error.html#variant.Index
     /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
     ///
     /// ```rust
@@ -646,27 +784,38 @@ where
     /// let mut s = ArrayString::<23>::try_from_str("ABCD🤔")?;
     /// s.try_insert_str(1, "AB")?;
     /// s.try_insert_str(1, "BC")?;
-    /// assert_eq!(s.try_insert_str(1, "0".repeat(ArrayString::<23>::capacity())), Err(Error::OutOfBounds));
+    /// let err = s.try_insert_str(1, "0".repeat(ArrayString::<23>::capacity())).unwrap_err();
+    /// assert_eq!(err.error(), Error::OutOfBounds { needed: 35, capacity: 23 });
+    /// assert_eq!(err.into_inner(), "0".repeat(ArrayString::<23>::capacity()));
     /// assert_eq!(s.as_str(), "ABCABBCD🤔");
-    /// assert_eq!(s.try_insert_str(20, "C"), Err(Error::OutOfBounds));
-    /// assert_eq!(s.try_insert_str(10, "D"), Err(Error::Utf8));
+    /// assert_eq!(s.try_insert_str(20, "C"), Err(CapacityError::new("C", Error::Index { index: 20, len: 12 })));
+    /// assert_eq!(s.try_insert_str(10, "D"), Err(CapacityError::new("D", Error::Utf8 { index: 10, valid_up_to: 8 })));
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn try_insert_str(&mut self, idx: usize, string: impl AsRef<str>) -> Result<(), Error> {
+    pub fn try_insert_str<S: AsRef<str>>(
+        &mut self,
+        idx: usize,
+        string: S,
+    ) -> Result<(), CapacityError<S, Error>> {
         trace!("Try insert at {idx} str: {:?} to {self:?}", string.as_ref());
-        is_inside_boundary(idx, self.len())?;
-        self.replace_range(idx..idx, string)
+        let inserted = is_inside_len(idx, self.len())
+            .map_err(Error::from)
+            .and_then(|()| self.replace_range(idx..idx, string.as_ref()));
+        match inserted {
+            Ok(()) => Ok(()),
+            Err(err) => Err(CapacityError::new(string, err)),
+        }
     }
 
     /// Inserts string slice at specified index, truncating size if bigger than [`capacity`].
     ///
-    /// Returns [`OutOfBounds`] if `idx` is out of bounds and [`Utf8`] if `idx` is not a char position
+    /// Returns [`Index`] if `idx` is out of bounds and [`Utf8`] if `idx` is not a char position
     ///
     /// [`capacity`]: ./struct.ArrayString.html#method.capacity
-    /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
+    /// [`Index`]: ./error/enum.Error.html#variant.Index
     /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
     ///
     /// ```rust
@@ -678,8 +827,8 @@ where
     /// s.insert_str_truncate(1, "BC")?;
     /// assert_eq!(s.as_str(), "ABCABBCD🤔");
     ///
-    /// assert_eq!(s.insert_str_truncate(20, "C"), Err(Error::OutOfBounds));
-    /// assert_eq!(s.insert_str_truncate(10, "D"), Err(Error::Utf8));
+    /// assert_eq!(s.insert_str_truncate(20, "C"), Err(Error::Index { index: 20, len: 12 }));
+    /// assert_eq!(s.insert_str_truncate(10, "D"), Err(Error::Utf8 { index: 10, valid_up_to: 8 }));
     ///
     /// s.clear();
     /// s.insert_str_truncate(0, "0".repeat(ArrayString::<23>::capacity() + 10))?;
@@ -725,9 +874,9 @@ where
 
     /// Splits `ArrayString` in two if `at` is smaller than `self.len()`.
     ///
-    /// Returns [`Utf8`] if `at` does not lie at a valid utf-8 char boundary and [`OutOfBounds`] if it's out of bounds
+    /// Returns [`Utf8`] if `at` does not lie at a valid utf-8 char boundary and [`Index`] if it's out of bounds
     ///
-    /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
+    /// [`Index`]: ./error/enum.Error.html#variant.Index
     /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
     ///
     /// ```rust
@@ -737,8 +886,8 @@ where
     /// let mut s = ArrayString::<23>::try_from_str("AB🤔CD")?;
     /// assert_eq!(s.split_off(6)?.as_str(), "CD");
     /// assert_eq!(s.as_str(), "AB🤔");
-    /// assert_eq!(s.split_off(20), Err(Error::OutOfBounds));
-    /// assert_eq!(s.split_off(4), Err(Error::Utf8));
+    /// assert_eq!(s.split_off(20), Err(Error::Index { index: 20, len: 6 }));
+    /// assert_eq!(s.split_off(4), Err(Error::Utf8 { index: 4, valid_up_to: 2 }));
     /// # Ok(())
     /// # }
     /// ```
@@ -750,6 +899,93 @@ where
         Ok(drained)
     }
 
+    /// Moves string into an `ArrayString` of capacity `M`, without checking the length as `M >= N` is asserted at compile time
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<20>::try_from_str("My String")?;
+    /// let wide: ArrayString<64> = s.resize();
+    /// assert_eq!(wide.as_str(), "My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Shrinking fails to compile:
+    ///
+    /// ```rust,compile_fail
+    /// # use arraystring::prelude::*;
+    /// let narrow: ArrayString<10> = ArrayString::<20>::new().resize();
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn resize<const M: usize>(self) -> ArrayString<M, P>
+    where
+        ArrayString<M, P>: ValidCapacity,
+    {
+        const {
+            assert!(
+                M >= N,
+                "ArrayString::resize can't shrink, use try_resize or resize_truncate"
+            )
+        };
+        trace!("Resize: {N} to {M}");
+        let mut array = [0; M];
+        for (to, from) in array.iter_mut().zip(self.as_bytes()) {
+            *to = *from;
+        }
+        ArrayString {
+            array,
+            size: self.size,
+            policy: PhantomData,
+        }
+    }
+
+    /// Moves string into an `ArrayString` of capacity `M` if it fits, otherwise returns an error
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<64>::try_from_str("My String")?;
+    /// assert_eq!(s.try_resize::<10>()?.as_str(), "My String");
+    /// assert_eq!(s.try_resize::<5>(), Err(OutOfBounds::new(9, 5)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_resize<const M: usize>(self) -> Result<ArrayString<M, P>, OutOfBounds>
+    where
+        ArrayString<M, P>: ValidCapacity,
+    {
+        trace!("Try resize: {N} to {M}");
+        ArrayString::try_from_str(self.as_str())
+    }
+
+    /// Moves string into an `ArrayString` of capacity `M`, truncating it (at a char boundary) if it doesn't fit
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = ArrayString::<64>::try_from_str("ABCD🤔")?;
+    /// assert_eq!(s.resize_truncate::<5>().as_str(), "ABCD");
+    /// assert_eq!(s.resize_truncate::<8>().as_str(), "ABCD🤔");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn resize_truncate<const M: usize>(self) -> ArrayString<M, P>
+    where
+        ArrayString<M, P>: ValidCapacity,
+    {
+        trace!("Resize truncate: {N} to {M}");
+        ArrayString::from_str_truncate(self.as_str())
+    }
+
     /// Empties `ArrayString`
     ///
     /// ```rust
@@ -767,7 +1003,7 @@ where
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     pub fn clear(&mut self) {
         trace!("Clear");
-        self.size = 0;
+        self.set_len(0);
     }
 
     /// Creates a draining iterator that removes the specified range in the `ArrayString` and yields the removed chars.
@@ -782,14 +1018,14 @@ where
     /// assert_eq!(s.drain(..3)?.collect::<Vec<_>>(), vec!['A', 'B', 'C']);
     /// assert_eq!(s.as_str(), "D🤔");
     ///
-    /// assert_eq!(s.drain(3..), Err(Error::Utf8));
-    /// assert_eq!(s.drain(10..), Err(Error::OutOfBounds));
+    /// assert_eq!(s.drain(3..), Err(Error::Utf8 { index: 3, valid_up_to: 1 }));
+    /// assert_eq!(s.drain(10..), Err(Error::Index { index: 10, len: 5 }));
     /// # Ok(())
     /// # }
     /// ```
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Result<Drain<N>, Error> {
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Result<Drain<N, P>, Error> {
         let start = match range.start_bound() {
             Bound::Included(t) => *t,
             Bound::Excluded(t) => t.saturating_add(1),
@@ -802,8 +1038,8 @@ where
         };
 
         debug!("Drain iterator (len: {}): {start}..{end}", self.len());
-        is_inside_boundary(start, end)?;
-        is_inside_boundary(end, self.len())?;
+        is_inside_len(start, end)?;
+        is_inside_len(end, self.len())?;
         is_char_boundary(self, start)?;
         is_char_boundary(self, end)?;
         debug_assert!(start <= end && end <= self.len());
@@ -829,9 +1065,9 @@ where
     /// s.replace_range(2..4, "EFGHI")?;
     /// assert_eq!(s, "ABEFGHI🤔");
     ///
-    /// assert_eq!(s.replace_range(9.., "J"), Err(Error::Utf8));
-    /// assert_eq!(s.replace_range(..90, "K"), Err(Error::OutOfBounds));
-    /// assert_eq!(s.replace_range(0..1, "0".repeat(ArrayString::<23>::capacity())), Err(Error::OutOfBounds));
+    /// assert_eq!(s.replace_range(9.., "J"), Err(Error::Utf8 { index: 9, valid_up_to: 7 }));
+    /// assert_eq!(s.replace_range(..90, "K"), Err(Error::Index { index: 90, len: 11 }));
+    /// assert_eq!(s.replace_range(0..1, "0".repeat(ArrayString::<23>::capacity())), Err(Error::OutOfBounds { needed: 33, capacity: 23 }));
     /// # Ok(())
     /// # }
     /// ```
@@ -864,8 +1100,8 @@ where
         if start == end && str.is_empty() {
             return Ok(());
         }
-        is_inside_boundary(start, end)?;
-        is_inside_boundary(end, self.len())?;
+        is_inside_len(start, end)?;
+        is_inside_len(end, self.len())?;
         is_inside_boundary(str.len(), Self::capacity())?;
         is_char_boundary(self, start)?;
         is_char_boundary(self, end)?;
@@ -887,268 +1123,465 @@ where
                 core::ptr::copy_nonoverlapping(str.as_ptr(), cut_start, str.len());
             }
         }
-        self.size = (self.len() + str.len() + start - end).into_lossy();
+        self.set_len(self.len() + str.len() + start - end);
         Ok(())
     }
+
+    /// Sets string size, zeroing the removed bytes to keep the array tail zeroed
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn set_len(&mut self, size: usize) {
+        let len = self.len();
+        if let Some(tail) = self.array.get_mut(size..len) {
+            tail.fill(0);
+        }
+        self.size = size.into_lossy();
+    }
+
+    /// Checks that every byte after the string is zero
+    #[inline]
+    pub(crate) fn is_tail_zeroed(&self) -> bool {
+        self.array
+            .get(self.len()..)
+            .is_none_or(|tail| tail.iter().all(|b| *b == 0))
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> ArrayString<N, P>
+where
+    Self: ValidCapacity,
+{
+    /// Pushes string slice to the end of the `ArrayString` following its [`OverflowPolicy`], returning [`OutOfBounds`] if it didn't fit whole
+    ///
+    /// The string is left as the policy handled it even on error, [`Truncate`] (the default) keeps the part that fits
+    ///
+    /// [`OverflowPolicy`]: ./policy/trait.OverflowPolicy.html
+    /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+    /// [`Truncate`]: ./policy/struct.Truncate.html
+    ///
+    /// ```rust
+    /// # use arraystring::{policy::Reject, prelude::*};
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<5>::try_from_str("My")?;
+    /// s.push_str(" St")?;
+    /// assert_eq!(s.push_str("ring"), Err(OutOfBounds::new(9, 5)));
+    /// assert_eq!(s.as_str(), "My St");
+    ///
+    /// let mut s = ArrayString::<5, Reject>::try_from_str("My")?;
+    /// assert_eq!(s.push_str(" String"), Err(OutOfBounds::new(9, 5)));
+    /// assert_eq!(s.as_str(), "My");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn push_str(&mut self, string: impl AsRef<str>) -> Result<(), OutOfBounds> {
+        P::push_str(self, string.as_ref())
+    }
+
+    /// Pushes character to the end of the `ArrayString` following its [`OverflowPolicy`], returning [`OutOfBounds`] if it didn't fit
+    ///
+    /// [`OverflowPolicy`]: ./policy/trait.OverflowPolicy.html
+    /// [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+    ///
+    /// ```rust
+    /// # use arraystring::{policy::{Ellipsis, TruncateWithMarker}, prelude::*};
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<5, TruncateWithMarker<Ellipsis>>::try_from_str("abcd")?;
+    /// s.push('e')?;
+    /// assert_eq!(s.push('f'), Err(OutOfBounds::new(6, 5)));
+    /// assert_eq!(s.as_str(), "ab…");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn push(&mut self, ch: char) -> Result<(), OutOfBounds> {
+        P::push_str(self, ch.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl<const N: usize, P> ArrayString<N, P>
+where
+    Self: ValidCapacity,
+{
+    /// Changes the [`OverflowPolicy`], keeping the text
+    ///
+    /// [`OverflowPolicy`]: ./policy/trait.OverflowPolicy.html
+    ///
+    /// ```rust
+    /// # use arraystring::{policy::Reject, prelude::*};
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let strict = ArrayString::<5, Reject>::try_from_str("My")?;
+    /// let mut lossy: ArrayString<5> = strict.with_policy();
+    /// assert_eq!(lossy.push_str(" String"), Err(OutOfBounds::new(9, 5)));
+    /// assert_eq!(lossy.as_str(), "My St");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn with_policy<Q>(self) -> ArrayString<N, Q> {
+        ArrayString {
+            array: self.array,
+            size: self.size,
+            policy: PhantomData,
+        }
+    }
+}
+
+/// Items accepted by [`try_extend`]: chars and string slices, owned or borrowed
+///
+/// [`try_extend`]: ./struct.ArrayString.html#method.try_extend
+pub trait ExtendItem {
+    /// Pushes the whole item if it fits, otherwise leaves `string` untouched and returns an error
+    fn try_push_to<const N: usize, P>(
+        &self,
+        string: &mut ArrayString<N, P>,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity;
+}
+
+impl ExtendItem for char {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn try_push_to<const N: usize, P>(
+        &self,
+        string: &mut ArrayString<N, P>,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        string.try_push(*self).map_err(|err| err.error())
+    }
+}
+
+impl ExtendItem for &char {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn try_push_to<const N: usize, P>(
+        &self,
+        string: &mut ArrayString<N, P>,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        (**self).try_push_to(string)
+    }
+}
+
+impl ExtendItem for &str {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn try_push_to<const N: usize, P>(
+        &self,
+        string: &mut ArrayString<N, P>,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        string.try_push_str(self).map_err(|err| err.error())
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+impl ExtendItem for String {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn try_push_to<const N: usize, P>(
+        &self,
+        string: &mut ArrayString<N, P>,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        self.as_str().try_push_to(string)
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+impl ExtendItem for Cow<'_, str> {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn try_push_to<const N: usize, P>(
+        &self,
+        string: &mut ArrayString<N, P>,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        self.as_ref().try_push_to(string)
+    }
 }
 
 /// Temporary hack until const generics constraints are stable
 pub(crate) mod sealed {
     use super::*;
+    /// Capacities supported by `ArrayString`, from 1 to 255 bytes
+    ///
+    /// Use it to bound code that is generic over the capacity, like custom [`OverflowPolicy`] impls. It's implemented for every supported `ArrayString<N, P>` and can't be implemented outside of this crate
+    ///
+    /// [`OverflowPolicy`]: ./policy/trait.OverflowPolicy.html
     pub trait ValidCapacity {}
-    impl ValidCapacity for ArrayString<1> {}
-    impl ValidCapacity for ArrayString<2> {}
-    impl ValidCapacity for ArrayString<3> {}
-    impl ValidCapacity for ArrayString<4> {}
-    impl ValidCapacity for ArrayString<5> {}
-    impl ValidCapacity for ArrayString<6> {}
-    impl ValidCapacity for ArrayString<7> {}
-    impl ValidCapacity for ArrayString<8> {}
-    impl ValidCapacity for ArrayString<9> {}
-    impl ValidCapacity for ArrayString<10> {}
-    impl ValidCapacity for ArrayString<11> {}
-    impl ValidCapacity for ArrayString<12> {}
-    impl ValidCapacity for ArrayString<13> {}
-    impl ValidCapacity for ArrayString<14> {}
-    impl ValidCapacity for ArrayString<15> {}
-    impl ValidCapacity for ArrayString<16> {}
-    impl ValidCapacity for ArrayString<17> {}
-    impl ValidCapacity for ArrayString<18> {}
-    impl ValidCapacity for ArrayString<19> {}
-    impl ValidCapacity for ArrayString<20> {}
-    impl ValidCapacity for ArrayString<21> {}
-    impl ValidCapacity for ArrayString<22> {}
-    impl ValidCapacity for ArrayString<23> {}
-    impl ValidCapacity for ArrayString<24> {}
-    impl ValidCapacity for ArrayString<25> {}
-    impl ValidCapacity for ArrayString<26> {}
-    impl ValidCapacity for ArrayString<27> {}
-    impl ValidCapacity for ArrayString<28> {}
-    impl ValidCapacity for ArrayString<29> {}
-    impl ValidCapacity for ArrayString<30> {}
-    impl ValidCapacity for ArrayString<31> {}
-    impl ValidCapacity for ArrayString<32> {}
-    impl ValidCapacity for ArrayString<33> {}
-    impl ValidCapacity for ArrayString<34> {}
-    impl ValidCapacity for ArrayString<35> {}
-    impl ValidCapacity for ArrayString<36> {}
-    impl ValidCapacity for ArrayString<37> {}
-    impl ValidCapacity for ArrayString<38> {}
-    impl ValidCapacity for ArrayString<39> {}
-    impl ValidCapacity for ArrayString<40> {}
-    impl ValidCapacity for ArrayString<41> {}
-    impl ValidCapacity for ArrayString<42> {}
-    impl ValidCapacity for ArrayString<43> {}
-    impl ValidCapacity for ArrayString<44> {}
-    impl ValidCapacity for ArrayString<45> {}
-    impl ValidCapacity for ArrayString<46> {}
-    impl ValidCapacity for ArrayString<47> {}
-    impl ValidCapacity for ArrayString<48> {}
-    impl ValidCapacity for ArrayString<49> {}
-    impl ValidCapacity for ArrayString<50> {}
-    impl ValidCapacity for ArrayString<51> {}
-    impl ValidCapacity for ArrayString<52> {}
-    impl ValidCapacity for ArrayString<53> {}
-    impl ValidCapacity for ArrayString<54> {}
-    impl ValidCapacity for ArrayString<55> {}
-    impl ValidCapacity for ArrayString<56> {}
-    impl ValidCapacity for ArrayString<57> {}
-    impl ValidCapacity for ArrayString<58> {}
-    impl ValidCapacity for ArrayString<59> {}
-    impl ValidCapacity for ArrayString<60> {}
-    impl ValidCapacity for ArrayString<61> {}
-    impl ValidCapacity for ArrayString<62> {}
-    impl ValidCapacity for ArrayString<63> {}
-    impl ValidCapacity for ArrayString<64> {}
-    impl ValidCapacity for ArrayString<65> {}
-    impl ValidCapacity for ArrayString<66> {}
-    impl ValidCapacity for ArrayString<67> {}
-    impl ValidCapacity for ArrayString<68> {}
-    impl ValidCapacity for ArrayString<69> {}
-    impl ValidCapacity for ArrayString<70> {}
-    impl ValidCapacity for ArrayString<71> {}
-    impl ValidCapacity for ArrayString<72> {}
-    impl ValidCapacity for ArrayString<73> {}
-    impl ValidCapacity for ArrayString<74> {}
-    impl ValidCapacity for ArrayString<75> {}
-    impl ValidCapacity for ArrayString<76> {}
-    impl ValidCapacity for ArrayString<77> {}
-    impl ValidCapacity for ArrayString<78> {}
-    impl ValidCapacity for ArrayString<79> {}
-    impl ValidCapacity for ArrayString<80> {}
-    impl ValidCapacity for ArrayString<81> {}
-    impl ValidCapacity for ArrayString<82> {}
-    impl ValidCapacity for ArrayString<83> {}
-    impl ValidCapacity for ArrayString<84> {}
-    impl ValidCapacity for ArrayString<85> {}
-    impl ValidCapacity for ArrayString<86> {}
-    impl ValidCapacity for ArrayString<87> {}
-    impl ValidCapacity for ArrayString<88> {}
-    impl ValidCapacity for ArrayString<89> {}
-    impl ValidCapacity for ArrayString<90> {}
-    impl ValidCapacity for ArrayString<91> {}
-    impl ValidCapacity for ArrayString<92> {}
-    impl ValidCapacity for ArrayString<93> {}
-    impl ValidCapacity for ArrayString<94> {}
-    impl ValidCapacity for ArrayString<95> {}
-    impl ValidCapacity for ArrayString<96> {}
-    impl ValidCapacity for ArrayString<97> {}
-    impl ValidCapacity for ArrayString<98> {}
-    impl ValidCapacity for ArrayString<99> {}
-    impl ValidCapacity for ArrayString<100> {}
-    impl ValidCapacity for ArrayString<101> {}
-    impl ValidCapacity for ArrayString<102> {}
-    impl ValidCapacity for ArrayString<103> {}
-    impl ValidCapacity for ArrayString<104> {}
-    impl ValidCapacity for ArrayString<105> {}
-    impl ValidCapacity for ArrayString<106> {}
-    impl ValidCapacity for ArrayString<107> {}
-    impl ValidCapacity for ArrayString<108> {}
-    impl ValidCapacity for ArrayString<109> {}
-    impl ValidCapacity for ArrayString<110> {}
-    impl ValidCapacity for ArrayString<111> {}
-    impl ValidCapacity for ArrayString<112> {}
-    impl ValidCapacity for ArrayString<113> {}
-    impl ValidCapacity for ArrayString<114> {}
-    impl ValidCapacity for ArrayString<115> {}
-    impl ValidCapacity for ArrayString<116> {}
-    impl ValidCapacity for ArrayString<117> {}
-    impl ValidCapacity for ArrayString<118> {}
-    impl ValidCapacity for ArrayString<119> {}
-    impl ValidCapacity for ArrayString<120> {}
-    impl ValidCapacity for ArrayString<121> {}
-    impl ValidCapacity for ArrayString<122> {}
-    impl ValidCapacity for ArrayString<123> {}
-    impl ValidCapacity for ArrayString<124> {}
-    impl ValidCapacity for ArrayString<125> {}
-    impl ValidCapacity for ArrayString<126> {}
-    impl ValidCapacity for ArrayString<127> {}
-    impl ValidCapacity for ArrayString<128> {}
-    impl ValidCapacity for ArrayString<129> {}
-    impl ValidCapacity for ArrayString<130> {}
-    impl ValidCapacity for ArrayString<131> {}
-    impl ValidCapacity for ArrayString<132> {}
-    impl ValidCapacity for ArrayString<133> {}
-    impl ValidCapacity for ArrayString<134> {}
-    impl ValidCapacity for ArrayString<135> {}
-    impl ValidCapacity for ArrayString<136> {}
-    impl ValidCapacity for ArrayString<137> {}
-    impl ValidCapacity for ArrayString<138> {}
-    impl ValidCapacity for ArrayString<139> {}
-    impl ValidCapacity for ArrayString<140> {}
-    impl ValidCapacity for ArrayString<141> {}
-    impl ValidCapacity for ArrayString<142> {}
-    impl ValidCapacity for ArrayString<143> {}
-    impl ValidCapacity for ArrayString<144> {}
-    impl ValidCapacity for ArrayString<145> {}
-    impl ValidCapacity for ArrayString<146> {}
-    impl ValidCapacity for ArrayString<147> {}
-    impl ValidCapacity for ArrayString<148> {}
-    impl ValidCapacity for ArrayString<149> {}
-    impl ValidCapacity for ArrayString<150> {}
-    impl ValidCapacity for ArrayString<151> {}
-    impl ValidCapacity for ArrayString<152> {}
-    impl ValidCapacity for ArrayString<153> {}
-    impl ValidCapacity for ArrayString<154> {}
-    impl ValidCapacity for ArrayString<155> {}
-    impl ValidCapacity for ArrayString<156> {}
-    impl ValidCapacity for ArrayString<157> {}
-    impl ValidCapacity for ArrayString<158> {}
-    impl ValidCapacity for ArrayString<159> {}
-    impl ValidCapacity for ArrayString<160> {}
-    impl ValidCapacity for ArrayString<161> {}
-    impl ValidCapacity for ArrayString<162> {}
-    impl ValidCapacity for ArrayString<163> {}
-    impl ValidCapacity for ArrayString<164> {}
-    impl ValidCapacity for ArrayString<165> {}
-    impl ValidCapacity for ArrayString<166> {}
-    impl ValidCapacity for ArrayString<167> {}
-    impl ValidCapacity for ArrayString<168> {}
-    impl ValidCapacity for ArrayString<169> {}
-    impl ValidCapacity for ArrayString<170> {}
-    impl ValidCapacity for ArrayString<171> {}
-    impl ValidCapacity for ArrayString<172> {}
-    impl ValidCapacity for ArrayString<173> {}
-    impl ValidCapacity for ArrayString<174> {}
-    impl ValidCapacity for ArrayString<175> {}
-    impl ValidCapacity for ArrayString<176> {}
-    impl ValidCapacity for ArrayString<177> {}
-    impl ValidCapacity for ArrayString<178> {}
-    impl ValidCapacity for ArrayString<179> {}
-    impl ValidCapacity for ArrayString<180> {}
-    impl ValidCapacity for ArrayString<181> {}
-    impl ValidCapacity for ArrayString<182> {}
-    impl ValidCapacity for ArrayString<183> {}
-    impl ValidCapacity for ArrayString<184> {}
-    impl ValidCapacity for ArrayString<185> {}
-    impl ValidCapacity for ArrayString<186> {}
-    impl ValidCapacity for ArrayString<187> {}
-    impl ValidCapacity for ArrayString<188> {}
-    impl ValidCapacity for ArrayString<189> {}
-    impl ValidCapacity for ArrayString<190> {}
-    impl ValidCapacity for ArrayString<191> {}
-    impl ValidCapacity for ArrayString<192> {}
-    impl ValidCapacity for ArrayString<193> {}
-    impl ValidCapacity for ArrayString<194> {}
-    impl ValidCapacity for ArrayString<195> {}
-    impl ValidCapacity for ArrayString<196> {}
-    impl ValidCapacity for ArrayString<197> {}
-    impl ValidCapacity for ArrayString<198> {}
-    impl ValidCapacity for ArrayString<199> {}
-    impl ValidCapacity for ArrayString<200> {}
-    impl ValidCapacity for ArrayString<201> {}
-    impl ValidCapacity for ArrayString<202> {}
-    impl ValidCapacity for ArrayString<203> {}
-    impl ValidCapacity for ArrayString<204> {}
-    impl ValidCapacity for ArrayString<205> {}
-    impl ValidCapacity for ArrayString<206> {}
-    impl ValidCapacity for ArrayString<207> {}
-    impl ValidCapacity for ArrayString<208> {}
-    impl ValidCapacity for ArrayString<209> {}
-    impl ValidCapacity for ArrayString<210> {}
-    impl ValidCapacity for ArrayString<211> {}
-    impl ValidCapacity for ArrayString<212> {}
-    impl ValidCapacity for ArrayString<213> {}
-    impl ValidCapacity for ArrayString<214> {}
-    impl ValidCapacity for ArrayString<215> {}
-    impl ValidCapacity for ArrayString<216> {}
-    impl ValidCapacity for ArrayString<217> {}
-    impl ValidCapacity for ArrayString<218> {}
-    impl ValidCapacity for ArrayString<219> {}
-    impl ValidCapacity for ArrayString<220> {}
-    impl ValidCapacity for ArrayString<221> {}
-    impl ValidCapacity for ArrayString<222> {}
-    impl ValidCapacity for ArrayString<223> {}
-    impl ValidCapacity for ArrayString<224> {}
-    impl ValidCapacity for ArrayString<225> {}
-    impl ValidCapacity for ArrayString<226> {}
-    impl ValidCapacity for ArrayString<227> {}
-    impl ValidCapacity for ArrayString<228> {}
-    impl ValidCapacity for ArrayString<229> {}
-    impl ValidCapacity for ArrayString<230> {}
-    impl ValidCapacity for ArrayString<231> {}
-    impl ValidCapacity for ArrayString<232> {}
-    impl ValidCapacity for ArrayString<233> {}
-    impl ValidCapacity for ArrayString<234> {}
-    impl ValidCapacity for ArrayString<235> {}
-    impl ValidCapacity for ArrayString<236> {}
-    impl ValidCapacity for ArrayString<237> {}
-    impl ValidCapacity for ArrayString<238> {}
-    impl ValidCapacity for ArrayString<239> {}
-    impl ValidCapacity for ArrayString<240> {}
-    impl ValidCapacity for ArrayString<241> {}
-    impl ValidCapacity for ArrayString<242> {}
-    impl ValidCapacity for ArrayString<243> {}
-    impl ValidCapacity for ArrayString<244> {}
-    impl ValidCapacity for ArrayString<245> {}
-    impl ValidCapacity for ArrayString<246> {}
-    impl ValidCapacity for ArrayString<247> {}
-    impl ValidCapacity for ArrayString<248> {}
-    impl ValidCapacity for ArrayString<249> {}
-    impl ValidCapacity for ArrayString<250> {}
-    impl ValidCapacity for ArrayString<251> {}
-    impl ValidCapacity for ArrayString<252> {}
-    impl ValidCapacity for ArrayString<253> {}
-    impl ValidCapacity for ArrayString<254> {}
-    impl ValidCapacity for ArrayString<255> {}
+    impl<P> ValidCapacity for ArrayString<1, P> {}
+    impl<P> ValidCapacity for ArrayString<2, P> {}
+    impl<P> ValidCapacity for ArrayString<3, P> {}
+    impl<P> ValidCapacity for ArrayString<4, P> {}
+    impl<P> ValidCapacity for ArrayString<5, P> {}
+    impl<P> ValidCapacity for ArrayString<6, P> {}
+    impl<P> ValidCapacity for ArrayString<7, P> {}
+    impl<P> ValidCapacity for ArrayString<8, P> {}
+    impl<P> ValidCapacity for ArrayString<9, P> {}
+    impl<P> ValidCapacity for ArrayString<10, P> {}
+    impl<P> ValidCapacity for ArrayString<11, P> {}
+    impl<P> ValidCapacity for ArrayString<12, P> {}
+    impl<P> ValidCapacity for ArrayString<13, P> {}
+    impl<P> ValidCapacity for ArrayString<14, P> {}
+    impl<P> ValidCapacity for ArrayString<15, P> {}
+    impl<P> ValidCapacity for ArrayString<16, P> {}
+    impl<P> ValidCapacity for ArrayString<17, P> {}
+    impl<P> ValidCapacity for ArrayString<18, P> {}
+    impl<P> ValidCapacity for ArrayString<19, P> {}
+    impl<P> ValidCapacity for ArrayString<20, P> {}
+    impl<P> ValidCapacity for ArrayString<21, P> {}
+    impl<P> ValidCapacity for ArrayString<22, P> {}
+    impl<P> ValidCapacity for ArrayString<23, P> {}
+    impl<P> ValidCapacity for ArrayString<24, P> {}
+    impl<P> ValidCapacity for ArrayString<25, P> {}
+    impl<P> ValidCapacity for ArrayString<26, P> {}
+    impl<P> ValidCapacity for ArrayString<27, P> {}
+    impl<P> ValidCapacity for ArrayString<28, P> {}
+    impl<P> ValidCapacity for ArrayString<29, P> {}
+    impl<P> ValidCapacity for ArrayString<30, P> {}
+    impl<P> ValidCapacity for ArrayString<31, P> {}
+    impl<P> ValidCapacity for ArrayString<32, P> {}
+    impl<P> ValidCapacity for ArrayString<33, P> {}
+    impl<P> ValidCapacity for ArrayString<34, P> {}
+    impl<P> ValidCapacity for ArrayString<35, P> {}
+    impl<P> ValidCapacity for ArrayString<36, P> {}
+    impl<P> ValidCapacity for ArrayString<37, P> {}
+    impl<P> ValidCapacity for ArrayString<38, P> {}
+    impl<P> ValidCapacity for ArrayString<39, P> {}
+    impl<P> ValidCapacity for ArrayString<40, P> {}
+    impl<P> ValidCapacity for ArrayString<41, P> {}
+    impl<P> ValidCapacity for ArrayString<42, P> {}
+    impl<P> ValidCapacity for ArrayString<43, P> {}
+    impl<P> ValidCapacity for ArrayString<44, P> {}
+    impl<P> ValidCapacity for ArrayString<45, P> {}
+    impl<P> ValidCapacity for ArrayString<46, P> {}
+    impl<P> ValidCapacity for ArrayString<47, P> {}
+    impl<P> ValidCapacity for ArrayString<48, P> {}
+    impl<P> ValidCapacity for ArrayString<49, P> {}
+    impl<P> ValidCapacity for ArrayString<50, P> {}
+    impl<P> ValidCapacity for ArrayString<51, P> {}
+    impl<P> ValidCapacity for ArrayString<52, P> {}
+    impl<P> ValidCapacity for ArrayString<53, P> {}
+    impl<P> ValidCapacity for ArrayString<54, P> {}
+    impl<P> ValidCapacity for ArrayString<55, P> {}
+    impl<P> ValidCapacity for ArrayString<56, P> {}
+    impl<P> ValidCapacity for ArrayString<57, P> {}
+    impl<P> ValidCapacity for ArrayString<58, P> {}
+    impl<P> ValidCapacity for ArrayString<59, P> {}
+    impl<P> ValidCapacity for ArrayString<60, P> {}
+    impl<P> ValidCapacity for ArrayString<61, P> {}
+    impl<P> ValidCapacity for ArrayString<62, P> {}
+    impl<P> ValidCapacity for ArrayString<63, P> {}
+    impl<P> ValidCapacity for ArrayString<64, P> {}
+    impl<P> ValidCapacity for ArrayString<65, P> {}
+    impl<P> ValidCapacity for ArrayString<66, P> {}
+    impl<P> ValidCapacity for ArrayString<67, P> {}
+    impl<P> ValidCapacity for ArrayString<68, P> {}
+    impl<P> ValidCapacity for ArrayString<69, P> {}
+    impl<P> ValidCapacity for ArrayString<70, P> {}
+    impl<P> ValidCapacity for ArrayString<71, P> {}
+    impl<P> ValidCapacity for ArrayString<72, P> {}
+    impl<P> ValidCapacity for ArrayString<73, P> {}
+    impl<P> ValidCapacity for ArrayString<74, P> {}
+    impl<P> ValidCapacity for ArrayString<75, P> {}
+    impl<P> ValidCapacity for ArrayString<76, P> {}
+    impl<P> ValidCapacity for ArrayString<77, P> {}
+    impl<P> ValidCapacity for ArrayString<78, P> {}
+    impl<P> ValidCapacity for ArrayString<79, P> {}
+    impl<P> ValidCapacity for ArrayString<80, P> {}
+    impl<P> ValidCapacity for ArrayString<81, P> {}
+    impl<P> ValidCapacity for ArrayString<82, P> {}
+    impl<P> ValidCapacity for ArrayString<83, P> {}
+    impl<P> ValidCapacity for ArrayString<84, P> {}
+    impl<P> ValidCapacity for ArrayString<85, P> {}
+    impl<P> ValidCapacity for ArrayString<86, P> {}
+    impl<P> ValidCapacity for ArrayString<87, P> {}
+    impl<P> ValidCapacity for ArrayString<88, P> {}
+    impl<P> ValidCapacity for ArrayString<89, P> {}
+    impl<P> ValidCapacity for ArrayString<90, P> {}
+    impl<P> ValidCapacity for ArrayString<91, P> {}
+    impl<P> ValidCapacity for ArrayString<92, P> {}
+    impl<P> ValidCapacity for ArrayString<93, P> {}
+    impl<P> ValidCapacity for ArrayString<94, P> {}
+    impl<P> ValidCapacity for ArrayString<95, P> {}
+    impl<P> ValidCapacity for ArrayString<96, P> {}
+    impl<P> ValidCapacity for ArrayString<97, P> {}
+    impl<P> ValidCapacity for ArrayString<98, P> {}
+    impl<P> ValidCapacity for ArrayString<99, P> {}
+    impl<P> ValidCapacity for ArrayString<100, P> {}
+    impl<P> ValidCapacity for ArrayString<101, P> {}
+    impl<P> ValidCapacity for ArrayString<102, P> {}
+    impl<P> ValidCapacity for ArrayString<103, P> {}
+    impl<P> ValidCapacity for ArrayString<104, P> {}
+    impl<P> ValidCapacity for ArrayString<105, P> {}
+    impl<P> ValidCapacity for ArrayString<106, P> {}
+    impl<P> ValidCapacity for ArrayString<107, P> {}
+    impl<P> ValidCapacity for ArrayString<108, P> {}
+    impl<P> ValidCapacity for ArrayString<109, P> {}
+    impl<P> ValidCapacity for ArrayString<110, P> {}
+    impl<P> ValidCapacity for ArrayString<111, P> {}
+    impl<P> ValidCapacity for ArrayString<112, P> {}
+    impl<P> ValidCapacity for ArrayString<113, P> {}
+    impl<P> ValidCapacity for ArrayString<114, P> {}
+    impl<P> ValidCapacity for ArrayString<115, P> {}
+    impl<P> ValidCapacity for ArrayString<116, P> {}
+    impl<P> ValidCapacity for ArrayString<117, P> {}
+    impl<P> ValidCapacity for ArrayString<118, P> {}
+    impl<P> ValidCapacity for ArrayString<119, P> {}
+    impl<P> ValidCapacity for ArrayString<120, P> {}
+    impl<P> ValidCapacity for ArrayString<121, P> {}
+    impl<P> ValidCapacity for ArrayString<122, P> {}
+    impl<P> ValidCapacity for ArrayString<123, P> {}
+    impl<P> ValidCapacity for ArrayString<124, P> {}
+    impl<P> ValidCapacity for ArrayString<125, P> {}
+    impl<P> ValidCapacity for ArrayString<126, P> {}
+    impl<P> ValidCapacity for ArrayString<127, P> {}
+    impl<P> ValidCapacity for ArrayString<128, P> {}
+    impl<P> ValidCapacity for ArrayString<129, P> {}
+    impl<P> ValidCapacity for ArrayString<130, P> {}
+    impl<P> ValidCapacity for ArrayString<131, P> {}
+    impl<P> ValidCapacity for ArrayString<132, P> {}
+    impl<P> ValidCapacity for ArrayString<133, P> {}
+    impl<P> ValidCapacity for ArrayString<134, P> {}
+    impl<P> ValidCapacity for ArrayString<135, P> {}
+    impl<P> ValidCapacity for ArrayString<136, P> {}
+    impl<P> ValidCapacity for ArrayString<137, P> {}
+    impl<P> ValidCapacity for ArrayString<138, P> {}
+    impl<P> ValidCapacity for ArrayString<139, P> {}
+    impl<P> ValidCapacity for ArrayString<140, P> {}
+    impl<P> ValidCapacity for ArrayString<141, P> {}
+    impl<P> ValidCapacity for ArrayString<142, P> {}
+    impl<P> ValidCapacity for ArrayString<143, P> {}
+    impl<P> ValidCapacity for ArrayString<144, P> {}
+    impl<P> ValidCapacity for ArrayString<145, P> {}
+    impl<P> ValidCapacity for ArrayString<146, P> {}
+    impl<P> ValidCapacity for ArrayString<147, P> {}
+    impl<P> ValidCapacity for ArrayString<148, P> {}
+    impl<P> ValidCapacity for ArrayString<149, P> {}
+    impl<P> ValidCapacity for ArrayString<150, P> {}
+    impl<P> ValidCapacity for ArrayString<151, P> {}
+    impl<P> ValidCapacity for ArrayString<152, P> {}
+    impl<P> ValidCapacity for ArrayString<153, P> {}
+    impl<P> ValidCapacity for ArrayString<154, P> {}
+    impl<P> ValidCapacity for ArrayString<155, P> {}
+    impl<P> ValidCapacity for ArrayString<156, P> {}
+    impl<P> ValidCapacity for ArrayString<157, P> {}
+    impl<P> ValidCapacity for ArrayString<158, P> {}
+    impl<P> ValidCapacity for ArrayString<159, P> {}
+    impl<P> ValidCapacity for ArrayString<160, P> {}
+    impl<P> ValidCapacity for ArrayString<161, P> {}
+    impl<P> ValidCapacity for ArrayString<162, P> {}
+    impl<P> ValidCapacity for ArrayString<163, P> {}
+    impl<P> ValidCapacity for ArrayString<164, P> {}
+    impl<P> ValidCapacity for ArrayString<165, P> {}
+    impl<P> ValidCapacity for ArrayString<166, P> {}
+    impl<P> ValidCapacity for ArrayString<167, P> {}
+    impl<P> ValidCapacity for ArrayString<168, P> {}
+    impl<P> ValidCapacity for ArrayString<169, P> {}
+    impl<P> ValidCapacity for ArrayString<170, P> {}
+    impl<P> ValidCapacity for ArrayString<171, P> {}
+    impl<P> ValidCapacity for ArrayString<172, P> {}
+    impl<P> ValidCapacity for ArrayString<173, P> {}
+    impl<P> ValidCapacity for ArrayString<174, P> {}
+    impl<P> ValidCapacity for ArrayString<175, P> {}
+    impl<P> ValidCapacity for ArrayString<176, P> {}
+    impl<P> ValidCapacity for ArrayString<177, P> {}
+    impl<P> ValidCapacity for ArrayString<178, P> {}
+    impl<P> ValidCapacity for ArrayString<179, P> {}
+    impl<P> ValidCapacity for ArrayString<180, P> {}
+    impl<P> ValidCapacity for ArrayString<181, P> {}
+    impl<P> ValidCapacity for ArrayString<182, P> {}
+    impl<P> ValidCapacity for ArrayString<183, P> {}
+    impl<P> ValidCapacity for ArrayString<184, P> {}
+    impl<P> ValidCapacity for ArrayString<185, P> {}
+    impl<P> ValidCapacity for ArrayString<186, P> {}
+    impl<P> ValidCapacity for ArrayString<187, P> {}
+    impl<P> ValidCapacity for ArrayString<188, P> {}
+    impl<P> ValidCapacity for ArrayString<189, P> {}
+    impl<P> ValidCapacity for ArrayString<190, P> {}
+    impl<P> ValidCapacity for ArrayString<191, P> {}
+    impl<P> ValidCapacity for ArrayString<192, P> {}
+    impl<P> ValidCapacity for ArrayString<193, P> {}
+    impl<P> ValidCapacity for ArrayString<194, P> {}
+    impl<P> ValidCapacity for ArrayString<195, P> {}
+    impl<P> ValidCapacity for ArrayString<196, P> {}
+    impl<P> ValidCapacity for ArrayString<197, P> {}
+    impl<P> ValidCapacity for ArrayString<198, P> {}
+    impl<P> ValidCapacity for ArrayString<199, P> {}
+    impl<P> ValidCapacity for ArrayString<200, P> {}
+    impl<P> ValidCapacity for ArrayString<201, P> {}
+    impl<P> ValidCapacity for ArrayString<202, P> {}
+    impl<P> ValidCapacity for ArrayString<203, P> {}
+    impl<P> ValidCapacity for ArrayString<204, P> {}
+    impl<P> ValidCapacity for ArrayString<205, P> {}
+    impl<P> ValidCapacity for ArrayString<206, P> {}
+    impl<P> ValidCapacity for ArrayString<207, P> {}
+    impl<P> ValidCapacity for ArrayString<208, P> {}
+    impl<P> ValidCapacity for ArrayString<209, P> {}
+    impl<P> ValidCapacity for ArrayString<210, P> {}
+    impl<P> ValidCapacity for ArrayString<211, P> {}
+    impl<P> ValidCapacity for ArrayString<212, P> {}
+    impl<P> ValidCapacity for ArrayString<213, P> {}
+    impl<P> ValidCapacity for ArrayString<214, P> {}
+    impl<P> ValidCapacity for ArrayString<215, P> {}
+    impl<P> ValidCapacity for ArrayString<216, P> {}
+    impl<P> ValidCapacity for ArrayString<217, P> {}
+    impl<P> ValidCapacity for ArrayString<218, P> {}
+    impl<P> ValidCapacity for ArrayString<219, P> {}
+    impl<P> ValidCapacity for ArrayString<220, P> {}
+    impl<P> ValidCapacity for ArrayString<221, P> {}
+    impl<P> ValidCapacity for ArrayString<222, P> {}
+    impl<P> ValidCapacity for ArrayString<223, P> {}
+    impl<P> ValidCapacity for ArrayString<224, P> {}
+    impl<P> ValidCapacity for ArrayString<225, P> {}
+    impl<P> ValidCapacity for ArrayString<226, P> {}
+    impl<P> ValidCapacity for ArrayString<227, P> {}
+    impl<P> ValidCapacity for ArrayString<228, P> {}
+    impl<P> ValidCapacity for ArrayString<229, P> {}
+    impl<P> ValidCapacity for ArrayString<230, P> {}
+    impl<P> ValidCapacity for ArrayString<231, P> {}
+    impl<P> ValidCapacity for ArrayString<232, P> {}
+    impl<P> ValidCapacity for ArrayString<233, P> {}
+    impl<P> ValidCapacity for ArrayString<234, P> {}
+    impl<P> ValidCapacity for ArrayString<235, P> {}
+    impl<P> ValidCapacity for ArrayString<236, P> {}
+    impl<P> ValidCapacity for ArrayString<237, P> {}
+    impl<P> ValidCapacity for ArrayString<238, P> {}
+    impl<P> ValidCapacity for ArrayString<239, P> {}
+    impl<P> ValidCapacity for ArrayString<240, P> {}
+    impl<P> ValidCapacity for ArrayString<241, P> {}
+    impl<P> ValidCapacity for ArrayString<242, P> {}
+    impl<P> ValidCapacity for ArrayString<243, P> {}
+    impl<P> ValidCapacity for ArrayString<244, P> {}
+    impl<P> ValidCapacity for ArrayString<245, P> {}
+    impl<P> ValidCapacity for ArrayString<246, P> {}
+    impl<P> ValidCapacity for ArrayString<247, P> {}
+    impl<P> ValidCapacity for ArrayString<248, P> {}
+    impl<P> ValidCapacity for ArrayString<249, P> {}
+    impl<P> ValidCapacity for ArrayString<250, P> {}
+    impl<P> ValidCapacity for ArrayString<251, P> {}
+    impl<P> ValidCapacity for ArrayString<252, P> {}
+    impl<P> ValidCapacity for ArrayString<253, P> {}
+    impl<P> ValidCapacity for ArrayString<254, P> {}
+    impl<P> ValidCapacity for ArrayString<255, P> {}
 }
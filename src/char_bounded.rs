@@ -0,0 +1,591 @@
+//! Strings bounded by a number of chars, like SQL's `VARCHAR(n)`
+//!
+//! [`ArrayString`] counts bytes, so text with accents or CJK chars hits its limit before reaching the number of chars a column accepts
+//!
+//! [`ArrayString`]: ../struct.ArrayString.html
+
+use crate::arraystring::sealed::ValidCapacity;
+use crate::utils::{count_chars, IntoLossy};
+use crate::{prelude::*, Error};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::{borrow::Borrow, cmp::Ordering, hash::Hash, hash::Hasher};
+use core::{ops::Deref, str::FromStr};
+#[cfg(feature = "logs")]
+use log::trace;
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// String with at most `CHARS` chars, stored in an [`ArrayString<BYTES>`]
+///
+/// Size `BYTES` for the worst case of your text, `4 * CHARS` fits any char, `3 * CHARS` fits the Basic Multilingual Plane (accents and CJK). Text is rejected or truncated by whichever limit it hits first
+///
+/// The char count is cached, so [`chars_len`] is O(1). `Deserialize`, `FromStr` and diesel's `FromSql` enforce both bounds
+///
+/// Going over the char limit is a [`CharLimit`] error, going over the byte capacity is still [`OutOfBounds`]
+///
+/// [`ArrayString<BYTES>`]: ./struct.ArrayString.html
+/// [`chars_len`]: ./struct.CharBounded.html#method.chars_len
+/// [`CharLimit`]: ./error/enum.Error.html#variant.CharLimit
+/// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// // VARCHAR(10)
+/// type Name = CharBounded<10, 40>;
+///
+/// let mut name = Name::try_from_str("Françoise")?;
+/// assert_eq!(name.chars_len(), 9);
+/// assert_eq!(name.len(), 10);
+///
+/// name.try_push('é')?;
+/// assert_eq!(name.try_push('é').map_err(|err| err.error()), Err(Error::CharLimit { needed: 11, limit: 10 }));
+/// assert_eq!(name.as_str(), "Françoiseé");
+///
+/// assert_eq!(Name::try_from_str("ÀÉÎÕÜàéîõüç"), Err(Error::CharLimit { needed: 11, limit: 10 }));
+/// assert_eq!(Name::from_str_truncate("ÀÉÎÕÜàéîõüç").as_str(), "ÀÉÎÕÜàéîõü");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Default)]
+#[cfg_attr(
+    all(feature = "diesel-traits", feature = "std"),
+    derive(diesel::AsExpression, diesel::FromSqlRow)
+)]
+#[cfg_attr(
+    all(feature = "diesel-traits", feature = "std"),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct CharBounded<const CHARS: usize, const BYTES: usize>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    /// Text, never more than `CHARS` chars
+    string: ArrayString<BYTES>,
+    /// Cached char count of `string`
+    chars: u8,
+}
+
+/// Longest prefix with at most `chars` chars and `bytes` bytes, and its char count
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+fn prefix(slice: &str, chars: usize, bytes: usize) -> (&str, usize) {
+    let (mut count, mut end) = (0, 0);
+    for (idx, ch) in slice.char_indices() {
+        let next = idx.saturating_add(ch.len_utf8());
+        if count == chars || next > bytes {
+            break;
+        }
+        (count, end) = (count.saturating_add(1), next);
+    }
+    (slice.get(..end).unwrap_or_default(), count)
+}
+
+/// Returns error if `chars` is over `limit`
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+fn is_inside_char_limit(chars: usize, limit: usize) -> Result<(), CharLimit> {
+    trace!("Char limit: ensures {} <= {}", chars, limit);
+    (chars <= limit)
+        .then_some(())
+        .ok_or(CharLimit::new(chars, limit))
+}
+
+impl<const CHARS: usize, const BYTES: usize> CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    /// Creates new empty string.
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = CharBounded::<5, 20>::new();
+    /// assert!(string.is_empty());
+    /// assert_eq!(string.chars_len(), 0);
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            string: ArrayString::new(),
+            chars: 0,
+        }
+    }
+
+    /// Creates new string from string slice if it fits in [`char_capacity`] chars and in [`capacity`] bytes, otherwise returns an error.
+    ///
+    /// [`char_capacity`]: ./struct.CharBounded.html#method.char_capacity
+    /// [`capacity`]: ./struct.CharBounded.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = CharBounded::<5, 20>::try_from_str("日本語")?;
+    /// assert_eq!(string.as_str(), "日本語");
+    ///
+    /// assert_eq!(CharBounded::<5, 20>::try_from_str("abcdef"), Err(Error::CharLimit { needed: 6, limit: 5 }));
+    /// assert_eq!(CharBounded::<5, 8>::try_from_str("日本語"), Err(Error::OutOfBounds { needed: 9, capacity: 8 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_str(string: impl AsRef<str>) -> Result<Self, Error> {
+        let mut s = Self::new();
+        s.try_push_str(string)?;
+        Ok(s)
+    }
+
+    /// Creates new string from string slice truncating it at [`char_capacity`] chars or at [`capacity`] bytes, whichever comes first
+    ///
+    /// [`char_capacity`]: ./struct.CharBounded.html#method.char_capacity
+    /// [`capacity`]: ./struct.CharBounded.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = CharBounded::<5, 20>::from_str_truncate("Éléphant");
+    /// assert_eq!(string.as_str(), "Éléph");
+    ///
+    /// let string = CharBounded::<5, 8>::from_str_truncate("日本語");
+    /// assert_eq!(string.as_str(), "日本");
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn from_str_truncate(string: impl AsRef<str>) -> Self {
+        let mut s = Self::new();
+        s.push_str_truncate(string);
+        s
+    }
+
+    /// Counts the chars of [`ArrayString`], returning an error if there are more than [`char_capacity`]
+    ///
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    /// [`char_capacity`]: ./struct.CharBounded.html#method.char_capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = ArrayString::<20>::try_from_str("Ça va")?;
+    /// let bounded = CharBounded::<5, 20>::try_from_array_string(string)?;
+    /// assert_eq!(bounded.into_inner(), string);
+    ///
+    /// let string = ArrayString::<20>::try_from_str("Ça va?")?;
+    /// assert_eq!(CharBounded::<5, 20>::try_from_array_string(string), Err(CharLimit::new(6, 5)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_array_string(string: ArrayString<BYTES>) -> Result<Self, CharLimit> {
+        trace!("Count chars: {}", string);
+        let chars = count_chars(&string);
+        is_inside_char_limit(chars, CHARS)?;
+        Ok(Self {
+            string,
+            chars: chars.into_lossy(),
+        })
+    }
+
+    /// Extracts the inner [`ArrayString`]
+    ///
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    #[inline]
+    pub fn into_inner(self) -> ArrayString<BYTES> {
+        self.string
+    }
+
+    /// Extracts a string slice containing the entire string
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = CharBounded::<10, 20>::try_from_str("My String")?;
+    /// assert_eq!(string.as_str(), "My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn as_str(&self) -> &str {
+        self.string.as_str()
+    }
+
+    /// Returns maximum string capacity in bytes, defined at compile time, it will never change
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(CharBounded::<8, 32>::capacity(), 32);
+    /// ```
+    #[inline]
+    pub const fn capacity() -> usize {
+        BYTES
+    }
+
+    /// Returns maximum number of chars, defined at compile time, it will never change
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(CharBounded::<8, 32>::char_capacity(), 8);
+    /// ```
+    #[inline]
+    pub const fn char_capacity() -> usize {
+        CHARS
+    }
+
+    /// Returns number of chars in the string, it's cached so it doesn't traverse the string
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = CharBounded::<8, 32>::try_from_str("🤔🤔")?;
+    /// assert_eq!(string.chars_len(), 2);
+    /// assert_eq!(string.len(), 8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn chars_len(&self) -> usize {
+        self.chars.into()
+    }
+
+    /// Pushes string slice to the end of the string if the result fits in both limits, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = CharBounded::<6, 20>::try_from_str("Ça")?;
+    /// string.try_push_str(" va")?;
+    /// assert_eq!(string.as_str(), "Ça va");
+    ///
+    /// assert_eq!(string.try_push_str(" ?"), Err(CapacityError::new(" ?", Error::CharLimit { needed: 7, limit: 6 })));
+    /// assert_eq!(string.as_str(), "Ça va");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_str<S: AsRef<str>>(
+        &mut self,
+        string: S,
+    ) -> Result<(), CapacityError<S, Error>> {
+        trace!("Push str: {}", string.as_ref());
+        let chars = count_chars(string.as_ref());
+        let pushed = is_inside_char_limit(self.chars_len().saturating_add(chars), CHARS)
+            .map_err(Error::from)
+            .and_then(|()| Ok(self.string.try_push_str(string.as_ref())?));
+        match pushed {
+            Ok(()) => {
+                self.chars = self.chars.saturating_add(chars.into_lossy());
+                Ok(())
+            }
+            Err(err) => Err(CapacityError::new(string, err)),
+        }
+    }
+
+    /// Pushes string slice to the end of the string, truncating it at whichever limit comes first
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = CharBounded::<6, 20>::try_from_str("Ça")?;
+    /// string.push_str_truncate(" va ?");
+    /// assert_eq!(string.as_str(), "Ça va ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn push_str_truncate(&mut self, string: impl AsRef<str>) {
+        trace!("Push str truncate: {}", string.as_ref());
+        let (string, chars) = prefix(
+            string.as_ref(),
+            CHARS.saturating_sub(self.chars_len()),
+            BYTES.saturating_sub(self.len()),
+        );
+        if self.string.try_push_str(string).is_ok() {
+            self.chars = self.chars.saturating_add(chars.into_lossy());
+        }
+    }
+
+    /// Pushes character to the end of the string if it fits in both limits, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = CharBounded::<2, 20>::try_from_str("à")?;
+    /// string.try_push('é')?;
+    /// assert_eq!(string.try_push('a'), Err(CapacityError::new('a', Error::CharLimit { needed: 3, limit: 2 })));
+    /// assert_eq!(string.as_str(), "àé");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError<char, Error>> {
+        trace!("Push: {}", ch);
+        is_inside_char_limit(self.chars_len().saturating_add(1), CHARS)
+            .map_err(|err| CapacityError::new(ch, err.into()))?;
+        self.string
+            .try_push(ch)
+            .map_err(|err| CapacityError::new(ch, err.error().into()))?;
+        self.chars = self.chars.saturating_add(1);
+        Ok(())
+    }
+
+    /// Removes last character, if any.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = CharBounded::<5, 20>::try_from_str("Çà")?;
+    /// assert_eq!(string.pop(), Some('à'));
+    /// assert_eq!(string.pop(), Some('Ç'));
+    /// assert_eq!(string.pop(), None);
+    /// assert_eq!(string.chars_len(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.string.pop()?;
+        self.chars = self.chars.saturating_sub(1);
+        Some(ch)
+    }
+
+    /// Truncates string to at most `chars` chars
+    ///
+    /// Unlike [`ArrayString::truncate`] it counts chars, not bytes, so it never fails
+    ///
+    /// [`ArrayString::truncate`]: ./struct.ArrayString.html#method.truncate
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = CharBounded::<10, 40>::try_from_str("日本語のテキスト")?;
+    /// string.truncate_chars(3);
+    /// assert_eq!(string.as_str(), "日本語");
+    /// string.truncate_chars(5);
+    /// assert_eq!(string.as_str(), "日本語");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn truncate_chars(&mut self, chars: usize) {
+        trace!("Truncate chars: {}", chars);
+        let (prefix, chars) = prefix(self.as_str(), chars, BYTES);
+        let size = prefix.len();
+        if self.string.truncate(size).is_ok() {
+            self.chars = chars.into_lossy();
+        }
+    }
+
+    /// Empties string
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = CharBounded::<10, 20>::try_from_str("My String")?;
+    /// string.clear();
+    /// assert!(string.is_empty());
+    /// assert_eq!(string.chars_len(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn clear(&mut self) {
+        self.string.clear();
+        self.chars = 0;
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> Debug for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("CharBounded").field(&self.string).finish()
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> Display for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.string, f)
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> PartialEq for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.string == other.string
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> Eq for CharBounded<CHARS, BYTES> where
+    ArrayString<BYTES>: ValidCapacity
+{
+}
+
+impl<const CHARS: usize, const BYTES: usize> PartialEq<str> for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.string.as_str() == other
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> PartialEq<&str> for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.string.as_str() == *other
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> Ord for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.string.cmp(&other.string)
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> PartialOrd for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> Hash for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.string.hash(hasher);
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> Deref for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.string.as_str()
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> AsRef<str> for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.string.as_str()
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> Borrow<str> for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.string.as_str()
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> FromStr for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> TryFrom<&str> for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    type Error = Error;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> TryFrom<ArrayString<BYTES>>
+    for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    type Error = CharLimit;
+
+    #[inline]
+    fn try_from(s: ArrayString<BYTES>) -> Result<Self, Self::Error> {
+        Self::try_from_array_string(s)
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> From<CharBounded<CHARS, BYTES>> for ArrayString<BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn from(s: CharBounded<CHARS, BYTES>) -> Self {
+        s.string
+    }
+}
+
+impl<const CHARS: usize, const BYTES: usize> fmt::Write for CharBounded<CHARS, BYTES>
+where
+    ArrayString<BYTES>: ValidCapacity,
+{
+    #[inline]
+    fn write_str(&mut self, slice: &str) -> fmt::Result {
+        self.try_push_str(slice).map_err(|_| fmt::Error)
+    }
+}
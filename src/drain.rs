@@ -2,7 +2,7 @@
 //!
 //! [`ArrayString`]: ./struct.ArrayString.html
 
-use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+use crate::{arraystring::sealed::ValidCapacity, policy::Truncate, prelude::*};
 use core::fmt::{self, Debug, Formatter};
 use core::{cmp::Ordering, hash::Hash, hash::Hasher, iter::FusedIterator};
 
@@ -12,31 +12,31 @@ use core::{cmp::Ordering, hash::Hash, hash::Hasher, iter::FusedIterator};
 ///
 /// [`ArrayString`]: ./struct.ArrayString.html
 /// [`drain`]: ./struct.ArrayString.html#method.drain
-pub struct Drain<const N: usize>(pub(crate) ArrayString<N>)
+pub struct Drain<const N: usize, P = Truncate>(pub(crate) ArrayString<N, P>)
 where
-    ArrayString<N>: ValidCapacity;
+    ArrayString<N, P>: ValidCapacity;
 
-impl<const N: usize> Copy for Drain<N> where ArrayString<N>: ValidCapacity {}
-impl<const N: usize> Clone for Drain<N>
+impl<const N: usize, P> Copy for Drain<N, P> where ArrayString<N, P>: ValidCapacity {}
+impl<const N: usize, P> Clone for Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     fn clone(&self) -> Self {
-        Self(self.0)
+        *self
     }
 }
-impl<const N: usize> Default for Drain<N>
+impl<const N: usize, P> Default for Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     fn default() -> Self {
         Self(Default::default())
     }
 }
 
-impl<const N: usize> Debug for Drain<N>
+impl<const N: usize, P> Debug for Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -44,30 +44,30 @@ where
     }
 }
 
-impl<const N: usize> PartialEq for Drain<N>
+impl<const N: usize, P> PartialEq for Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.as_str().eq(other.as_str())
+        self.0.eq(&other.0)
     }
 }
-impl<const N: usize> Eq for Drain<N> where ArrayString<N>: ValidCapacity {}
+impl<const N: usize, P> Eq for Drain<N, P> where ArrayString<N, P>: ValidCapacity {}
 
-impl<const N: usize> Ord for Drain<N>
+impl<const N: usize, P> Ord for Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        self.as_str().cmp(other.as_str())
+        self.0.cmp(&other.0)
     }
 }
 
-impl<const N: usize> PartialOrd for Drain<N>
+impl<const N: usize, P> PartialOrd for Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -75,9 +75,9 @@ where
     }
 }
 
-impl<const N: usize> Hash for Drain<N>
+impl<const N: usize, P> Hash for Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     #[inline]
     fn hash<H: Hasher>(&self, hasher: &mut H) {
@@ -85,9 +85,9 @@ where
     }
 }
 
-impl<const N: usize> Drain<N>
+impl<const N: usize, P> Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     /// Extracts string slice containing the remaining characters of `Drain`.
     #[inline]
@@ -96,9 +96,9 @@ where
     }
 }
 
-impl<const N: usize> Iterator for Drain<N>
+impl<const N: usize, P> Iterator for Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     type Item = char;
 
@@ -108,9 +108,9 @@ where
     }
 }
 
-impl<const N: usize> DoubleEndedIterator for Drain<N>
+impl<const N: usize, P> DoubleEndedIterator for Drain<N, P>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
@@ -118,4 +118,4 @@ where
     }
 }
 
-impl<const N: usize> FusedIterator for Drain<N> where ArrayString<N>: ValidCapacity {}
+impl<const N: usize, P> FusedIterator for Drain<N, P> where ArrayString<N, P>: ValidCapacity {}
@@ -1,54 +1,72 @@
 //! Contains all of this crate's errors
 
 use core::fmt::{self, Debug, Display, Formatter};
-use core::{char::DecodeUtf16Error, hash::Hash, hash::Hasher, str::EncodeUtf16, str::Utf8Error};
+use core::iter::{Chain, Once};
+use core::{char::DecodeUtf16Error, str::EncodeUtf16, str::Utf8Error};
 #[cfg(feature = "logs")]
 use log::trace;
 
 /// Every error possible when using [`ArrayString`]
 ///
 /// [`ArrayString`]: ../struct.ArrayString.html
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Error {
     /// Invalid UTF-8 access (invalid data or invalid utf-8 character index)
-    Utf8,
+    Utf8 {
+        /// Byte index of the invalid data, or the index that isn't at a char boundary
+        index: usize,
+        /// Bytes before this index are valid, like [`Utf8Error::valid_up_to`]
+        ///
+        /// [`Utf8Error::valid_up_to`]: core::str::Utf8Error::valid_up_to
+        valid_up_to: usize,
+    },
     /// Failed to convert `[u16]` to UTF-16 string
     Utf16,
     /// Out of bounds access
-    OutOfBounds,
-}
-
-impl PartialEq for Error {
-    #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        use self::Error::*;
-        matches!(
-            (self, other),
-            (Utf8, Utf8) | (Utf16, Utf16) | (OutOfBounds, OutOfBounds)
-        )
-    }
-}
-
-impl Eq for Error {}
-
-impl Hash for Error {
-    #[inline]
-    fn hash<H: Hasher>(&self, hasher: &mut H) {
-        match self {
-            Error::Utf8 => "Utf8".hash(hasher),
-            Error::Utf16 => "Utf16".hash(hasher),
-            Error::OutOfBounds => "OutOfBounds".hash(hasher),
-        }
-    }
+    OutOfBounds {
+        /// Size the operation required
+        needed: usize,
+        /// Size that was available
+        capacity: usize,
+    },
+    /// Text with more chars than the limit of [`CharBounded`]
+    ///
+    /// [`CharBounded`]: ../struct.CharBounded.html
+    CharLimit {
+        /// Chars the operation required
+        needed: usize,
+        /// Maximum number of chars
+        limit: usize,
+    },
+    /// Index past the end of the string
+    Index {
+        /// Index that was accessed
+        index: usize,
+        /// Length it had to be within, the string's or the end of the range's
+        len: usize,
+    },
+    /// Text failed a [`Validator`] rule
+    ///
+    /// [`Validator`]: ../validated/trait.Validator.html
+    Invalid {
+        /// Name of the failed rule
+        rule: &'static str,
+    },
 }
 
 impl Display for Error {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self {
-            Error::Utf8 => write!(f, "Utf8"),
+        match *self {
+            Error::Utf8 { index, valid_up_to } => Display::fmt(&Utf8 { index, valid_up_to }, f),
             Error::Utf16 => write!(f, "Utf16"),
-            Error::OutOfBounds => write!(f, "OutOfBounds"),
+            Error::OutOfBounds { needed, capacity } => {
+                Display::fmt(&OutOfBounds { needed, capacity }, f)
+            }
+            Error::CharLimit { needed, limit } => Display::fmt(&CharLimit { needed, limit }, f),
+            Error::Index { index, len } => Display::fmt(&Index { index, len }, f),
+            Error::Invalid { rule } => write!(f, "Invalid: {rule}"),
         }
     }
 }
@@ -57,9 +75,22 @@ impl Debug for Error {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
-            Error::Utf8 => write!(f, "Error::Utf8"),
+            Error::Utf8 { index, valid_up_to } => write!(
+                f,
+                "Error::Utf8 {{ index: {index}, valid_up_to: {valid_up_to} }}"
+            ),
             Error::Utf16 => write!(f, "Error::Utf16"),
-            Error::OutOfBounds => write!(f, "Error::OutOfBounds"),
+            Error::OutOfBounds { needed, capacity } => write!(
+                f,
+                "Error::OutOfBounds {{ needed: {needed}, capacity: {capacity} }}"
+            ),
+            Error::CharLimit { needed, limit } => {
+                write!(f, "Error::CharLimit {{ needed: {needed}, limit: {limit} }}")
+            }
+            Error::Index { index, len } => {
+                write!(f, "Error::Index {{ index: {index}, len: {len} }}")
+            }
+            Error::Invalid { rule } => write!(f, "Error::Invalid {{ rule: {rule:?} }}"),
         }
     }
 }
@@ -69,8 +100,8 @@ impl std::error::Error for Error {}
 
 impl From<Utf8Error> for Error {
     #[inline]
-    fn from(_: Utf8Error) -> Self {
-        Error::Utf8
+    fn from(err: Utf8Error) -> Self {
+        Utf8::from(err).into()
     }
 }
 
@@ -88,21 +119,60 @@ impl<'a> From<EncodeUtf16<'a>> for Error {
     }
 }
 
-/// Error caused by invalid UTF-8 data
-#[derive(Copy, Clone, Default, PartialEq, Eq)]
-pub struct Utf8;
+/// Error caused by invalid UTF-8 data, or by an index that isn't at a char boundary
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Utf8 {
+    index: usize,
+    valid_up_to: usize,
+}
+
+impl Utf8 {
+    /// Creates error for the byte at `index`, text before `valid_up_to` is valid
+    #[inline]
+    pub const fn new(index: usize, valid_up_to: usize) -> Self {
+        Self { index, valid_up_to }
+    }
+
+    /// Byte index of the invalid data, or the index that isn't at a char boundary
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Bytes before this index are valid, like [`Utf8Error::valid_up_to`]
+    ///
+    /// For an index inside a char it's the start of that char
+    ///
+    /// [`Utf8Error::valid_up_to`]: core::str::Utf8Error::valid_up_to
+    #[inline]
+    pub const fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
 
 impl Debug for Utf8 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Utf8")
+        write!(
+            f,
+            "Utf8 {{ index: {}, valid_up_to: {} }}",
+            self.index, self.valid_up_to
+        )
     }
 }
 
 impl Display for Utf8 {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Utf8")
+        if self.index == self.valid_up_to {
+            write!(f, "invalid utf-8 at byte {}", self.index)
+        } else {
+            write!(
+                f,
+                "byte {} is not a char boundary, valid up to {}",
+                self.index, self.valid_up_to
+            )
+        }
     }
 }
 
@@ -111,16 +181,19 @@ impl std::error::Error for Utf8 {}
 
 impl From<Utf8Error> for Utf8 {
     #[inline]
-    fn from(_: Utf8Error) -> Self {
-        Utf8
+    fn from(err: Utf8Error) -> Self {
+        Utf8::new(err.valid_up_to(), err.valid_up_to())
     }
 }
 
 impl From<Utf8> for Error {
     #[inline]
-    fn from(_: Utf8) -> Self {
+    fn from(err: Utf8) -> Self {
         trace!("From Utf8");
-        Error::Utf8
+        Error::Utf8 {
+            index: err.index,
+            valid_up_to: err.valid_up_to,
+        }
     }
 }
 
@@ -170,20 +243,53 @@ impl<'a> From<EncodeUtf16<'a>> for Utf16 {
 /// Error caused by out of bounds access to [`ArrayString`]
 ///
 /// [`ArrayString`]: ../struct.ArrayString.html
-#[derive(Copy, Clone, Default, PartialEq, Eq)]
-pub struct OutOfBounds;
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OutOfBounds {
+    needed: usize,
+    capacity: usize,
+}
+
+impl OutOfBounds {
+    /// Creates error for an operation that needed `needed` bytes, with only `capacity` available
+    #[inline]
+    pub const fn new(needed: usize, capacity: usize) -> Self {
+        Self { needed, capacity }
+    }
+
+    /// Size the operation required
+    ///
+    /// Operations over iterators stop at the first item that doesn't fit, so it counts up to that item
+    #[inline]
+    pub const fn needed(&self) -> usize {
+        self.needed
+    }
+
+    /// Size that was available
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
 
 impl Debug for OutOfBounds {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "OutOfBounds")
+        write!(
+            f,
+            "OutOfBounds {{ needed: {}, capacity: {} }}",
+            self.needed, self.capacity
+        )
     }
 }
 
 impl Display for OutOfBounds {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "OutOfBounds")
+        write!(
+            f,
+            "needed {} bytes but capacity is {}",
+            self.needed, self.capacity
+        )
     }
 }
 
@@ -192,8 +298,293 @@ impl std::error::Error for OutOfBounds {}
 
 impl From<OutOfBounds> for Error {
     #[inline]
-    fn from(_: OutOfBounds) -> Self {
+    fn from(err: OutOfBounds) -> Self {
         trace!("From OutOfBounds");
-        Error::OutOfBounds
+        Error::OutOfBounds {
+            needed: err.needed,
+            capacity: err.capacity,
+        }
+    }
+}
+
+/// Error caused by text with more chars than the limit of [`CharBounded`]
+///
+/// [`CharBounded`]: ../struct.CharBounded.html
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CharLimit {
+    needed: usize,
+    limit: usize,
+}
+
+impl CharLimit {
+    /// Creates error for an operation that needed `needed` chars, with a limit of `limit`
+    #[inline]
+    pub const fn new(needed: usize, limit: usize) -> Self {
+        Self { needed, limit }
+    }
+
+    /// Chars the operation required
+    #[inline]
+    pub const fn needed(&self) -> usize {
+        self.needed
+    }
+
+    /// Maximum number of chars
+    #[inline]
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl Debug for CharLimit {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CharLimit {{ needed: {}, limit: {} }}",
+            self.needed, self.limit
+        )
+    }
+}
+
+impl Display for CharLimit {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "needed {} chars but limit is {}",
+            self.needed, self.limit
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CharLimit {}
+
+impl From<CharLimit> for Error {
+    #[inline]
+    fn from(err: CharLimit) -> Self {
+        trace!("From CharLimit");
+        Error::CharLimit {
+            needed: err.needed,
+            limit: err.limit,
+        }
+    }
+}
+
+/// Error caused by an index past the end of [`ArrayString`]
+///
+/// [`ArrayString`]: ../struct.ArrayString.html
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Index {
+    index: usize,
+    len: usize,
+}
+
+impl Index {
+    /// Creates error for `index`, that had to be within `len`
+    #[inline]
+    pub const fn new(index: usize, len: usize) -> Self {
+        Self { index, len }
+    }
+
+    /// Index that was accessed
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Length it had to be within, the string's or the end of the range's
+    #[allow(clippy::len_without_is_empty)]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl Debug for Index {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Index {{ index: {}, len: {} }}", self.index, self.len)
+    }
+}
+
+impl Display for Index {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds of length {}",
+            self.index, self.len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Index {}
+
+impl From<Index> for Error {
+    #[inline]
+    fn from(err: Index) -> Self {
+        trace!("From Index");
+        Error::Index {
+            index: err.index,
+            len: err.len,
+        }
+    }
+}
+
+/// Error caused by text that failed a [`Validator`] rule
+///
+/// [`Validator`]: ../validated/trait.Validator.html
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Invalid {
+    rule: &'static str,
+}
+
+impl Invalid {
+    /// Creates error for the failed rule
+    #[inline]
+    pub const fn new(rule: &'static str) -> Self {
+        Self { rule }
+    }
+
+    /// Name of the failed rule
+    #[inline]
+    pub const fn rule(&self) -> &'static str {
+        self.rule
+    }
+}
+
+impl Debug for Invalid {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Invalid {{ rule: {:?} }}", self.rule)
+    }
+}
+
+impl Display for Invalid {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Invalid: {}", self.rule)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Invalid {}
+
+impl From<Invalid> for Error {
+    #[inline]
+    fn from(err: Invalid) -> Self {
+        trace!("From Invalid");
+        Error::Invalid { rule: err.rule }
+    }
+}
+
+/// Error returned by fallible push and insert operations, it hands back the input that was rejected
+///
+/// The cause is [`OutOfBounds`] for pushes and [`Error`] for inserts, since the index may also be invalid
+///
+/// [`OutOfBounds`]: ./struct.OutOfBounds.html
+/// [`Error`]: ./enum.Error.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// let mut s = ArrayString::<5>::try_from_str("abc")?;
+/// let err = s.try_push_str(String::from("def")).unwrap_err();
+/// assert_eq!(err.element(), "def");
+/// assert_eq!(err.error(), OutOfBounds::new(6, 5));
+///
+/// let mut overflow = String::new();
+/// overflow.push_str(&err.into_inner());
+/// assert_eq!(overflow, "def");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CapacityError<T, E = OutOfBounds> {
+    element: T,
+    pub(crate) error: E,
+}
+
+impl<T, E> CapacityError<T, E> {
+    /// Creates error holding the rejected `element`
+    #[inline]
+    pub const fn new(element: T, error: E) -> Self {
+        Self { element, error }
+    }
+
+    /// Input that was rejected
+    #[inline]
+    pub const fn element(&self) -> &T {
+        &self.element
+    }
+
+    /// Extracts the input that was rejected
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.element
+    }
+
+    /// Cause of the rejection
+    #[inline]
+    pub fn error(&self) -> E
+    where
+        E: Copy,
+    {
+        self.error
+    }
+
+    /// Maps the rejected input, keeping the cause
+    #[inline]
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> CapacityError<U, E> {
+        CapacityError::new(f(self.element), self.error)
     }
 }
+
+impl<T, E: Debug> Debug for CapacityError<T, E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "CapacityError({:?})", self.error)
+    }
+}
+
+impl<T, E: Display> Display for CapacityError<T, E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, E: Debug + Display> std::error::Error for CapacityError<T, E> {}
+
+impl<T> From<CapacityError<T>> for OutOfBounds {
+    #[inline]
+    fn from(err: CapacityError<T>) -> Self {
+        err.error
+    }
+}
+
+impl<T, E: Into<Error>> From<CapacityError<T, E>> for Error {
+    #[inline]
+    fn from(err: CapacityError<T, E>) -> Self {
+        trace!("From CapacityError");
+        err.error.into()
+    }
+}
+
+/// Items an iterator based operation didn't consume, starting with the item that didn't fit
+pub type Remainder<I> = Chain<Once<<I as Iterator>::Item>, I>;
+
+/// Error of iterator based constructors, hands back the string `S` built so far and the [`Remainder`] of `I`
+///
+/// [`Remainder`]: ./type.Remainder.html
+pub type FromIterError<S, I> = CapacityError<(S, Remainder<I>)>;
+
+/// Error of [`try_extend`], hands back the [`Remainder`] of `I`
+///
+/// [`try_extend`]: ../struct.ArrayString.html#method.try_extend
+/// [`Remainder`]: ./type.Remainder.html
+pub type ExtendError<I> = CapacityError<Remainder<I>>;
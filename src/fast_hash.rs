@@ -0,0 +1,84 @@
+//! Hashing of the whole array instead of the string slice
+//!
+//! [`ArrayString`]'s `Hash` must match `str`'s, because of `Borrow<str>`, [`FastHash`] opts out of that
+
+use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// Wrapper that hashes the full fixed-size array of [`ArrayString`] or [`CacheString`], instead of the variable-length string slice
+///
+/// Bytes after the string are always zeroed, so the array and size identify the string. It's faster to hash, but the hash differs from `str`'s, so it doesn't implement `Borrow<str>` (it can't be looked up in a map by `&str`)
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`CacheString`]: ./struct.CacheString.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(FastHash(CacheString::try_from_str("My String")?));
+/// assert!(set.contains(&FastHash(CacheString::try_from_str("My String")?)));
+/// assert!(!set.contains(&FastHash(CacheString::try_from_str("My String!")?)));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FastHash<S>(pub S);
+
+impl<S> FastHash<S> {
+    /// Extracts the wrapped string
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<const N: usize, P> Hash for FastHash<ArrayString<N, P>>
+where
+    ArrayString<N, P>: ValidCapacity,
+{
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        debug_assert!(self.0.is_tail_zeroed());
+        hasher.write(&self.0.array);
+        hasher.write_u8(self.0.size);
+    }
+}
+
+impl Hash for FastHash<CacheString> {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        FastHash(self.0 .0).hash(hasher);
+    }
+}
+
+impl<S> From<S> for FastHash<S> {
+    #[inline]
+    fn from(s: S) -> Self {
+        Self(s)
+    }
+}
+
+impl<S> Deref for FastHash<S> {
+    type Target = S;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<S> DerefMut for FastHash<S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -1,15 +1,36 @@
 //! Trait implementations for `ArrayString` (that aren't for integration)
 
-use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+#[cfg(not(feature = "strict"))]
+use crate::policy::{Lossy, Reject};
+use crate::utils::{words_cmp, words_eq};
+use crate::{arraystring::sealed::ValidCapacity, prelude::*, Error};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, string::String};
 use core::fmt::{self, Debug, Display, Formatter, Write};
-use core::ops::{Add, Deref, DerefMut, Index, IndexMut};
-use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
-use core::str::{self, FromStr};
+#[cfg(not(feature = "strict"))]
+use core::ops::Add;
+use core::ops::{Deref, DerefMut};
+use core::str::{self, Chars, FromStr};
 use core::{borrow::Borrow, borrow::BorrowMut, cmp::Ordering, hash::Hash, hash::Hasher};
+#[cfg(not(feature = "no-panicking-index"))]
+use core::{ops::Index, ops::IndexMut, slice::SliceIndex};
 #[cfg(all(feature = "no-panic", not(debug_assertions)))]
 use no_panic::no_panic;
 
-impl<const N: usize> Default for ArrayString<N>
+impl<const N: usize, P> Clone for ArrayString<N, P>
+where
+    Self: ValidCapacity,
+{
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, P> Copy for ArrayString<N, P> where Self: ValidCapacity {}
+
+impl<const N: usize, P> Default for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -20,7 +41,7 @@ where
     }
 }
 
-impl<const N: usize> AsRef<str> for ArrayString<N>
+impl<const N: usize, P> AsRef<str> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -35,7 +56,7 @@ where
     }
 }
 
-impl<const N: usize> AsMut<str> for ArrayString<N>
+impl<const N: usize, P> AsMut<str> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -54,7 +75,7 @@ where
     }
 }
 
-impl<const N: usize> AsRef<[u8]> for ArrayString<N>
+impl<const N: usize, P> AsRef<[u8]> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -67,18 +88,22 @@ where
     }
 }
 
-impl<'a, const N: usize> From<&'a str> for ArrayString<N>
+#[cfg_attr(docs_rs_workaround, doc(cfg(not(feature = "strict"))))]
+#[cfg(not(feature = "strict"))]
+impl<const N: usize, P: Lossy> From<&str> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn from(s: &str) -> Self {
-        Self::from_str_truncate(s)
+        let mut out = Self::new();
+        let _ = out.push_str(s);
+        out
     }
 }
 
-impl<const N: usize> FromStr for ArrayString<N>
+impl<const N: usize, P> FromStr for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -91,43 +116,128 @@ where
     }
 }
 
-impl<const N: usize> Debug for ArrayString<N>
+/// Lossy policies get `From<&str>` instead, unless the `strict` feature removes it
+///
+/// Their `TryFrom<&str>` is then std's blanket impl over `From<&str>`, it never fails and truncates text that doesn't fit
+#[cfg(not(feature = "strict"))]
+impl<const N: usize> TryFrom<&str> for ArrayString<N, Reject>
 where
     Self: ValidCapacity,
 {
+    type Error = OutOfBounds;
+
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.debug_struct("ArrayString")
-            .field("array", &self.as_str())
-            .field("size", &self.size)
-            .finish()
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
     }
 }
 
-impl<const N: usize> PartialEq<str> for ArrayString<N>
+#[cfg(feature = "strict")]
+impl<const N: usize, P> TryFrom<&str> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
+    type Error = OutOfBounds;
+
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn eq(&self, other: &str) -> bool {
-        self.as_str().eq(other)
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+/// Hands back the `String` if it doesn't fit
+#[cfg(feature = "alloc")]
+impl<const N: usize, P> TryFrom<String> for ArrayString<N, P>
+where
+    Self: ValidCapacity,
+{
+    type Error = CapacityError<String>;
+
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let mut out = Self::new();
+        out.try_push_str(s)?;
+        Ok(out)
     }
 }
 
-impl<const N: usize> PartialEq<&str> for ArrayString<N>
+/// Hands back the `Cow` if it doesn't fit
+#[cfg(feature = "alloc")]
+impl<'a, const N: usize, P> TryFrom<Cow<'a, str>> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
+    type Error = CapacityError<Cow<'a, str>>;
+
+    #[inline]
+    fn try_from(s: Cow<'a, str>) -> Result<Self, Self::Error> {
+        let mut out = Self::new();
+        out.try_push_str(s)?;
+        Ok(out)
+    }
+}
+
+/// Returns `Error::Utf8` on invalid utf-8 data and `Error::OutOfBounds` if it doesn't fit
+impl<const N: usize, P> TryFrom<&[u8]> for ArrayString<N, P>
+where
+    Self: ValidCapacity,
+{
+    type Error = Error;
+
+    // Not `no_panic`: `str::from_utf8` can't be proven panic free
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_from_str(str::from_utf8(bytes)?)?)
+    }
+}
+
+/// Returns `Error::Utf16` on invalid utf-16 data and `Error::OutOfBounds` if it doesn't fit
+impl<const N: usize, P> TryFrom<&[u16]> for ArrayString<N, P>
+where
+    Self: ValidCapacity,
+{
+    type Error = Error;
+
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn eq(&self, other: &&str) -> bool {
-        self.eq(*other)
+    fn try_from(slice: &[u16]) -> Result<Self, Self::Error> {
+        Self::try_from_utf16(slice)
+    }
+}
+
+/// Hands back the `char` if it doesn't fit, only possible below 4 bytes of capacity
+impl<const N: usize, P> TryFrom<char> for ArrayString<N, P>
+where
+    Self: ValidCapacity,
+{
+    type Error = CapacityError<char>;
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        let mut out = Self::new();
+        out.try_push(ch)?;
+        Ok(out)
+    }
+}
+
+impl<const N: usize, P> Debug for ArrayString<N, P>
+where
+    Self: ValidCapacity,
+{
+    // Not `no_panic`: the formatter writes to a sink chosen by the caller, which may panic
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ArrayString")
+            .field("array", &self.as_str())
+            .field("size", &self.size)
+            .finish()
     }
 }
 
-impl<const N: usize> Borrow<str> for ArrayString<N>
+impl<const N: usize, P> Borrow<str> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -138,7 +248,7 @@ where
     }
 }
 
-impl<const N: usize> BorrowMut<str> for ArrayString<N>
+impl<const N: usize, P> BorrowMut<str> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -149,7 +259,7 @@ where
     }
 }
 
-impl<const N: usize> Hash for ArrayString<N>
+impl<const N: usize, P> Hash for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -160,42 +270,125 @@ where
     }
 }
 
-impl<const N: usize> PartialEq for ArrayString<N>
+impl<const N: usize, const M: usize, P> PartialEq<ArrayString<M, P>> for ArrayString<N, P>
 where
     Self: ValidCapacity,
+    ArrayString<M, P>: ValidCapacity,
 {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn eq(&self, other: &Self) -> bool {
-        self.as_str().eq(other.as_str())
+    fn eq(&self, other: &ArrayString<M, P>) -> bool {
+        debug_assert!(self.is_tail_zeroed() && other.is_tail_zeroed());
+        // Only converts when `N == M`, bytes after the string are zeroed, so the whole array can be compared in words
+        match <&[u8; N]>::try_from(other.array.as_slice()) {
+            Ok(array) => self.size == other.size && words_eq(&self.array, array),
+            Err(_) => self.as_str() == other.as_str(),
+        }
     }
 }
 
-impl<const N: usize> Eq for ArrayString<N> where Self: ValidCapacity {}
+impl<const N: usize, P> Eq for ArrayString<N, P> where Self: ValidCapacity {}
 
-impl<const N: usize> Ord for ArrayString<N>
+impl<const N: usize, P> Ord for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn cmp(&self, other: &Self) -> Ordering {
-        self.as_str().cmp(other.as_str())
+        debug_assert!(self.is_tail_zeroed() && other.is_tail_zeroed());
+        // Zero is the smallest byte, so a zeroed tail orders like the end of the string,
+        // strings that only differ by trailing nul chars are ordered by size
+        words_cmp(&self.array, &other.array).then_with(|| self.size.cmp(&other.size))
     }
 }
 
-impl<const N: usize> PartialOrd for ArrayString<N>
+impl<const N: usize, const M: usize, P> PartialOrd<ArrayString<M, P>> for ArrayString<N, P>
 where
     Self: ValidCapacity,
+    ArrayString<M, P>: ValidCapacity,
 {
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl<'a, const N: usize> Add<&'a str> for ArrayString<N>
+    fn partial_cmp(&self, other: &ArrayString<M, P>) -> Option<Ordering> {
+        debug_assert!(self.is_tail_zeroed() && other.is_tail_zeroed());
+        // Only converts when `N == M`, same as `Ord::cmp` for it
+        match <&[u8; N]>::try_from(other.array.as_slice()) {
+            Ok(array) => {
+                Some(words_cmp(&self.array, array).then_with(|| self.size.cmp(&other.size)))
+            }
+            Err(_) => self.as_str().partial_cmp(other.as_str()),
+        }
+    }
+}
+
+/// Compares `ArrayString` with other string types through `str`, in both directions
+macro_rules! impl_cmp {
+    ($($(#[$attr:meta])* $other:ty),* $(,)?) => {$(
+        $(#[$attr])*
+        impl<const N: usize, P> PartialEq<$other> for ArrayString<N, P>
+        where
+            Self: ValidCapacity,
+        {
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn eq(&self, other: &$other) -> bool {
+                self.as_str() == &other[..]
+            }
+        }
+
+        $(#[$attr])*
+        impl<const N: usize, P> PartialEq<ArrayString<N, P>> for $other
+        where
+            ArrayString<N, P>: ValidCapacity,
+        {
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn eq(&self, other: &ArrayString<N, P>) -> bool {
+                &self[..] == other.as_str()
+            }
+        }
+
+        $(#[$attr])*
+        impl<const N: usize, P> PartialOrd<$other> for ArrayString<N, P>
+        where
+            Self: ValidCapacity,
+        {
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn partial_cmp(&self, other: &$other) -> Option<Ordering> {
+                self.as_str().partial_cmp(&other[..])
+            }
+        }
+
+        $(#[$attr])*
+        impl<const N: usize, P> PartialOrd<ArrayString<N, P>> for $other
+        where
+            ArrayString<N, P>: ValidCapacity,
+        {
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn partial_cmp(&self, other: &ArrayString<N, P>) -> Option<Ordering> {
+                self[..].partial_cmp(other.as_str())
+            }
+        }
+    )*};
+}
+
+impl_cmp!(
+    str,
+    &str,
+    #[cfg(feature = "alloc")]
+    String,
+    #[cfg(feature = "alloc")]
+    Cow<'_, str>,
+    #[cfg(feature = "alloc")]
+    Box<str>,
+);
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(not(feature = "strict"))))]
+#[cfg(not(feature = "strict"))]
+impl<const N: usize, P: Lossy> Add<&str> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -204,12 +397,12 @@ where
     #[inline]
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn add(mut self, other: &str) -> Self::Output {
-        self.push_str_truncate(other);
+        let _ = self.push_str(other);
         self
     }
 }
 
-impl<const N: usize> Write for ArrayString<N>
+impl<const N: usize, P> Write for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -220,18 +413,18 @@ where
     }
 }
 
-impl<const N: usize> Display for ArrayString<N>
+impl<const N: usize, P> Display for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
+    // Not `no_panic`: the formatter writes to a sink chosen by the caller, which may panic
     #[inline]
-    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
 
-impl<const N: usize> Deref for ArrayString<N>
+impl<const N: usize, P> Deref for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -244,7 +437,7 @@ where
     }
 }
 
-impl<const N: usize> DerefMut for ArrayString<N>
+impl<const N: usize, P> DerefMut for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -255,37 +448,54 @@ where
     }
 }
 
-impl<const N: usize> FromIterator<char> for ArrayString<N>
+#[cfg_attr(docs_rs_workaround, doc(cfg(not(feature = "strict"))))]
+#[cfg(not(feature = "strict"))]
+impl<const N: usize, P: Lossy> FromIterator<char> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
-        Self::from_chars_truncate(iter)
+        let mut out = Self::new();
+        out.extend(iter);
+        out
     }
 }
 
-impl<'a, const N: usize> FromIterator<&'a str> for ArrayString<N>
+#[cfg_attr(docs_rs_workaround, doc(cfg(not(feature = "strict"))))]
+#[cfg(not(feature = "strict"))]
+impl<'a, const N: usize, P: Lossy> FromIterator<&'a str> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
-        Self::from_iterator_truncate(iter)
+        let mut out = Self::new();
+        out.extend(iter);
+        out
     }
 }
 
-impl<const N: usize> Extend<char> for ArrayString<N>
+/// Stops at the first char that doesn't fit, after the policy handles it
+#[cfg_attr(docs_rs_workaround, doc(cfg(not(feature = "strict"))))]
+#[cfg(not(feature = "strict"))]
+impl<const N: usize, P: Lossy> Extend<char> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn extend<I: IntoIterator<Item = char>>(&mut self, iterable: I) {
-        self.push_str_truncate(Self::from_chars_truncate(iterable))
+        for ch in iterable {
+            if self.push(ch).is_err() {
+                break;
+            }
+        }
     }
 }
 
-impl<'a, const N: usize> Extend<&'a char> for ArrayString<N>
+#[cfg_attr(docs_rs_workaround, doc(cfg(not(feature = "strict"))))]
+#[cfg(not(feature = "strict"))]
+impl<'a, const N: usize, P: Lossy> Extend<&'a char> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
@@ -295,158 +505,90 @@ where
     }
 }
 
-impl<'a, const N: usize> Extend<&'a str> for ArrayString<N>
+/// Stops at the first string slice that doesn't fit, after the policy handles it
+#[cfg_attr(docs_rs_workaround, doc(cfg(not(feature = "strict"))))]
+#[cfg(not(feature = "strict"))]
+impl<'a, const N: usize, P: Lossy> Extend<&'a str> for ArrayString<N, P>
 where
     Self: ValidCapacity,
 {
     #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
     fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iterable: I) {
-        self.push_str_truncate(Self::from_iterator_truncate(iterable))
-    }
-}
-
-impl<const N: usize> IndexMut<RangeFrom<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
-    #[inline]
-    fn index_mut(&mut self, index: RangeFrom<u8>) -> &mut str {
-        let start = index.start as usize;
-        self.as_mut_str().index_mut(RangeFrom { start })
+        for s in iterable {
+            if self.push_str(s).is_err() {
+                break;
+            }
+        }
     }
 }
 
-impl<const N: usize> IndexMut<RangeTo<u8>> for ArrayString<N>
+impl<const N: usize, P> IntoIterator for ArrayString<N, P>
 where
     Self: ValidCapacity,
+    ArrayString<N>: ValidCapacity,
 {
-    #[inline]
-    fn index_mut(&mut self, index: RangeTo<u8>) -> &mut str {
-        let end = index.end as usize;
-        self.as_mut_str().index_mut(RangeTo { end })
-    }
-}
+    type Item = char;
+    type IntoIter = IntoChars<N>;
 
-impl<const N: usize> IndexMut<RangeFull> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
     #[inline]
-    fn index_mut(&mut self, index: RangeFull) -> &mut str {
-        self.as_mut_str().index_mut(index)
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoChars::new(self)
     }
 }
 
-impl<const N: usize> IndexMut<Range<u8>> for ArrayString<N>
+impl<'a, const N: usize, P> IntoIterator for &'a ArrayString<N, P>
 where
-    Self: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
-    #[inline]
-    fn index_mut(&mut self, index: Range<u8>) -> &mut str {
-        let (start, end) = (index.start as usize, index.end as usize);
-        let range = Range { start, end };
-        self.as_mut_str().index_mut(range)
-    }
-}
+    type Item = char;
+    type IntoIter = Chars<'a>;
 
-impl<const N: usize> IndexMut<RangeToInclusive<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
     #[inline]
-    fn index_mut(&mut self, index: RangeToInclusive<u8>) -> &mut str {
-        let end = index.end as usize;
-        let range = RangeToInclusive { end };
-        self.as_mut_str().index_mut(range)
-    }
-}
-
-impl<const N: usize> IndexMut<RangeInclusive<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
-    #[inline]
-    fn index_mut(&mut self, index: RangeInclusive<u8>) -> &mut str {
-        let (start, end) = (*index.start() as usize, *index.end() as usize);
-        let range = RangeInclusive::new(start, end);
-        self.as_mut_str().index_mut(range)
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.chars()
     }
 }
 
-impl<const N: usize> Index<RangeFrom<u8>> for ArrayString<N>
+impl<'a, const N: usize, P> IntoIterator for &'a mut ArrayString<N, P>
 where
-    Self: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
-    type Output = str;
+    type Item = char;
+    type IntoIter = Chars<'a>;
 
     #[inline]
-    fn index(&self, index: RangeFrom<u8>) -> &Self::Output {
-        let start = index.start as usize;
-        self.as_str().index(RangeFrom { start })
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.chars()
     }
 }
 
-impl<const N: usize> Index<RangeTo<u8>> for ArrayString<N>
+/// Panics like `str` indexing, use [`ArrayString::get_mut`] to get an `Option` instead
+#[cfg(not(feature = "no-panicking-index"))]
+impl<I, const N: usize, P> IndexMut<I> for ArrayString<N, P>
 where
+    I: SliceIndex<str>,
     Self: ValidCapacity,
 {
-    type Output = str;
-
     #[inline]
-    fn index(&self, index: RangeTo<u8>) -> &Self::Output {
-        let end = index.end as usize;
-        self.as_str().index(RangeTo { end })
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        self.as_mut_str().index_mut(index)
     }
 }
 
-impl<const N: usize> Index<RangeFull> for ArrayString<N>
+/// Panics like `str` indexing, use [`ArrayString::get`] to get an `Option` instead
+#[cfg(not(feature = "no-panicking-index"))]
+impl<I, const N: usize, P> Index<I> for ArrayString<N, P>
 where
+    I: SliceIndex<str>,
     Self: ValidCapacity,
 {
-    type Output = str;
+    type Output = I::Output;
 
     #[inline]
-    fn index(&self, index: RangeFull) -> &Self::Output {
+    fn index(&self, index: I) -> &Self::Output {
         self.as_str().index(index)
     }
 }
-
-impl<const N: usize> Index<Range<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
-    type Output = str;
-
-    #[inline]
-    fn index(&self, index: Range<u8>) -> &Self::Output {
-        let (start, end) = (index.start as usize, index.end as usize);
-        self.as_str().index(Range { start, end })
-    }
-}
-
-impl<const N: usize> Index<RangeToInclusive<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
-    type Output = str;
-
-    #[inline]
-    fn index(&self, index: RangeToInclusive<u8>) -> &Self::Output {
-        let end = index.end as usize;
-        self.as_str().index(RangeToInclusive { end })
-    }
-}
-
-impl<const N: usize> Index<RangeInclusive<u8>> for ArrayString<N>
-where
-    Self: ValidCapacity,
-{
-    type Output = str;
-
-    #[inline]
-    fn index(&self, index: RangeInclusive<u8>) -> &Self::Output {
-        let (start, end) = (*index.start() as usize, *index.end() as usize);
-        let range = RangeInclusive::new(start, end);
-        self.as_str().index(range)
-    }
-}
@@ -0,0 +1,1208 @@
+//! Small string that stores text in an [`ArrayString`] and spills to a `String` when it outgrows it
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+#![allow(clippy::len_without_is_empty)]
+
+use crate::arraystring::sealed::ValidCapacity;
+use crate::utils::{is_inside_len, is_str_char_boundary, range_bounds};
+use crate::{error::Index, prelude::*, Error};
+use alloc::string::String;
+use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
+use core::fmt::{self, Debug, Display, Formatter, Write};
+use core::iter::FusedIterator;
+use core::{borrow::Borrow, borrow::BorrowMut, ops::*};
+use core::{cmp::Ordering, hash::Hash, hash::Hasher, str::FromStr};
+#[cfg(feature = "logs")]
+use log::{debug, trace};
+
+/// Where the text currently lives
+#[derive(Clone)]
+enum Repr<const N: usize>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    Inline(ArrayString<N>),
+    Heap(String),
+}
+
+/// String stored inline in an [`ArrayString`] that moves to the heap instead of erroring or truncating
+///
+/// Operations that would overflow [`capacity`] (like [`try_push_str`], [`try_insert_str`] or [`replace_range`]) move the text to a `String`, it's moved back inline with [`shrink_inline`]
+///
+/// Has the same methods as [`ArrayString`], so it can replace it without code changes, but errors caused only by capacity are never returned and `*_truncate` methods never truncate
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+/// [`try_push_str`]: ./struct.InlineOrHeap.html#method.try_push_str
+/// [`try_insert_str`]: ./struct.InlineOrHeap.html#method.try_insert_str
+/// [`replace_range`]: ./struct.InlineOrHeap.html#method.replace_range
+/// [`shrink_inline`]: ./struct.InlineOrHeap.html#method.shrink_inline
+#[derive(Clone)]
+pub struct InlineOrHeap<const N: usize>(Repr<N>)
+where
+    ArrayString<N>: ValidCapacity;
+
+impl<const N: usize> InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    /// Creates new empty string, stored inline.
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = InlineOrHeap::<23>::new();
+    /// assert!(string.is_empty());
+    /// assert!(string.is_inline());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self(Repr::Inline(ArrayString::new()))
+    }
+
+    /// Creates new `InlineOrHeap` from string slice, stored inline if it fits in [`capacity`].
+    ///
+    /// Never fails, kept for parity with [`ArrayString`]
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = InlineOrHeap::<23>::try_from_str("My String")?;
+    /// assert_eq!(string.as_str(), "My String");
+    /// assert!(string.is_inline());
+    ///
+    /// let big = "0".repeat(InlineOrHeap::<23>::capacity() + 1);
+    /// let string = InlineOrHeap::<23>::try_from_str(&big)?;
+    /// assert_eq!(string.as_str(), big);
+    /// assert!(!string.is_inline());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_from_str(string: impl AsRef<str>) -> Result<Self, OutOfBounds> {
+        Ok(Self::from_str_truncate(string))
+    }
+
+    /// Creates new `InlineOrHeap` from string slice, stored inline if it fits in [`capacity`].
+    ///
+    /// Never truncates, kept for parity with [`ArrayString`]
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let big = "0".repeat(InlineOrHeap::<23>::capacity() + 1);
+    /// let string = InlineOrHeap::<23>::from_str_truncate(&big);
+    /// assert_eq!(string.as_str(), big);
+    /// ```
+    #[inline]
+    pub fn from_str_truncate(string: impl AsRef<str>) -> Self {
+        trace!("InlineOrHeap from str: {}", string.as_ref());
+        let mut s = Self::new();
+        s.push_str(string);
+        s
+    }
+
+    /// Creates new `InlineOrHeap` from string slice iterator.
+    ///
+    /// Never fails, kept for parity with [`ArrayString`]
+    ///
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// let string = InlineOrHeap::<23>::try_from_iterator(&["My String", " My Other String"][..])?;
+    /// assert_eq!(string.as_str(), "My String My Other String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_from_iterator(
+        iter: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Self, OutOfBounds> {
+        Ok(Self::from_iterator_truncate(iter))
+    }
+
+    /// Creates new `InlineOrHeap` from string slice iterator.
+    ///
+    /// Never truncates, kept for parity with [`ArrayString`]
+    ///
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// let string = InlineOrHeap::<5>::from_iterator_truncate(&["My String", " Other"][..]);
+    /// assert_eq!(string.as_str(), "My String Other");
+    /// ```
+    #[inline]
+    pub fn from_iterator_truncate(iter: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        trace!("InlineOrHeap from iterator");
+        let mut out = Self::new();
+        for s in iter {
+            out.push_str(s);
+        }
+        out
+    }
+
+    /// Creates new `InlineOrHeap` from char iterator.
+    ///
+    /// Never fails, kept for parity with [`ArrayString`]
+    ///
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = InlineOrHeap::<5>::try_from_chars("My String".chars())?;
+    /// assert_eq!(string.as_str(), "My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_from_chars(iter: impl IntoIterator<Item = char>) -> Result<Self, OutOfBounds> {
+        Ok(Self::from_chars_truncate(iter))
+    }
+
+    /// Creates new `InlineOrHeap` from char iterator.
+    ///
+    /// Never truncates, kept for parity with [`ArrayString`]
+    ///
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = InlineOrHeap::<5>::from_chars_truncate("My String".chars());
+    /// assert_eq!(string.as_str(), "My String");
+    /// ```
+    #[inline]
+    pub fn from_chars_truncate(iter: impl IntoIterator<Item = char>) -> Self {
+        trace!("InlineOrHeap from chars");
+        let mut out = Self::new();
+        for ch in iter {
+            out.push(ch);
+        }
+        out
+    }
+
+    /// Creates new `InlineOrHeap` from `u16` slice, returning [`Utf16`] on invalid utf-16 data.
+    ///
+    /// [`Utf16`]: ./error/enum.Error.html#variant.Utf16
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let music = [0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069, 0x0063];
+    /// let string = InlineOrHeap::<5>::try_from_utf16(music)?;
+    /// assert_eq!(string.as_str(), "𝄞music");
+    ///
+    /// let invalid_utf16 = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
+    /// assert_eq!(InlineOrHeap::<5>::try_from_utf16(invalid_utf16), Err(Error::Utf16));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_from_utf16(slice: impl AsRef<[u16]>) -> Result<Self, Error> {
+        Ok(Self::from_utf16_truncate(slice)?)
+    }
+
+    /// Creates new `InlineOrHeap` from `u16` slice, returning [`Utf16`] on invalid utf-16 data.
+    ///
+    /// Never truncates, kept for parity with [`ArrayString`]
+    ///
+    /// [`Utf16`]: ./error/enum.Error.html#variant.Utf16
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::{error::Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let music = [0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069, 0x0063];
+    /// let string = InlineOrHeap::<5>::from_utf16_truncate(music)?;
+    /// assert_eq!(string.as_str(), "𝄞music");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_utf16_truncate(slice: impl AsRef<[u16]>) -> Result<Self, Utf16> {
+        debug!("InlineOrHeap from utf16: {:?}", slice.as_ref());
+        let mut out = Self::new();
+        for ch in decode_utf16(slice.as_ref().iter().cloned()) {
+            out.push(ch?);
+        }
+        Ok(out)
+    }
+
+    /// Creates new `InlineOrHeap` from `u16` slice, replacing invalid utf-16 data with `REPLACEMENT_CHARACTER` (\u{FFFD}).
+    ///
+    /// Never truncates, kept for parity with [`ArrayString`]
+    ///
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let music = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
+    /// let string = InlineOrHeap::<5>::from_utf16_lossy_truncate(music);
+    /// assert_eq!(string.as_str(), "𝄞mu\u{FFFD}ic");
+    /// ```
+    #[inline]
+    pub fn from_utf16_lossy_truncate(slice: impl AsRef<[u16]>) -> Self {
+        debug!("InlineOrHeap from utf16 lossy: {:?}", slice.as_ref());
+        Self::from_chars_truncate(
+            decode_utf16(slice.as_ref().iter().cloned())
+                .map(|ch| ch.unwrap_or(REPLACEMENT_CHARACTER)),
+        )
+    }
+
+    /// Extracts a string slice containing the entire `InlineOrHeap`
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = InlineOrHeap::<23>::try_from_str("My String")?;
+    /// assert_eq!(s.as_str(), "My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Inline(s) => s.as_str(),
+            Repr::Heap(s) => s.as_str(),
+        }
+    }
+
+    /// Extracts a mutable string slice containing the entire `InlineOrHeap`
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<23>::try_from_str("My String")?;
+    /// assert_eq!(s.as_mut_str(), "My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        match &mut self.0 {
+            Repr::Inline(s) => s.as_mut_str(),
+            Repr::Heap(s) => s.as_mut_str(),
+        }
+    }
+
+    /// Returns maximum string capacity stored inline, defined at compile time, bigger strings are moved to the heap
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(InlineOrHeap::<32>::capacity(), 32);
+    /// ```
+    #[inline]
+    pub const fn capacity() -> usize {
+        N
+    }
+
+    /// Returns `true` if the text is stored inline, `false` if it was moved to the heap.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<3>::try_from_str("abc")?;
+    /// assert!(s.is_inline());
+    /// s.push('d');
+    /// assert!(!s.is_inline());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.0, Repr::Inline(_))
+    }
+
+    /// Moves the text back inline if it fits in [`capacity`], returns if it's stored inline.
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<3>::try_from_str("abcd")?;
+    /// assert!(!s.shrink_inline());
+    /// s.pop();
+    /// assert!(s.shrink_inline());
+    /// assert_eq!(s.as_str(), "abc");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn shrink_inline(&mut self) -> bool {
+        debug!("Shrink inline");
+        if let Repr::Heap(s) = &self.0 {
+            if let Ok(inline) = ArrayString::try_from_str(s) {
+                self.0 = Repr::Inline(inline);
+            }
+        }
+        self.is_inline()
+    }
+
+    /// Converts into a `String`, only allocates if stored inline.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let s = InlineOrHeap::<3>::try_from_str("abcd")?;
+    /// assert_eq!(s.into_string(), "abcd");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn into_string(self) -> String {
+        match self.0 {
+            Repr::Inline(s) => String::from(s.as_str()),
+            Repr::Heap(s) => s,
+        }
+    }
+
+    /// Pushes string slice to the end of the `InlineOrHeap`, moving it to the heap if it doesn't fit in [`capacity`].
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<10>::try_from_str("My String")?;
+    /// s.push_str(" My other String");
+    /// assert_eq!(s.as_str(), "My String My other String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn push_str(&mut self, string: impl AsRef<str>) {
+        trace!("Push str: {}", string.as_ref());
+        let len = self.len();
+        let _ = self.replace_range(len..len, string);
+    }
+
+    /// Pushes string slice to the end of the `InlineOrHeap`, moving it to the heap if it doesn't fit in [`capacity`].
+    ///
+    /// Never fails, kept for parity with [`ArrayString`]
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<10>::try_from_str("My String")?;
+    /// s.try_push_str(" My other String")?;
+    /// assert_eq!(s.as_str(), "My String My other String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_push_str<S: AsRef<str>>(&mut self, string: S) -> Result<(), CapacityError<S>> {
+        self.push_str(string);
+        Ok(())
+    }
+
+    /// Pushes string slice to the end of the `InlineOrHeap`, moving it to the heap if it doesn't fit in [`capacity`].
+    ///
+    /// Never truncates, kept for parity with [`ArrayString`]
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<23>::default();
+    /// s.push_str_truncate("0".repeat(InlineOrHeap::<23>::capacity() + 1));
+    /// assert_eq!(s.as_str(), "0".repeat(InlineOrHeap::<23>::capacity() + 1).as_str());
+    /// ```
+    #[inline]
+    pub fn push_str_truncate(&mut self, string: impl AsRef<str>) {
+        self.push_str(string);
+    }
+
+    /// Inserts character to the end of the `InlineOrHeap`, moving it to the heap if it doesn't fit in [`capacity`].
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<9>::try_from_str("My String")?;
+    /// s.push('!');
+    /// assert_eq!(s.as_str(), "My String!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        let mut buf = [0; 4];
+        self.push_str(ch.encode_utf8(&mut buf));
+    }
+
+    /// Inserts character to the end of the `InlineOrHeap`, moving it to the heap if it doesn't fit in [`capacity`].
+    ///
+    /// Never fails, kept for parity with [`ArrayString`]
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<9>::try_from_str("My String")?;
+    /// s.try_push('!')?;
+    /// assert_eq!(s.as_str(), "My String!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError<char>> {
+        self.push(ch);
+        Ok(())
+    }
+
+    /// Truncates `InlineOrHeap` to specified size (if smaller than current size and a valid utf-8 char index).
+    ///
+    /// Stays in the heap if it was there, see [`shrink_inline`]
+    ///
+    /// [`shrink_inline`]: ./struct.InlineOrHeap.html#method.shrink_inline
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<5>::try_from_str("My String")?;
+    /// s.truncate(5)?;
+    /// assert_eq!(s.as_str(), "My St");
+    ///
+    /// // Index is not at a valid char
+    /// let mut s = InlineOrHeap::<5>::try_from_str("🤔")?;
+    /// assert!(s.truncate(1).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, size: usize) -> Result<(), Utf8> {
+        debug!("Truncate: {}", size);
+        match &mut self.0 {
+            Repr::Inline(s) => s.truncate(size),
+            Repr::Heap(s) if size >= s.len() => Ok(()),
+            Repr::Heap(s) => {
+                is_str_char_boundary(s, size)?;
+                s.truncate(size);
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes last character from `InlineOrHeap`, if any.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<1>::try_from_str("A🤔")?;
+    /// assert_eq!(s.pop(), Some('🤔'));
+    /// assert_eq!(s.pop(), Some('A'));
+    /// assert_eq!(s.pop(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        debug!("Pop");
+        match &mut self.0 {
+            Repr::Inline(s) => s.pop(),
+            Repr::Heap(s) => s.pop(),
+        }
+    }
+
+    /// Removes whitespaces from the beggining and end of the string
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = InlineOrHeap::<5>::try_from_str("   to be trimmed     ")?;
+    /// string.trim();
+    /// assert_eq!(string.as_str(), "to be trimmed");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn trim(&mut self) {
+        trace!("Trim");
+        let trimmed = self.as_str().trim();
+        let start = trimmed.as_ptr() as usize - self.as_str().as_ptr() as usize;
+        let end = start + trimmed.len();
+        let _ = self.replace_range(end.., "");
+        let _ = self.replace_range(..start, "");
+    }
+
+    /// Removes specified char from `InlineOrHeap`
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<5>::try_from_str("ABCD🤔")?;
+    /// assert_eq!(s.remove("ABCD🤔".len()), Err(Error::Index { index: 8, len: 8 }));
+    /// assert_eq!(s.remove(10), Err(Error::Index { index: 10, len: 8 }));
+    /// assert_eq!(s.remove(6), Err(Error::Utf8 { index: 6, valid_up_to: 4 }));
+    /// assert_eq!(s.remove(0), Ok('A'));
+    /// assert_eq!(s.as_str(), "BCD🤔");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> Result<char, Error> {
+        debug!("Remove: {}", idx);
+        match &mut self.0 {
+            Repr::Inline(s) => s.remove(idx),
+            Repr::Heap(s) => {
+                if idx >= s.len() {
+                    return Err(Index::new(idx, s.len()).into());
+                }
+                is_str_char_boundary(s, idx)?;
+                Ok(s.remove(idx))
+            }
+        }
+    }
+
+    /// Retains only the characters specified by the predicate.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<5>::try_from_str("ABCD🤔")?;
+    /// s.retain(|c| c != '🤔');
+    /// assert_eq!(s.as_str(), "ABCD");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn retain(&mut self, f: impl FnMut(char) -> bool) {
+        trace!("Retain");
+        match &mut self.0 {
+            Repr::Inline(s) => s.retain(f),
+            Repr::Heap(s) => s.retain(f),
+        }
+    }
+
+    /// Inserts character at specified index, moving the string to the heap if it doesn't fit in [`capacity`].
+    ///
+    /// Returns [`Index`] if `idx` is out of bounds and [`Utf8`] if `idx` is not a char position
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    /// [`Index`]: ./error/enum.Error.html#variant.Index
+    /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<8>::try_from_str("ABCD🤔")?;
+    /// s.try_insert(1, 'A')?;
+    /// s.try_insert(2, 'B')?;
+    /// assert_eq!(s.as_str(), "AABBCD🤔");
+    /// assert_eq!(s.try_insert(20, 'C'), Err(CapacityError::new('C', Error::Index { index: 20, len: 10 })));
+    /// assert_eq!(s.try_insert(8, 'D'), Err(CapacityError::new('D', Error::Utf8 { index: 8, valid_up_to: 6 })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_insert(&mut self, idx: usize, ch: char) -> Result<(), CapacityError<char, Error>> {
+        let mut buf = [0; 4];
+        self.try_insert_str(idx, ch.encode_utf8(&mut buf))
+            .map_err(|err| err.map(|_| ch))
+    }
+
+    /// Inserts string slice at specified index, moving the string to the heap if it doesn't fit in [`capacity`].
+    ///
+    /// Returns [`Index`] if `idx` is out of bounds and [`Utf8`] if `idx` is not a char position
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    /// [`Index`]: ./error/enum.Error.html#variant.Index
+    /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<8>::try_from_str("ABCD🤔")?;
+    /// s.try_insert_str(1, "AB")?;
+    /// s.try_insert_str(1, "BC")?;
+    /// assert_eq!(s.as_str(), "ABCABBCD🤔");
+    /// assert_eq!(s.try_insert_str(20, "C"), Err(CapacityError::new("C", Error::Index { index: 20, len: 12 })));
+    /// assert_eq!(s.try_insert_str(10, "D"), Err(CapacityError::new("D", Error::Utf8 { index: 10, valid_up_to: 8 })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_insert_str<S: AsRef<str>>(
+        &mut self,
+        idx: usize,
+        string: S,
+    ) -> Result<(), CapacityError<S, Error>> {
+        trace!("Try insert at {idx} str: {:?}", string.as_ref());
+        let inserted = is_inside_len(idx, self.len())
+            .map_err(Error::from)
+            .and_then(|()| self.replace_range(idx..idx, string.as_ref()));
+        match inserted {
+            Ok(()) => Ok(()),
+            Err(err) => Err(CapacityError::new(string, err)),
+        }
+    }
+
+    /// Inserts string slice at specified index, moving the string to the heap if it doesn't fit in [`capacity`].
+    ///
+    /// Never truncates, kept for parity with [`ArrayString`]
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    /// [`ArrayString`]: ./struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<5>::try_from_str("ABCD🤔")?;
+    /// s.insert_str_truncate(1, "AB")?;
+    /// assert_eq!(s.as_str(), "AABBCD🤔");
+    /// assert_eq!(s.insert_str_truncate(20, "C"), Err(Error::Index { index: 20, len: 10 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn insert_str_truncate(
+        &mut self,
+        idx: usize,
+        string: impl AsRef<str>,
+    ) -> Result<(), Error> {
+        Ok(self.try_insert_str(idx, string)?)
+    }
+
+    /// Returns `InlineOrHeap` length.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<5>::try_from_str("ABCD")?;
+    /// assert_eq!(s.len(), 4);
+    /// s.push('🤔');
+    /// assert_eq!(s.len(), 8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Splits `InlineOrHeap` in two if `at` is smaller than `self.len()`.
+    ///
+    /// Returns [`Utf8`] if `at` does not lie at a valid utf-8 char boundary and [`Index`] if it's out of bounds
+    ///
+    /// [`Index`]: ./error/enum.Error.html#variant.Index
+    /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<5>::try_from_str("AB🤔CD")?;
+    /// assert_eq!(s.split_off(6)?.as_str(), "CD");
+    /// assert_eq!(s.as_str(), "AB🤔");
+    /// assert_eq!(s.split_off(20), Err(Error::Index { index: 20, len: 6 }));
+    /// assert_eq!(s.split_off(4), Err(Error::Utf8 { index: 4, valid_up_to: 2 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> Result<Self, Error> {
+        debug!("Split off");
+        Ok(self.drain(at..)?.0)
+    }
+
+    /// Empties `InlineOrHeap`, the allocation is kept if it was moved to the heap
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<5>::try_from_str("ABCD")?;
+    /// assert!(!s.is_empty());
+    /// s.clear();
+    /// assert!(s.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        trace!("Clear");
+        match &mut self.0 {
+            Repr::Inline(s) => s.clear(),
+            Repr::Heap(s) => s.clear(),
+        }
+    }
+
+    /// Creates a draining iterator that removes the specified range in the `InlineOrHeap` and yields the removed chars.
+    ///
+    /// Note: The element range is removed even if the iterator is not consumed until the end.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<2>::try_from_str("ABCD🤔")?;
+    /// assert_eq!(s.drain(..3)?.collect::<Vec<_>>(), vec!['A', 'B', 'C']);
+    /// assert_eq!(s.as_str(), "D🤔");
+    ///
+    /// assert_eq!(s.drain(3..), Err(Error::Utf8 { index: 3, valid_up_to: 1 }));
+    /// assert_eq!(s.drain(10..), Err(Error::Index { index: 10, len: 5 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Result<InlineOrHeapDrain<N>, Error> {
+        let (start, end) = range_bounds(&range, self.len());
+        debug!("Drain iterator (len: {}): {start}..{end}", self.len());
+        self.check_range(start, end)?;
+        let drained = Self::from_str_truncate(
+            self.as_str()
+                .get(start..end)
+                .ok_or(OutOfBounds::new(end, self.len()))?,
+        );
+        self.replace_range(start..end, "")?;
+        Ok(InlineOrHeapDrain(drained))
+    }
+
+    /// Removes the specified range of the `InlineOrHeap`, and replaces it with the given string. The given string doesn't need to have the same length as the range.
+    ///
+    /// Moves the string to the heap if the result doesn't fit in [`capacity`]
+    ///
+    /// [`capacity`]: ./struct.InlineOrHeap.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = InlineOrHeap::<8>::try_from_str("ABCD🤔")?;
+    /// s.replace_range(2..4, "EFGHI")?;
+    /// assert_eq!(s, "ABEFGHI🤔");
+    /// assert!(!s.is_inline());
+    ///
+    /// assert_eq!(s.replace_range(9.., "J"), Err(Error::Utf8 { index: 9, valid_up_to: 7 }));
+    /// assert_eq!(s.replace_range(..90, "K"), Err(Error::Index { index: 90, len: 11 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn replace_range(
+        &mut self,
+        r: impl RangeBounds<usize>,
+        with: impl AsRef<str>,
+    ) -> Result<(), Error> {
+        let with = with.as_ref();
+        let (start, end) = range_bounds(&r, self.len());
+        debug!(
+            "Replace range (len: {}) ({start}..{end}) with {with}",
+            self.len()
+        );
+        self.check_range(start, end)?;
+
+        let len = self.len() - (end - start) + with.len();
+        if let Repr::Inline(s) = &self.0 {
+            if len > N {
+                debug!("Moving to the heap: {len} > {N}");
+                let mut heap = String::with_capacity(len);
+                heap.push_str(s.as_str());
+                self.0 = Repr::Heap(heap);
+            }
+        }
+
+        match &mut self.0 {
+            Repr::Inline(s) => s.replace_range(start..end, with),
+            Repr::Heap(s) => {
+                s.replace_range(start..end, with);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns error if `start..end` is not a valid range of char boundaries
+    #[inline]
+    fn check_range(&self, start: usize, end: usize) -> Result<(), Error> {
+        is_inside_len(start, end)?;
+        is_inside_len(end, self.len())?;
+        is_str_char_boundary(self.as_str(), start)?;
+        is_str_char_boundary(self.as_str(), end)?;
+        Ok(())
+    }
+}
+
+/// A draining iterator for [`InlineOrHeap`].
+///
+/// Created through [`drain`]
+///
+/// [`InlineOrHeap`]: ./struct.InlineOrHeap.html
+/// [`drain`]: ./struct.InlineOrHeap.html#method.drain
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InlineOrHeapDrain<const N: usize>(InlineOrHeap<N>)
+where
+    ArrayString<N>: ValidCapacity;
+
+impl<const N: usize> InlineOrHeapDrain<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    /// Extracts string slice containing the remaining characters of `InlineOrHeapDrain`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<const N: usize> Iterator for InlineOrHeapDrain<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.remove(0).ok()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for InlineOrHeapDrain<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+}
+
+impl<const N: usize> FusedIterator for InlineOrHeapDrain<N> where ArrayString<N>: ValidCapacity {}
+
+impl<const N: usize> Default for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Debug for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("InlineOrHeap")
+            .field("string", &self.as_str())
+            .field("inline", &self.is_inline())
+            .finish()
+    }
+}
+
+impl<const N: usize> Display for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> Deref for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> DerefMut for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsMut<str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> Borrow<str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> BorrowMut<str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> PartialEq for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq(other.as_str())
+    }
+}
+
+impl<const N: usize> Eq for InlineOrHeap<N> where ArrayString<N>: ValidCapacity {}
+
+impl<const N: usize> PartialEq<str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(other)
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(*other)
+    }
+}
+
+impl<const N: usize> Ord for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> PartialOrd for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Hash for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher);
+    }
+}
+
+impl<const N: usize> From<&str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn from(s: &str) -> Self {
+        Self::from_str_truncate(s)
+    }
+}
+
+impl<const N: usize> From<ArrayString<N>> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn from(s: ArrayString<N>) -> Self {
+        Self(Repr::Inline(s))
+    }
+}
+
+/// Keeps the allocation, use [`shrink_inline`] to move it inline
+///
+/// [`shrink_inline`]: ./struct.InlineOrHeap.html#method.shrink_inline
+impl<const N: usize> From<String> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn from(s: String) -> Self {
+        Self(Repr::Heap(s))
+    }
+}
+
+impl<const N: usize> From<InlineOrHeap<N>> for String
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn from(s: InlineOrHeap<N>) -> Self {
+        s.into_string()
+    }
+}
+
+impl<const N: usize> FromStr for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    type Err = OutOfBounds;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const N: usize> Add<&str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &str) -> Self::Output {
+        self.push_str(other);
+        self
+    }
+}
+
+impl<const N: usize> Write for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn write_str(&mut self, slice: &str) -> fmt::Result {
+        self.push_str(slice);
+        Ok(())
+    }
+}
+
+impl<const N: usize> FromIterator<char> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        Self::from_chars_truncate(iter)
+    }
+}
+
+impl<'a, const N: usize> FromIterator<&'a str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        Self::from_iterator_truncate(iter)
+    }
+}
+
+impl<const N: usize> Extend<char> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|ch| self.push(ch));
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a char> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a str> for InlineOrHeap<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|s| self.push_str(s));
+    }
+}
@@ -6,7 +6,8 @@ mod diesel_impl {
     #[cfg(all(feature = "no-panic", not(debug_assertions)))]
     use no_panic::no_panic;
 
-    pub use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    pub use crate::policy::{Lossy, Reject};
+    pub use crate::{arraystring::sealed::ValidCapacity, prelude::*, validated::Validator};
 
     #[cfg(feature = "std")]
     pub use std::io::Write;
@@ -21,7 +22,10 @@ mod diesel_impl {
         sql_types::*,
     };
 
-    impl<const N: usize, ST, DB> FromSql<ST, DB> for ArrayString<N>
+    /// Text that doesn't fit is handled by the policy, like [`push_str`]
+    ///
+    /// [`push_str`]: ./struct.ArrayString.html#method.push_str
+    impl<const N: usize, P: Lossy, ST, DB> FromSql<ST, DB> for ArrayString<N, P>
     where
         DB: Backend,
         *const str: FromSql<ST, DB>,
@@ -33,90 +37,1043 @@ mod diesel_impl {
             let ptr = <*const str as FromSql<ST, DB>>::from_sql(bytes)?;
             // Safety: We know that the pointer impl will never return null. We copied diesel's implementation for String
             debug_assert!(!ptr.is_null());
-            Ok(Self::from_str_truncate(unsafe { &*ptr }))
+            let mut out = Self::new();
+            let _ = out.push_str(unsafe { &*ptr });
+            Ok(out)
         }
     }
-    impl<const N: usize, DB> ToSql<Text, DB> for ArrayString<N>
+
+    /// Text that doesn't fit is an error
+    #[cfg(feature = "std")]
+    impl<const N: usize, ST, DB> FromSql<ST, DB> for ArrayString<N, Reject>
+    where
+        DB: Backend,
+        *const str: FromSql<ST, DB>,
+        Self: ValidCapacity,
+    {
+        #[inline]
+        fn from_sql(bytes: RawValue<'_, DB>) -> deserialize::Result<Self> {
+            let ptr = <*const str as FromSql<ST, DB>>::from_sql(bytes)?;
+            // Safety: We know that the pointer impl will never return null. We copied diesel's implementation for String
+            debug_assert!(!ptr.is_null());
+            Ok(Self::try_from_str(unsafe { &*ptr })?)
+        }
+    }
+
+    impl<const N: usize, P, DB> ToSql<Text, DB> for ArrayString<N, P>
+    where
+        DB: Backend,
+        str: ToSql<Text, DB>,
+        Self: ValidCapacity,
+    {
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.as_str().to_sql(out)
+        }
+    }
+
+    /// Text that doesn't fit or doesn't follow the rule is an error
+    #[cfg(feature = "std")]
+    impl<const N: usize, V, ST, DB> FromSql<ST, DB> for Validated<N, V>
+    where
+        DB: Backend,
+        *const str: FromSql<ST, DB>,
+        V: Validator,
+        ArrayString<N>: ValidCapacity,
+    {
+        #[inline]
+        fn from_sql(bytes: RawValue<'_, DB>) -> deserialize::Result<Self> {
+            let ptr = <*const str as FromSql<ST, DB>>::from_sql(bytes)?;
+            // Safety: We know that the pointer impl will never return null. We copied diesel's implementation for String
+            debug_assert!(!ptr.is_null());
+            Ok(Self::try_from_str(unsafe { &*ptr })?)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<const N: usize, V, DB> ToSql<Text, DB> for Validated<N, V>
+    where
+        DB: Backend,
+        str: ToSql<Text, DB>,
+        ArrayString<N>: ValidCapacity,
+    {
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.as_str().to_sql(out)
+        }
+    }
+
+    /// Text with more chars or bytes than the limits is an error
+    #[cfg(feature = "std")]
+    impl<const C: usize, const N: usize, ST, DB> FromSql<ST, DB> for CharBounded<C, N>
+    where
+        DB: Backend,
+        *const str: FromSql<ST, DB>,
+        ArrayString<N>: ValidCapacity,
+    {
+        #[inline]
+        fn from_sql(bytes: RawValue<'_, DB>) -> deserialize::Result<Self> {
+            let ptr = <*const str as FromSql<ST, DB>>::from_sql(bytes)?;
+            // Safety: We know that the pointer impl will never return null. We copied diesel's implementation for String
+            debug_assert!(!ptr.is_null());
+            Ok(Self::try_from_str(unsafe { &*ptr })?)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<const C: usize, const N: usize, DB> ToSql<Text, DB> for CharBounded<C, N>
+    where
+        DB: Backend,
+        str: ToSql<Text, DB>,
+        ArrayString<N>: ValidCapacity,
+    {
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.as_str().to_sql(out)
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "serde-traits")))]
+#[cfg(feature = "serde-traits")]
+mod serde_impl {
+    pub use crate::policy::{Lossy, Reject};
+    pub use crate::{arraystring::sealed::ValidCapacity, prelude::*, validated::Validator};
+    #[cfg(all(feature = "no-panic", not(debug_assertions)))]
+    use no_panic::no_panic;
+    pub use serde::{de::Deserializer, ser::Serializer, Deserialize, Serialize};
+
+    impl<const N: usize, P> Serialize for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            Serialize::serialize(self.as_str(), ser)
+        }
+    }
+
+    /// Text that doesn't fit is handled by the policy, like [`push_str`]
+    ///
+    /// [`push_str`]: ./struct.ArrayString.html#method.push_str
+    impl<'a, const N: usize, P: Lossy> Deserialize<'a> for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn deserialize<D: Deserializer<'a>>(des: D) -> Result<Self, D::Error> {
+            let string = <&str>::deserialize(des)?;
+            let mut out = Self::new();
+            let _ = out.push_str(string);
+            Ok(out)
+        }
+    }
+
+    /// Text that doesn't fit is an error
+    impl<'a, const N: usize> Deserialize<'a> for ArrayString<N, Reject>
+    where
+        Self: ValidCapacity,
+    {
+        #[inline]
+        fn deserialize<D: Deserializer<'a>>(des: D) -> Result<Self, D::Error> {
+            let string = <&str>::deserialize(des)?;
+            Self::try_from_str(string).map_err(serde::de::Error::custom)
+        }
+    }
+
+    impl<const N: usize, V> Serialize for Validated<N, V>
+    where
+        ArrayString<N>: ValidCapacity,
+    {
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            Serialize::serialize(self.as_str(), ser)
+        }
+    }
+
+    /// Text that doesn't fit or doesn't follow the rule is an error
+    impl<'a, const N: usize, V: Validator> Deserialize<'a> for Validated<N, V>
+    where
+        ArrayString<N>: ValidCapacity,
+    {
+        #[inline]
+        fn deserialize<D: Deserializer<'a>>(des: D) -> Result<Self, D::Error> {
+            let string = <&str>::deserialize(des)?;
+            Self::try_from_str(string).map_err(serde::de::Error::custom)
+        }
+    }
+
+    impl<const C: usize, const N: usize> Serialize for CharBounded<C, N>
+    where
+        ArrayString<N>: ValidCapacity,
+    {
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+            Serialize::serialize(self.as_str(), ser)
+        }
+    }
+
+    /// Text with more chars or bytes than the limits is an error
+    impl<'a, const C: usize, const N: usize> Deserialize<'a> for CharBounded<C, N>
+    where
+        ArrayString<N>: ValidCapacity,
+    {
+        #[inline]
+        fn deserialize<D: Deserializer<'a>>(des: D) -> Result<Self, D::Error> {
+            let string = <&str>::deserialize(des)?;
+            Self::try_from_str(string).map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "arbitrary")))]
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl {
+    use crate::utils::{char_from_class, generate};
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    impl<'a, const N: usize, P> Arbitrary<'a> for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let exact = u.ratio(1u8, 4)?;
+            let chars = u
+                .arbitrary_iter::<(u8, u32)>()?
+                .map_while(Result::ok)
+                .map(|(class, raw)| char_from_class(class, raw));
+            Ok(generate(exact, chars))
+        }
+    }
+
+    impl<'a, const N: usize, P> Arbitrary<'a> for Drain<N, P>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        #[inline]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self(Arbitrary::arbitrary(u)?))
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "proptest")))]
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use crate::utils::{char_from_class, generate};
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    use proptest::prelude::*;
+    use proptest::{bool::weighted, collection::vec};
+
+    impl<const N: usize, P> Arbitrary for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        #[inline]
+        fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+            (weighted(0.25), vec(any::<(u8, u32)>(), 0..=N))
+                .prop_map(|(exact, chars)| {
+                    let chars = chars.into_iter();
+                    generate(exact, chars.map(|(class, raw)| char_from_class(class, raw)))
+                })
+                .boxed()
+        }
+    }
+
+    impl<const N: usize, P: 'static> Arbitrary for Drain<N, P>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        #[inline]
+        fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+            any::<ArrayString<N, P>>().prop_map(Self).boxed()
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "quickcheck")))]
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl {
+    use crate::utils::{char_from_class, generate};
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    use quickcheck::{Arbitrary, Gen};
+
+    impl<const N: usize, P: 'static> Arbitrary for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        #[inline]
+        fn arbitrary(g: &mut Gen) -> Self {
+            let exact = u8::arbitrary(g) % 4 == 0;
+            let len = usize::arbitrary(g) % (N + 1);
+            let chars = (0..len).map(|_| char_from_class(u8::arbitrary(g), u32::arbitrary(g)));
+            generate(exact, chars)
+        }
+
+        #[inline]
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(
+                self.as_str()
+                    .to_owned()
+                    .shrink()
+                    .map(Self::from_str_truncate),
+            )
+        }
+    }
+
+    impl<const N: usize, P: 'static> Arbitrary for Drain<N, P>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        #[inline]
+        fn arbitrary(g: &mut Gen) -> Self {
+            Self(Arbitrary::arbitrary(g))
+        }
+
+        #[inline]
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.0.shrink().map(Self))
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "schemars")))]
+#[cfg(feature = "schemars")]
+mod schemars_impl {
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+    use std::borrow::Cow;
+
+    impl<const N: usize, P> JsonSchema for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        #[inline]
+        fn inline_schema() -> bool {
+            true
+        }
+
+        #[inline]
+        fn schema_name() -> Cow<'static, str> {
+            format!("ArrayString{N}").into()
+        }
+
+        #[inline]
+        fn schema_id() -> Cow<'static, str> {
+            format!("arraystring::ArrayString<{N}>").into()
+        }
+
+        /// `maxLength` counts chars, the capacity is in bytes, so `x-maxBytes` holds the actual limit
+        #[inline]
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "maxLength": N,
+                "x-maxBytes": N
+            })
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "defmt")))]
+#[cfg(feature = "defmt")]
+mod defmt_impl {
+    use crate::error::{CapacityError, CharLimit, Error, Index, Invalid, OutOfBounds, Utf16, Utf8};
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    use defmt::{Format, Formatter};
+
+    impl<const N: usize, P> Format for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(f, "{=str}", self.as_str());
+        }
+    }
+
+    impl<const N: usize, P> Format for Drain<N, P>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(f, "Drain({})", self.0);
+        }
+    }
+
+    impl Format for Error {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            match self {
+                Error::Utf8 { index, valid_up_to } => defmt::write!(
+                    f,
+                    "Error::Utf8 {{ index: {=usize}, valid_up_to: {=usize} }}",
+                    index,
+                    valid_up_to
+                ),
+                Error::Utf16 => defmt::write!(f, "Error::Utf16"),
+                Error::OutOfBounds { needed, capacity } => defmt::write!(
+                    f,
+                    "Error::OutOfBounds {{ needed: {=usize}, capacity: {=usize} }}",
+                    needed,
+                    capacity
+                ),
+                Error::CharLimit { needed, limit } => defmt::write!(
+                    f,
+                    "Error::CharLimit {{ needed: {=usize}, limit: {=usize} }}",
+                    needed,
+                    limit
+                ),
+                Error::Index { index, len } => defmt::write!(
+                    f,
+                    "Error::Index {{ index: {=usize}, len: {=usize} }}",
+                    index,
+                    len
+                ),
+                Error::Invalid { rule } => {
+                    defmt::write!(f, "Error::Invalid {{ rule: {=str} }}", rule)
+                }
+            }
+        }
+    }
+
+    impl Format for Utf8 {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(
+                f,
+                "Utf8 {{ index: {=usize}, valid_up_to: {=usize} }}",
+                self.index(),
+                self.valid_up_to()
+            );
+        }
+    }
+
+    impl Format for Utf16 {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(f, "Utf16");
+        }
+    }
+
+    impl Format for OutOfBounds {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(
+                f,
+                "OutOfBounds {{ needed: {=usize}, capacity: {=usize} }}",
+                self.needed(),
+                self.capacity()
+            );
+        }
+    }
+
+    impl Format for CharLimit {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(
+                f,
+                "CharLimit {{ needed: {=usize}, limit: {=usize} }}",
+                self.needed(),
+                self.limit()
+            );
+        }
+    }
+
+    impl Format for Index {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(
+                f,
+                "Index {{ index: {=usize}, len: {=usize} }}",
+                self.index(),
+                self.len()
+            );
+        }
+    }
+
+    impl Format for Invalid {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(f, "Invalid {{ rule: {=str} }}", self.rule());
+        }
+    }
+
+    /// Only the cause is formatted, like `Debug`, the element may not implement `Format`
+    impl<T, E: Format> Format for CapacityError<T, E> {
+        #[inline]
+        fn format(&self, f: Formatter<'_>) {
+            defmt::write!(f, "CapacityError({})", self.error);
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "ufmt")))]
+#[cfg(feature = "ufmt")]
+mod ufmt_impl {
+    use crate::{arraystring::sealed::ValidCapacity, error::OutOfBounds, prelude::*};
+    #[cfg(all(feature = "no-panic", not(debug_assertions)))]
+    use no_panic::no_panic;
+    use ufmt::{uDisplay, uWrite, Formatter};
+
+    impl<const N: usize, P> uWrite for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        type Error = OutOfBounds;
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn write_str(&mut self, slice: &str) -> Result<(), Self::Error> {
+            self.try_push_str(slice).map_err(|err| err.error())
+        }
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn write_char(&mut self, ch: char) -> Result<(), Self::Error> {
+            self.try_push(ch).map_err(|err| err.error())
+        }
+    }
+
+    impl<const N: usize, P> uDisplay for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str(self.as_str())
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "heapless")))]
+#[cfg(feature = "heapless")]
+mod heapless_impl {
+    use crate::{arraystring::sealed::ValidCapacity, error::OutOfBounds, prelude::*};
+    #[cfg(all(feature = "no-panic", not(debug_assertions)))]
+    use no_panic::no_panic;
+
+    impl<const N: usize, const M: usize, P> TryFrom<heapless::String<M>> for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        type Error = OutOfBounds;
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn try_from(s: heapless::String<M>) -> Result<Self, Self::Error> {
+            Self::try_from_str(s.as_str())
+        }
+    }
+
+    impl<const N: usize, const M: usize, P> TryFrom<ArrayString<N, P>> for heapless::String<M>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        type Error = OutOfBounds;
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn try_from(s: ArrayString<N, P>) -> Result<Self, Self::Error> {
+            let mut out = Self::new();
+            out.push_str(s.as_str())
+                .map_err(|_| OutOfBounds::new(s.len(), M))?;
+            Ok(out)
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "arrayvec")))]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_impl {
+    use crate::{arraystring::sealed::ValidCapacity, error::OutOfBounds, prelude::*};
+    use arrayvec::ArrayString as ArrayVecString;
+    #[cfg(all(feature = "no-panic", not(debug_assertions)))]
+    use no_panic::no_panic;
+
+    impl<const N: usize, const M: usize, P> TryFrom<ArrayVecString<M>> for ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        type Error = OutOfBounds;
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn try_from(s: ArrayVecString<M>) -> Result<Self, Self::Error> {
+            Self::try_from_str(s.as_str())
+        }
+    }
+
+    impl<const N: usize, const M: usize, P> TryFrom<ArrayString<N, P>> for ArrayVecString<M>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        type Error = OutOfBounds;
+
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        fn try_from(s: ArrayString<N, P>) -> Result<Self, Self::Error> {
+            Self::from(s.as_str()).map_err(|_| OutOfBounds::new(s.len(), M))
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "smol_str")))]
+#[cfg(feature = "smol_str")]
+mod smol_str_impl {
+    use crate::{arraystring::sealed::ValidCapacity, error::OutOfBounds, prelude::*};
+    use smol_str::SmolStr;
+
+    impl<const N: usize, P> TryFrom<SmolStr> for ArrayString<N, P>
     where
-        DB: Backend,
-        str: ToSql<Text, DB>,
         Self: ValidCapacity,
     {
+        type Error = OutOfBounds;
+
         #[inline]
-        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
-            self.as_str().to_sql(out)
+        fn try_from(s: SmolStr) -> Result<Self, Self::Error> {
+            Self::try_from_str(s.as_str())
         }
     }
 
-    impl<ST, DB> FromSql<ST, DB> for CacheString
+    /// Strings up to 23 bytes are stored inline, without allocating
+    impl<const N: usize, P> From<ArrayString<N, P>> for SmolStr
     where
-        DB: Backend,
-        *const str: FromSql<ST, DB>,
+        ArrayString<N, P>: ValidCapacity,
     {
         #[inline]
-        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-        fn from_sql(bytes: RawValue<'_, DB>) -> deserialize::Result<Self> {
-            Ok(Self(FromSql::from_sql(bytes)?))
+        fn from(s: ArrayString<N, P>) -> Self {
+            Self::new(s.as_str())
         }
     }
+}
 
-    impl<DB> ToSql<Text, DB> for CacheString
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "compact_str")))]
+#[cfg(feature = "compact_str")]
+mod compact_str_impl {
+    use crate::{arraystring::sealed::ValidCapacity, error::OutOfBounds, prelude::*};
+    use compact_str::CompactString;
+
+    impl<const N: usize, P> TryFrom<CompactString> for ArrayString<N, P>
     where
-        DB: Backend,
-        str: ToSql<Text, DB>,
+        Self: ValidCapacity,
     {
+        type Error = OutOfBounds;
+
         #[inline]
-        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
-            ToSql::<Text, DB>::to_sql(&self.0, out)
+        fn try_from(s: CompactString) -> Result<Self, Self::Error> {
+            Self::try_from_str(s.as_str())
+        }
+    }
+
+    /// Strings up to 24 bytes are stored inline, without allocating
+    impl<const N: usize, P> From<ArrayString<N, P>> for CompactString
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        #[inline]
+        fn from(s: ArrayString<N, P>) -> Self {
+            Self::new(s.as_str())
         }
     }
 }
 
-#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "serde-traits")))]
-#[cfg(feature = "serde-traits")]
-mod serde_impl {
-    pub use crate::{arraystring::sealed::ValidCapacity, prelude::*};
-    #[cfg(all(feature = "no-panic", not(debug_assertions)))]
-    use no_panic::no_panic;
-    pub use serde::{de::Deserializer, ser::Serializer, Deserialize, Serialize};
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "zeroize")))]
+#[cfg(feature = "zeroize")]
+mod zeroize_impl {
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    use zeroize::Zeroize;
 
-    impl<const N: usize> Serialize for ArrayString<N>
+    /// Wipes the whole array, not only the string, and empties it
+    ///
+    /// `ArrayString` is `Copy`, copies made before aren't wiped, use [`SecretArrayString`] to prevent them
+    ///
+    /// [`SecretArrayString`]: ./struct.SecretArrayString.html
+    impl<const N: usize, P> Zeroize for ArrayString<N, P>
     where
         Self: ValidCapacity,
     {
         #[inline]
-        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-        fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
-            Serialize::serialize(self.as_str(), ser)
+        fn zeroize(&mut self) {
+            self.array.zeroize();
+            self.size.zeroize();
         }
     }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "subtle")))]
+#[cfg(feature = "subtle")]
+mod subtle_impl {
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    use subtle::{Choice, ConstantTimeEq};
 
-    impl<'a, const N: usize> Deserialize<'a> for ArrayString<N>
+    /// Compares the full array and the length, bytes after the string are always zeroed
+    impl<const N: usize, P> ConstantTimeEq for ArrayString<N, P>
     where
         Self: ValidCapacity,
     {
         #[inline]
-        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-        fn deserialize<D: Deserializer<'a>>(des: D) -> Result<Self, D::Error> {
-            <&str>::deserialize(des).map(Self::from_str_truncate)
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.array.ct_eq(&other.array) & self.size.ct_eq(&other.size)
         }
     }
 
-    impl Serialize for CacheString {
+    #[cfg(feature = "zeroize")]
+    impl<const N: usize> ConstantTimeEq for SecretArrayString<N>
+    where
+        ArrayString<N>: ValidCapacity,
+    {
         #[inline]
-        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-        fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
-            self.0.serialize(ser)
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0)
+        }
+    }
+
+    impl<const N: usize, P> ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        /// Compares with external string slice in constant time, for tokens and digests
+        ///
+        /// Time depends on the capacity, not on the contents, each of the `N` bytes is compared with the slice's (or zero, past its end)
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let token = ArrayString::<64>::try_from_str("4f2a9c")?;
+        /// assert!(bool::from(token.ct_eq_str("4f2a9c")));
+        /// assert!(!bool::from(token.ct_eq_str("4f2a9d")));
+        /// assert!(!bool::from(token.ct_eq_str("4f2a9c\0")));
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn ct_eq_str(&self, other: &str) -> Choice {
+            let other = other.as_bytes();
+            let bytes = self
+                .array
+                .iter()
+                .enumerate()
+                .fold(Choice::from(1), |eq, (idx, byte)| {
+                    eq & byte.ct_eq(other.get(idx).unwrap_or(&0))
+                });
+            bytes & (self.len() as u64).ct_eq(&(other.len() as u64))
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl<const N: usize> SecretArrayString<N>
+    where
+        ArrayString<N>: ValidCapacity,
+    {
+        /// Compares with external string slice in constant time, without exposing the secret
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let token = SecretArrayString::<64>::try_from_str("4f2a9c")?;
+        /// assert!(bool::from(token.ct_eq_str("4f2a9c")));
+        /// assert!(!bool::from(token.ct_eq_str("4f2a9d")));
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn ct_eq_str(&self, other: &str) -> Choice {
+            self.0.ct_eq_str(other)
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "unicode-segmentation")))]
+#[cfg(feature = "unicode-segmentation")]
+mod unicode_segmentation_impl {
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    #[cfg(feature = "logs")]
+    use log::trace;
+    use unicode_segmentation::UnicodeSegmentation;
+
+    /// Longest prefix of whole extended grapheme clusters that fits in `size` bytes
+    #[inline]
+    pub(crate) fn truncate_graphemes(slice: &str, size: usize) -> &str {
+        let end = slice
+            .grapheme_indices(true)
+            .map(|(idx, grapheme)| idx + grapheme.len())
+            .take_while(|end| *end <= size)
+            .last()
+            .unwrap_or(0);
+        slice.get(..end).unwrap_or_default()
+    }
+
+    impl<const N: usize, P> ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        /// Creates new `ArrayString` from string slice truncating size if bigger than [`capacity`], without splitting grapheme clusters
+        ///
+        /// Flags, emoji sequences joined by ZWJ and combining accents are kept whole or dropped whole
+        ///
+        /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let family = "👨\u{200D}👩\u{200D}👧";
+        /// let string = ArrayString::<19>::from_str_truncate_graphemes(format!("ab{family}"));
+        /// assert_eq!(string.as_str(), "ab");
+        /// let string = ArrayString::<20>::from_str_truncate_graphemes(format!("{family}ab"));
+        /// assert_eq!(string.as_str(), format!("{family}ab"));
+        /// ```
+        #[inline]
+        pub fn from_str_truncate_graphemes(string: impl AsRef<str>) -> Self {
+            trace!("FromStr truncate graphemes: {}", string.as_ref());
+            let mut s = Self::new();
+            s.push_str_truncate_graphemes(string);
+            s
+        }
+
+        /// Pushes string slice to the end of the `ArrayString` truncating it if total size is bigger than [`capacity`], without splitting its grapheme clusters
+        ///
+        /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let mut s = ArrayString::<8>::try_from_str("abc")?;
+        /// s.push_str_truncate_graphemes("e\u{301}🇧🇷");
+        /// assert_eq!(s.as_str(), "abce\u{301}");
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn push_str_truncate_graphemes(&mut self, string: impl AsRef<str>) {
+            trace!("Push str truncate graphemes: {}", string.as_ref());
+            let size = Self::capacity().saturating_sub(self.len());
+            let _ = self.try_push_str(truncate_graphemes(string.as_ref(), size));
+        }
+
+        /// Truncates `ArrayString` to at most `max_bytes`, without splitting grapheme clusters
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let mut s = ArrayString::<20>::try_from_str("ab🇧🇷")?;
+        /// s.truncate_graphemes(9);
+        /// assert_eq!(s.as_str(), "ab");
+        /// s.truncate_graphemes(20);
+        /// assert_eq!(s.as_str(), "ab");
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn truncate_graphemes(&mut self, max_bytes: usize) {
+            trace!("Truncate graphemes: {max_bytes}");
+            let size = truncate_graphemes(self.as_str(), max_bytes).len();
+            let _ = self.truncate(size);
+        }
+    }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "unicode-width")))]
+#[cfg(feature = "unicode-width")]
+mod unicode_width_impl {
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    #[cfg(feature = "logs")]
+    use log::trace;
+    use unicode_width::UnicodeWidthChar;
+
+    /// Longest prefix that is at most `cols` columns wide and `bytes` long
+    ///
+    /// Stops at the first char that doesn't fit, so a wide char is never split in half and the zero width marks after it are dropped with it
+    #[inline]
+    fn prefix(slice: &str, cols: usize, bytes: usize) -> &str {
+        let mut width = 0;
+        let end = slice
+            .char_indices()
+            .take_while(|(idx, ch)| {
+                width += ch.width().unwrap_or(0);
+                width <= cols && idx + ch.len_utf8() <= bytes
+            })
+            .last()
+            .map_or(0, |(idx, ch)| idx + ch.len_utf8());
+        slice.get(..end).unwrap_or_default()
+    }
+
+    /// Prefix of `slice` and the ellipsis to append so both fit in `cols` columns and `bytes` bytes
+    ///
+    /// The ellipsis is only appended if `slice` was cut, and dropped if it doesn't fit by itself
+    #[inline]
+    pub(crate) fn fit_width<'a, 'b>(
+        slice: &'a str,
+        cols: usize,
+        bytes: usize,
+        ellipsis: &'b str,
+    ) -> (&'a str, &'b str) {
+        let whole = prefix(slice, cols, bytes);
+        if whole.len() == slice.len() {
+            return (whole, "");
+        }
+
+        let ellipsis_cols = ellipsis.chars().filter_map(|ch| ch.width()).sum::<usize>();
+        match (
+            cols.checked_sub(ellipsis_cols),
+            bytes.checked_sub(ellipsis.len()),
+        ) {
+            (Some(cols), Some(bytes)) => (prefix(slice, cols, bytes), ellipsis),
+            _ => (whole, ""),
+        }
+    }
+
+    impl<const N: usize, P> ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        /// Creates new `ArrayString` from string slice that fits in `max_cols` terminal columns and in [`capacity`] bytes, ending with `ellipsis` if it had to be cut
+        ///
+        /// Widths come from `unicode-width`, so CJK and other wide chars take two columns. A wide char that doesn't fit is dropped whole, so the result may be one column narrower than `max_cols`. If `ellipsis` alone doesn't fit, the text is cut without it
+        ///
+        /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let string = ArrayString::<20>::from_str_fit_width("日本語のテキスト", 7, "…");
+        /// assert_eq!(string.as_str(), "日本語…");
+        /// let string = ArrayString::<20>::from_str_fit_width("日本語のテキスト", 6, "…");
+        /// assert_eq!(string.as_str(), "日本…");
+        ///
+        /// let string = ArrayString::<8>::from_str_fit_width("notification", 80, "…");
+        /// assert_eq!(string.as_str(), "notif…");
+        /// let string = ArrayString::<20>::from_str_fit_width("short", 80, "…");
+        /// assert_eq!(string.as_str(), "short");
+        /// ```
+        #[inline]
+        pub fn from_str_fit_width(
+            string: impl AsRef<str>,
+            max_cols: usize,
+            ellipsis: &str,
+        ) -> Self {
+            trace!("FromStr fit width {max_cols}: {}", string.as_ref());
+            let (text, ellipsis) = fit_width(string.as_ref(), max_cols, Self::capacity(), ellipsis);
+            let mut s = Self::new();
+            let _ = s.try_push_str(text);
+            let _ = s.try_push_str(ellipsis);
+            s
+        }
+
+        /// Truncates `ArrayString` to fit in `max_cols` terminal columns, ending with `ellipsis` if it had to be cut
+        ///
+        /// Follows the same rules as [`from_str_fit_width`]
+        ///
+        /// [`from_str_fit_width`]: ./struct.ArrayString.html#method.from_str_fit_width
+        ///
+        /// ```rust
+        /// # use arraystring::{Error, prelude::*};
+        /// # fn main() -> Result<(), Error> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let mut s = ArrayString::<20>::try_from_str("表示幅")?;
+        /// s.truncate_to_width(6, "…");
+        /// assert_eq!(s.as_str(), "表示幅");
+        /// s.truncate_to_width(5, "…");
+        /// assert_eq!(s.as_str(), "表示…");
+        /// s.truncate_to_width(0, "…");
+        /// assert_eq!(s.as_str(), "");
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn truncate_to_width(&mut self, max_cols: usize, ellipsis: &str) {
+            trace!("Truncate to width: {max_cols}");
+            let (text, ellipsis) = fit_width(self.as_str(), max_cols, Self::capacity(), ellipsis);
+            let _ = self.truncate(text.len());
+            let _ = self.try_push_str(ellipsis);
         }
     }
+}
+
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "ryu")))]
+#[cfg(feature = "ryu")]
+mod ryu_impl {
+    use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+    #[cfg(feature = "logs")]
+    use log::trace;
+    #[cfg(all(feature = "no-panic", not(debug_assertions)))]
+    use no_panic::no_panic;
+
+    impl<const N: usize, P> ArrayString<N, P>
+    where
+        Self: ValidCapacity,
+    {
+        /// Creates new `ArrayString` from the shortest representation that parses back to the same `f64`, if it fits in [`capacity`], otherwise returns an error.
+        ///
+        /// Digits come from `ryu`, without `core::fmt`. Whole numbers keep a `.0`, very big and very small ones use exponents like `1e30`, and non-finite values become `NaN`, `inf` and `-inf`
+        ///
+        /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # fn main() -> Result<(), OutOfBounds> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// assert_eq!(ArrayString::<8>::try_from_f64(0.1 + 0.2), Err(OutOfBounds::new(19, 8)));
+        /// assert_eq!(ArrayString::<8>::try_from_f64(12.5)?, "12.5");
+        /// assert_eq!(ArrayString::<8>::try_from_f64(3.0)?, "3.0");
+        /// assert_eq!(ArrayString::<8>::try_from_f64(1e30)?, "1e30");
+        /// assert_eq!(ArrayString::<8>::try_from_f64(f64::NEG_INFINITY)?, "-inf");
+        /// # Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+        pub fn try_from_f64(n: f64) -> Result<Self, OutOfBounds> {
+            let mut s = Self::new();
+            s.try_push_f64(n)?;
+            Ok(s)
+        }
 
-    impl<'a> Deserialize<'a> for CacheString {
+        /// Pushes the shortest representation that parses back to the same `f64` to the end of the `ArrayString` if it fits in [`capacity`], otherwise returns an error and leaves it untouched.
+        ///
+        /// Follows the same rules as [`try_from_f64`]
+        ///
+        /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+        /// [`try_from_f64`]: ./struct.ArrayString.html#method.try_from_f64
+        ///
+        /// ```rust
+        /// # use arraystring::prelude::*;
+        /// # fn main() -> Result<(), OutOfBounds> {
+        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+        /// let mut s = ArrayString::<16>::try_from_str("price=")?;
+        /// s.try_push_f64(19.99)?;
+        /// assert_eq!(s, "price=19.99");
+        /// assert_eq!(s.try_push_f64(-0.125), Err(OutOfBounds::new(17, 16)));
+        /// # Ok(())
+        /// # }
+        /// ```
         #[inline]
         #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-        fn deserialize<D: Deserializer<'a>>(des: D) -> Result<Self, D::Error> {
-            Ok(CacheString(Deserialize::deserialize(des)?))
+        pub fn try_push_f64(&mut self, n: f64) -> Result<(), OutOfBounds> {
+            trace!("Push f64: {n}");
+            let mut buffer = ryu::Buffer::new();
+            self.try_push_str(buffer.format(n))
+                .map_err(|err| err.error())
         }
     }
 }
@@ -131,7 +1088,17 @@ mod tests {
     use super::serde_impl::*;
     #[cfg(any(
         feature = "serde-traits",
-        all(feature = "diesel-traits", feature = "std")
+        all(feature = "diesel-traits", feature = "std"),
+        feature = "arbitrary",
+        feature = "proptest",
+        feature = "quickcheck",
+        feature = "schemars",
+        feature = "defmt",
+        feature = "ufmt",
+        feature = "heapless",
+        feature = "arrayvec",
+        feature = "smol_str",
+        feature = "compact_str"
     ))]
     use crate::ArrayString;
 
@@ -170,6 +1137,47 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "serde-traits")]
+    fn serde_validated_json() {
+        use crate::validated::{Alphanumeric, Validated};
+
+        type Username = Validated<8, Alphanumeric>;
+        let string = serde_json::to_string(&Username::try_from_str("abc123").unwrap()).unwrap();
+        assert_eq!(string, "\"abc123\"");
+        let s: Username = serde_json::from_str(&string).unwrap();
+        assert_eq!(s.as_str(), "abc123");
+
+        let err = serde_json::from_str::<Username>("\"abc 123\"").unwrap_err();
+        assert!(err.to_string().contains("alphanumeric"));
+        assert!(serde_json::from_str::<Username>("\"abc123456\"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde-traits")]
+    fn serde_bounded_json() {
+        use crate::validated::BoundedString;
+
+        type Name = BoundedString<2, 4>;
+        let s: Name = serde_json::from_str("\"abc\"").unwrap();
+        assert_eq!(s.as_str(), "abc");
+        let err = serde_json::from_str::<Name>("\"a\"").unwrap_err();
+        assert!(err.to_string().contains("min-length"));
+        assert!(serde_json::from_str::<Name>("\"abcde\"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde-traits")]
+    fn serde_char_bounded_json() {
+        use crate::CharBounded;
+
+        type Name = CharBounded<3, 12>;
+        let s: Name = serde_json::from_str("\"日本語\"").unwrap();
+        assert_eq!(s.chars_len(), 3);
+        assert_eq!(serde_json::to_string(&s).unwrap(), "\"日本語\"");
+        assert!(serde_json::from_str::<Name>("\"abcd\"").is_err());
+    }
+
     #[test]
     #[cfg(feature = "serde-traits")]
     fn serde_json() {
@@ -179,6 +1187,251 @@ mod tests {
         assert_eq!(s, ArrayString::<8>::try_from_str("abcdefg").unwrap());
     }
 
+    #[test]
+    #[cfg(feature = "serde-traits")]
+    fn serde_policy_json() {
+        use crate::policy::{Saturate, TruncateWithMarker};
+
+        let s: ArrayString<5> = serde_json::from_str("\"abcdefg\"").unwrap();
+        assert_eq!(s.as_str(), "abcde");
+        let s: ArrayString<5, TruncateWithMarker> = serde_json::from_str("\"abcdefg\"").unwrap();
+        assert_eq!(s.as_str(), "ab…");
+        let s: ArrayString<5, Saturate> = serde_json::from_str("\"abcdefg\"").unwrap();
+        assert_eq!(s.as_str(), "");
+
+        let s: ArrayString<5, Reject> = serde_json::from_str("\"abcde\"").unwrap();
+        assert_eq!(serde_json::to_string(&s).unwrap(), "\"abcde\"");
+        let err = serde_json::from_str::<ArrayString<5, Reject>>("\"abcdef\"").unwrap_err();
+        assert!(err.to_string().contains("needed 6 bytes but capacity is 5"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "arbitrary", not(feature = "no-panic")))]
+    fn arbitrary_within_capacity() {
+        use crate::CacheString;
+        use arbitrary::{Arbitrary, Unstructured};
+        let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let s = ArrayString::<5>::arbitrary(&mut u).unwrap();
+            assert!(s.len() <= 5);
+            assert!(core::str::from_utf8(s.as_bytes()).is_ok());
+            let _ = CacheString::arbitrary(&mut u).unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "quickcheck", not(feature = "no-panic")))]
+    fn quickcheck_within_capacity() {
+        use crate::CacheString;
+        use quickcheck::Arbitrary;
+
+        fn prop(s: ArrayString<5>, c: CacheString) -> bool {
+            s.len() <= 5 && c.len() <= 63 && s.shrink().all(|shrunk| shrunk.len() <= s.len())
+        }
+        quickcheck::quickcheck(prop as fn(_, _) -> bool);
+    }
+
+    #[cfg(all(feature = "proptest", not(feature = "no-panic")))]
+    use crate::{CacheString, Drain};
+
+    #[cfg(all(feature = "proptest", not(feature = "no-panic")))]
+    proptest::proptest! {
+        #[test]
+        fn proptest_within_capacity(s: ArrayString<5>, c: CacheString, d: Drain<5>) {
+            proptest::prop_assert!(s.len() <= 5);
+            proptest::prop_assert!(c.len() <= 63);
+            proptest::prop_assert!(d.as_str().len() <= 5);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "schemars")]
+    fn schemars_max_length() {
+        use crate::CacheString;
+
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct User {
+            name: ArrayString<20>,
+            cache: CacheString,
+        }
+
+        let schema = schemars::schema_for!(User);
+        let properties = &schema.as_value()["properties"];
+        assert_eq!(
+            properties["name"],
+            serde_json::json!({"type": "string", "maxLength": 20, "x-maxBytes": 20})
+        );
+        assert_eq!(
+            properties["cache"],
+            serde_json::json!({"type": "string", "maxLength": 63, "x-maxBytes": 63})
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "defmt")]
+    fn defmt_format() {
+        use crate::error::{
+            CapacityError, CharLimit, Error, Index, Invalid, OutOfBounds, Utf16, Utf8,
+        };
+        use crate::{policy::Reject, CacheString};
+        use defmt::Format;
+
+        // Formatting needs a global logger, so only the impls are checked
+        fn format<T: Format>(_: &T) {}
+
+        let mut s = ArrayString::<8>::try_from_str("abcd").unwrap();
+        format(&s);
+        format(&s.with_policy::<Reject>());
+        format(&CacheString::try_from_str("ab").unwrap());
+        format(&s.drain(..2).unwrap());
+        format(&Error::from(OutOfBounds::new(9, 8)));
+        format(&Utf8::new(1, 0));
+        format(&Utf16);
+        format(&OutOfBounds::new(9, 8));
+        format(&CharLimit::new(6, 5));
+        format(&Index::new(9, 8));
+        format(&Invalid::new("alphanumeric"));
+        format(&CapacityError::new(
+            String::from("cd"),
+            OutOfBounds::new(10, 8),
+        ));
+        format(&CapacityError::new('c', Error::from(Index::new(9, 8))));
+    }
+
+    #[test]
+    #[cfg(feature = "ufmt")]
+    fn ufmt_write() {
+        use crate::{error::OutOfBounds, CacheString};
+        use ufmt::uwrite;
+
+        let mut s = ArrayString::<8>::try_from_str("ab").unwrap();
+        uwrite!(
+            s,
+            "{}-{}",
+            12u8,
+            ArrayString::<2>::try_from_str("cd").unwrap()
+        )
+        .unwrap();
+        assert_eq!(s.as_str(), "ab12-cd");
+        assert_eq!(uwrite!(s, "{}", "too long"), Err(OutOfBounds::new(15, 8)));
+        assert_eq!(s.as_str(), "ab12-cd");
+        assert_eq!(uwrite!(s, "{}", '🤔'), Err(OutOfBounds::new(11, 8)));
+        uwrite!(s, "{}", 'e').unwrap();
+        assert_eq!(s.as_str(), "ab12-cde");
+
+        let mut cache = CacheString::new();
+        uwrite!(cache, "{}", s).unwrap();
+        let mut out = ArrayString::<8>::new();
+        uwrite!(out, "{}", cache).unwrap();
+        assert_eq!(out.as_str(), "ab12-cde");
+    }
+
+    #[test]
+    #[cfg(feature = "heapless")]
+    fn heapless_round_trip() {
+        let s = ArrayString::<8>::try_from_str("🤔abcd").unwrap();
+        let h = heapless::String::<8>::try_from(s).unwrap();
+        assert_eq!(h.as_str(), "🤔abcd");
+        assert_eq!(ArrayString::<8>::try_from(h.clone()), Ok(s));
+        assert_eq!(
+            ArrayString::<20>::try_from(h.clone()).unwrap().as_str(),
+            "🤔abcd"
+        );
+        assert!(ArrayString::<7>::try_from(h).is_err());
+        assert!(heapless::String::<7>::try_from(s).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "arrayvec")]
+    fn arrayvec_round_trip() {
+        let s = ArrayString::<8>::try_from_str("🤔abcd").unwrap();
+        let a = arrayvec::ArrayString::<8>::try_from(s).unwrap();
+        assert_eq!(a.as_str(), "🤔abcd");
+        assert_eq!(ArrayString::<8>::try_from(a), Ok(s));
+        assert_eq!(ArrayString::<20>::try_from(a).unwrap().as_str(), "🤔abcd");
+        assert!(ArrayString::<7>::try_from(a).is_err());
+        assert!(arrayvec::ArrayString::<7>::try_from(s).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroize_wipes_buffer() {
+        use crate::CacheString;
+        use zeroize::Zeroize;
+
+        let mut s = CacheString::try_from_str("hunter2").unwrap();
+        s.zeroize();
+        assert!(s.is_empty());
+        assert!(s.0.array.iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn secret_wipes_on_shrink_and_drop() {
+        use crate::SecretArrayString;
+        use core::mem::ManuallyDrop;
+
+        let mut secret = SecretArrayString::<16>::try_from_str("hunter2🤔").unwrap();
+        assert_eq!(
+            format!("{secret:?} {secret}"),
+            "SecretArrayString([REDACTED]) [REDACTED]"
+        );
+        assert_eq!(secret.pop(), Some('🤔'));
+        assert_eq!(secret.remove(0), Ok('h'));
+        secret.truncate(3).unwrap();
+        assert_eq!(secret.expose_secret(), "unt");
+        assert!(secret.0.array[3..].iter().all(|b| *b == 0));
+
+        let mut secret = ManuallyDrop::new(secret);
+        // SAFETY: it's not used after drop, only its plain bytes are read
+        unsafe { ManuallyDrop::drop(&mut secret) };
+        assert_eq!(secret.0.size, 0);
+        assert!(secret.0.array.iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    #[cfg(feature = "subtle")]
+    fn subtle_ct_eq() {
+        use crate::CacheString;
+        use subtle::ConstantTimeEq;
+
+        let token = |s| CacheString::try_from_str(s).unwrap();
+        assert!(bool::from(token("abc").ct_eq(&token("abc"))));
+        assert!(!bool::from(token("abc").ct_eq(&token("abd"))));
+        assert!(!bool::from(token("abc").ct_eq(&token("abc\0"))));
+        assert!(!bool::from(token("").ct_eq(&token("\0"))));
+        assert!(bool::from(token("").ct_eq_str("")));
+        assert!(!bool::from(token("").ct_eq_str("\0")));
+        assert!(!bool::from(token("abc").ct_eq_str("ab")));
+        let long = "a".repeat(64);
+        assert!(!bool::from(token(&long[..63]).ct_eq_str(&long)));
+        assert!(bool::from(token(&long[..63]).ct_eq_str(&long[..63])));
+    }
+
+    #[test]
+    #[cfg(feature = "smol_str")]
+    fn smol_str_round_trip() {
+        let s = ArrayString::<30>::try_from_str("🤔abcd").unwrap();
+        let smol = smol_str::SmolStr::from(s);
+        assert_eq!(smol.as_str(), "🤔abcd");
+        assert!(!smol.is_heap_allocated());
+        assert_eq!(ArrayString::<30>::try_from(smol.clone()), Ok(s));
+        assert!(ArrayString::<7>::try_from(smol).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "compact_str")]
+    fn compact_str_round_trip() {
+        let s = ArrayString::<30>::try_from_str("🤔abcd").unwrap();
+        let compact = compact_str::CompactString::from(s);
+        assert_eq!(compact.as_str(), "🤔abcd");
+        assert!(!compact.is_heap_allocated());
+        assert_eq!(ArrayString::<30>::try_from(compact.clone()), Ok(s));
+        assert!(ArrayString::<7>::try_from(compact).is_err());
+    }
+
     #[cfg(all(feature = "diesel-traits", feature = "std"))]
     use diesel::{dsl, mysql, pg, prelude::*};
 
@@ -302,4 +1555,185 @@ mod tests {
         let queried: Derive2Diesel = derives::table.first(&mut conn).unwrap();
         assert_eq!(queried.name.as_str(), "Name1");
     }
+
+    #[test]
+    #[cfg(all(feature = "diesel-traits", feature = "std", not(miri)))]
+    fn diesel_validated_query_sqlite() {
+        use crate::validated::{Alphanumeric, Validated};
+
+        let mut conn = diesel::sqlite::SqliteConnection::establish(":memory:").unwrap();
+        let _ = diesel::sql_query("CREATE TABLE derives (id INTEGER, name VARCHAR(32));")
+            .execute(&mut conn)
+            .unwrap();
+        let name = Validated::<32, Alphanumeric>::try_from_str("Name1").unwrap();
+        let _ = diesel::insert_into(derives::table)
+            .values((derives::id.eq(0), derives::name.eq(name)))
+            .execute(&mut conn)
+            .unwrap();
+
+        let queried: Validated<32, Alphanumeric> = derives::table
+            .select(derives::name)
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(queried, name);
+
+        let _ = diesel::sql_query("UPDATE derives SET name = 'Name 1';")
+            .execute(&mut conn)
+            .unwrap();
+        let err = derives::table
+            .select(derives::name)
+            .first::<Validated<32, Alphanumeric>>(&mut conn)
+            .unwrap_err();
+        assert!(err.to_string().contains("alphanumeric"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "diesel-traits", feature = "std", not(miri)))]
+    fn diesel_policy_query_sqlite() {
+        let mut conn = diesel::sqlite::SqliteConnection::establish(":memory:").unwrap();
+        let _ = diesel::sql_query("CREATE TABLE derives (id INTEGER, name VARCHAR(32));")
+            .execute(&mut conn)
+            .unwrap();
+        let _ = diesel::sql_query("INSERT INTO derives VALUES (0, 'Name123');")
+            .execute(&mut conn)
+            .unwrap();
+
+        let queried: ArrayString<8, Reject> = derives::table
+            .select(derives::name)
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(queried.as_str(), "Name123");
+        let queried: ArrayString<4> = derives::table
+            .select(derives::name)
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(queried.as_str(), "Name");
+        let err = derives::table
+            .select(derives::name)
+            .first::<ArrayString<4, Reject>>(&mut conn)
+            .unwrap_err();
+        assert!(err.to_string().contains("needed 7 bytes but capacity is 4"));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn truncate_graphemes() {
+        use crate::{prelude::*, CacheString};
+
+        // Man, woman and girl joined by ZWJ: 18 bytes, one grapheme
+        let family = "👨\u{200D}👩\u{200D}👧";
+        // Hangul syllable as conjoining jamo: 9 bytes, one grapheme
+        let hangul = "\u{1112}\u{1161}\u{11AB}";
+
+        assert_eq!(ArrayString::<17>::from_str_truncate_graphemes(family), "");
+        assert_eq!(
+            ArrayString::<17>::from_str_truncate(family),
+            "👨\u{200D}👩\u{200D}"
+        );
+        assert_eq!(
+            ArrayString::<18>::from_str_truncate_graphemes(family),
+            family
+        );
+        assert_eq!(ArrayString::<8>::from_str_truncate_graphemes(hangul), "");
+        assert_eq!(
+            ArrayString::<8>::from_str_truncate(hangul),
+            "\u{1112}\u{1161}"
+        );
+
+        let mut s = ArrayString::<20>::try_from_str(hangul).unwrap();
+        s.push_str_truncate_graphemes(format!("e\u{301}{family}"));
+        assert_eq!(s, format!("{hangul}e\u{301}").as_str());
+        s.truncate_graphemes(10);
+        assert_eq!(s, hangul);
+        s.truncate_graphemes(8);
+        assert_eq!(s, "");
+
+        let mut cache = CacheString::from_str_truncate_graphemes(family.repeat(4));
+        assert_eq!(cache.len(), 54);
+        cache.push_str_truncate_graphemes(hangul);
+        assert_eq!(cache.len(), 63);
+        cache.truncate_graphemes(62);
+        assert_eq!(cache.as_str(), family.repeat(3));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-width")]
+    fn fit_width() {
+        use crate::{prelude::*, CacheString};
+
+        // Each char is two columns wide and three bytes long
+        let cjk = "漢字仮名交じり文";
+
+        for cols in 0..20 {
+            let s = ArrayString::<255>::from_str_fit_width(cjk, cols, "…");
+            let width: usize = s.chars().map(|c| if c == '…' { 1 } else { 2 }).sum();
+            assert!(width <= cols, "{s} is wider than {cols}");
+            assert!(width + 1 >= cols.min(16), "{s} leaves a gap in {cols}");
+        }
+        assert_eq!(ArrayString::<255>::from_str_fit_width(cjk, 16, "…"), cjk);
+        assert_eq!(
+            ArrayString::<255>::from_str_fit_width(cjk, 15, "…"),
+            "漢字仮名交じり…"
+        );
+        assert_eq!(
+            ArrayString::<255>::from_str_fit_width(cjk, 14, "…"),
+            "漢字仮名交じ…"
+        );
+        assert_eq!(ArrayString::<255>::from_str_fit_width(cjk, 1, "..."), "");
+        assert_eq!(ArrayString::<255>::from_str_fit_width(cjk, 2, "..."), "漢");
+
+        // Bytes limit the text before columns do
+        assert_eq!(ArrayString::<10>::from_str_fit_width(cjk, 80, "…"), "漢字…");
+        assert_eq!(ArrayString::<2>::from_str_fit_width("abc", 80, "…"), "ab");
+        assert_eq!(ArrayString::<3>::from_str_fit_width("abc", 80, "…"), "abc");
+
+        // Combining marks are zero width and go with their base char
+        assert_eq!(
+            ArrayString::<255>::from_str_fit_width("ae\u{301}io", 3, "…"),
+            "ae\u{301}…"
+        );
+
+        let mut cache = CacheString::try_from_str(cjk).unwrap();
+        cache.truncate_to_width(9, "…");
+        assert_eq!(cache, "漢字仮名…");
+        cache.truncate_to_width(9, "…");
+        assert_eq!(cache, "漢字仮名…");
+        cache.truncate_to_width(4, "");
+        assert_eq!(cache, "漢字");
+        let cache = CacheString::from_str_fit_width(cjk.repeat(4), 200, "…");
+        assert_eq!(cache.len(), 63);
+        assert!(cache.ends_with('…'));
+    }
+
+    #[test]
+    #[cfg(feature = "ryu")]
+    fn ryu_round_trip() {
+        use crate::prelude::*;
+
+        let values = [
+            0.0,
+            -0.0,
+            1.0,
+            0.1,
+            -2.5e-8,
+            1e300,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            f64::EPSILON,
+            core::f64::consts::PI,
+        ];
+        for n in values {
+            let s = ArrayString::<24>::try_from_f64(n).unwrap();
+            assert_eq!(s.parse::<f64>().unwrap().to_bits(), n.to_bits(), "{s}");
+        }
+        assert_eq!(ArrayString::<3>::try_from_f64(f64::NAN).unwrap(), "NaN");
+        assert_eq!(
+            ArrayString::<3>::try_from_f64(f64::INFINITY).unwrap(),
+            "inf"
+        );
+
+        let mut s = ArrayString::<8>::try_from_str("v").unwrap();
+        assert_eq!(s.try_push_f64(f64::MAX), Err(OutOfBounds::new(23, 8)));
+        assert_eq!(s, "v");
+    }
 }
@@ -0,0 +1,131 @@
+//! Owning char iterator for [`ArrayString`]
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+
+use crate::{arraystring::sealed::ValidCapacity, prelude::*, utils::IntoLossy};
+use core::fmt::{self, Debug, Formatter};
+use core::{iter::FusedIterator, str};
+#[cfg(feature = "logs")]
+use log::trace;
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// An owning iterator over the chars of an [`ArrayString`].
+///
+/// Created through [`IntoIterator`], unlike [`Drain`] each step is O(1) since it only moves the bounds of the remaining slice
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// fn letters(s: &str) -> IntoChars<8> {
+///     ArrayString::<8>::from_str_truncate(s).into_iter()
+/// }
+/// let mut chars = letters("a🤔c");
+/// assert_eq!(chars.next(), Some('a'));
+/// assert_eq!(chars.next_back(), Some('c'));
+/// assert_eq!(chars.as_str(), "🤔");
+/// assert_eq!(chars.collect::<Vec<_>>(), ['🤔']);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`ArrayString`]: ./struct.ArrayString.html
+/// [`IntoIterator`]: ./struct.ArrayString.html#impl-IntoIterator-for-ArrayString%3CN,+P%3E
+/// [`Drain`]: ./struct.Drain.html
+#[derive(Clone)]
+pub struct IntoChars<const N: usize>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    /// Bytes of the original string, only `start..end` is still yielded
+    array: [u8; N],
+    /// Index of the next char from the front
+    start: u8,
+    /// Index right after the next char from the back
+    end: u8,
+}
+
+impl<const N: usize> IntoChars<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    /// Creates iterator over every char of the string
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub(crate) fn new<P>(string: ArrayString<N, P>) -> Self
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        Self {
+            array: string.array,
+            start: 0,
+            end: string.size,
+        }
+    }
+
+    /// Extracts string slice containing the remaining characters of `IntoChars`.
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn as_str(&self) -> &str {
+        trace!("As str");
+        let range = self.start as usize..self.end as usize;
+        // Safety: the bytes come from a valid `ArrayString` and the bounds only move by whole chars
+        unsafe { str::from_utf8_unchecked(self.array.get_unchecked(range)) }
+    }
+}
+
+impl<const N: usize> Debug for IntoChars<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("IntoChars").field(&self.as_str()).finish()
+    }
+}
+
+impl<const N: usize> Iterator for IntoChars<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    type Item = char;
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ch = self.as_str().chars().next()?;
+        self.start = (self.start as usize + ch.len_utf8()).into_lossy();
+        Some(ch)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_str().chars().size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.as_str().chars().count()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for IntoChars<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ch = self.as_str().chars().next_back()?;
+        self.end = (self.end as usize - ch.len_utf8()).into_lossy();
+        Some(ch)
+    }
+}
+
+impl<const N: usize> FusedIterator for IntoChars<N> where ArrayString<N>: ValidCapacity {}
@@ -4,10 +4,12 @@
 //!
 //! *Maximum Capacity is 255*
 //!
-//! *Doesn't allocate memory on the heap and should never panic in release (except in `Index`/`IndexMut` traits, since they are supposed to)*
+//! *Doesn't allocate memory on the heap and should never panic in release (except in `Index`/`IndexMut` traits, since they are supposed to, use [`get`] and [`get_mut`] instead)*
 //!
 //! *The no panic garantee can be ensured at compilation time with the `no-panic` feature, just be aware that a compiler update might break this garantee, therefore making the crate uncompilable, open an issue if you notice.*
 //!
+//! *Without the `strict` feature `ArrayString::try_from(&str)` and `CacheString::try_from(&str)` never fail: they go through `From<&str>`, which truncates text that doesn't fit and returns `Ok`. Use [`try_from_str`] (or `parse`) to get an error, and enable `strict` (recommended) to make the truncating conversions a compile error.*
+//!
 //! ## Why
 //!
 //! Data is generally bounded, you don't want a phone number with 30 characters, nor a username with 100. You probably don't even support it in your database.
@@ -22,14 +24,22 @@
 //!
 //! Be aware that array based strings always occupy the full space in memory, so they may use more memory (although in the stack) than dynamic strings.
 //!
+//! Bytes past the length are always kept zeroed, so equality and ordering compare the whole array in machine words instead of variable length slices. `Hash` must stay consistent with `Borrow<str>`, so hashing the whole array is opt-in through the [`FastHash`] wrapper.
+//!
 //! [`capacity`]: ./struct.ArrayString.html#method.capacity
 //! [`MaxString`]: ./type.MaxString.html
+//! [`try_from_str`]: ./struct.ArrayString.html#method.try_from_str
+//! [`try_push_str`]: ./struct.ArrayString.html#method.try_push_str
+//! [`OutOfBounds`]: ./error/struct.OutOfBounds.html
+//! [`get`]: ./struct.ArrayString.html#method.get
+//! [`get_mut`]: ./struct.ArrayString.html#method.get_mut
 //!
 //! ## Features
 //!
 //! **default:** `std`
 //!
 //! - `std` enabled by default, enables `std` compatibility, implementing std only traits (disable it to be `#[no_std]` compatible)
+//! - `alloc` enabled by `std`, adds [`InlineOrHeap`], that stores text in an [`ArrayString`] and moves it to a `String` when it outgrows capacity
 //! - `serde-traits` enables serde traits integration (`Serialize`/`Deserialize`)
 //!
 //!     Opperates like `String`, but truncates it if it's bigger than capacity
@@ -38,12 +48,57 @@
 //!
 //!      Opperates like `String`, but truncates it if it's bigger than capacity
 //!
+//! - `arbitrary`, `proptest` and `quickcheck` implement each crate's `Arbitrary` for [`ArrayString`], [`CacheString`] and [`Drain`]
+//!
+//!     Generates valid utf-8 up to capacity, biased toward multi-byte chars and strings filled to the exact capacity
+//!
+//! - `schemars` implements `JsonSchema` for [`ArrayString`] and [`CacheString`]
+//!
+//!     Emits `{"type": "string", "maxLength": N}`, since JSON Schema counts `maxLength` in chars the byte limit is also exposed in the `x-maxBytes` extension keyword
+//!
+//! - `defmt` implements `defmt::Format` for [`ArrayString`], [`CacheString`], [`Drain`] and the error types
+//! - `ufmt` implements `ufmt::uWrite` and `ufmt::uDisplay` for [`ArrayString`] and [`CacheString`]
+//!
+//!     `uwrite!` appends each piece with [`try_push_str`] semantics, returning [`OutOfBounds`] if it doesn't fit
+//!
+//! - `heapless`, `arrayvec`, `smol_str` and `compact_str` implement conversions between [`ArrayString`] and each crate's string
+//!
+//!     Converting to [`ArrayString`] (or to a fixed capacity string) is done with `TryFrom`, since capacities may differ. `smol_str` and `compact_str` only allocate if the string doesn't fit inline
+//!
+//! - `zeroize` implements `Zeroize` for [`ArrayString`] and [`CacheString`] and adds [`SecretArrayString`]
+//!
+//!     [`SecretArrayString`] is not `Copy`, wipes its buffer on drop and on every shrinking operation, and redacts its text in `Debug` and `Display`
+//!
+//! - `subtle` implements `subtle::ConstantTimeEq` for [`ArrayString`] and [`CacheString`] (and `SecretArrayString` with `zeroize`), and adds `ct_eq_str`
+//!
+//!     Compares the full array and the length, so the time depends on the capacity, not on where the first difference is. Use it for tokens and digests
+//!
+//! - `unicode-segmentation` adds `from_str_truncate_graphemes`, `push_str_truncate_graphemes` and `truncate_graphemes` to [`ArrayString`] and [`CacheString`]
+//!
+//!     The `_truncate` methods only respect char boundaries, these never split extended grapheme clusters like flags, emoji joined by ZWJ or combining accents
+//!
+//! - `unicode-width` adds `from_str_fit_width` to [`ArrayString`] and [`CacheString`] and `truncate_to_width` to both
+//!
+//!     Fits text in a number of terminal columns and in the byte capacity, ending with an ellipsis if it had to be cut. Wide CJK chars are never split
+//!
+//! - `ryu` adds `try_from_f64` and `try_push_f64` to [`ArrayString`]
+//!
+//!     Writes the shortest text that parses back to the same `f64` without going through `core::fmt`. Integers don't need a feature, `try_from_u64`, `try_push_u64` and their radix and zero padded variants are always available
+//!
 //! - `no-panic` checks at compile time that the panic function is not linked by the library
 //!
 //!      Be careful before using this, it won't change functions behaviors, it will just enforce that panic functions can't be linked by this library. This may break your compilation and won't improve the safety of this library. It's mostly for testing and environments where if the non panicking invariantcan't be garanteed compilation should fail. This should not apply to most projects.
 //!
 //!      Only works when all optimizations are enabled, and may break in future compiler updates. Please open an issue if you notice.
 //!
+//! - `no-panicking-index` removes the `Index` and `IndexMut` impls, which panic like `str` indexing
+//!
+//!     Use [`get`] and [`get_mut`] instead. Indexing still compiles through `Deref<Target = str>`, so it only removes the panicking code from this library
+//!
+//! - `strict` (recommended) removes the impls that truncate silently: `From<&str>`, `FromIterator`, `Extend` and `Add<&str>`, for [`ArrayString`] and [`CacheString`]
+//!
+//!     Accidental truncation becomes a compile error, use `TryFrom<&str>` or the `*_truncate` methods instead. **Without it `TryFrom<&str>` silently truncates**: it only returns an error for `ArrayString<N, Reject>`, for lossy policies it's std's blanket impl over `From<&str>`, which never fails. Use [`try_from_str`] to check the length
+//!
 //! - `logs` enables internal logging
 //!
 //!     You will probably only need this if you are debugging this library
@@ -73,6 +128,77 @@
 //! }
 //! ```
 //!
+//! To validate more than the length, [`Validated`] runs a rule on creation, on every mutation and in the serde and diesel decoders:
+//!
+//! ```rust
+//! use arraystring::{Error, Validated, validated::{Alphanumeric, Ascii}};
+//!
+//! type Username = Validated<20, (Ascii, Alphanumeric)>;
+//!
+//! fn main() -> Result<(), Error> {
+//!     let username = Username::try_from_str("user42")?;
+//!     println!("{}", username);
+//!
+//!     assert_eq!(Username::try_from_str("user 42"), Err(Error::Invalid { rule: "alphanumeric" }));
+//!     Ok(())
+//! }
+//! ```
+//!
+//! For a minimum length in chars there is [`BoundedString`], its shrinking operations error instead of going below the minimum.
+//!
+//! Limits in chars, like SQL's `VARCHAR(n)`, are enforced by [`CharBounded`], it stores the text in a byte buffer sized for the worst case and caches the char count:
+//!
+//! ```rust
+//! use arraystring::{CharBounded, Error};
+//!
+//! fn main() -> Result<(), Error> {
+//!     let name = CharBounded::<5, 20>::try_from_str("Zoë")?;
+//!     assert_eq!(name.chars_len(), 3);
+//!     assert_eq!(CharBounded::<5, 20>::try_from_str("Chloë!"), Err(Error::CharLimit { needed: 6, limit: 5 }));
+//!     Ok(())
+//! }
+//! ```
+//!
+//! What happens when text doesn't fit is set per type by the [`policy`] parameter, it defaults to truncating. `push_str` follows it, and so do `From<&str>`, `FromIterator`, `Extend` and `Add`, which aren't implemented for [`Reject`]:
+//!
+//! ```rust
+//! use arraystring::{error::OutOfBounds, policy::{Reject, TruncateWithMarker}, ArrayString};
+//!
+//! fn main() -> Result<(), OutOfBounds> {
+//!     let mut preview = ArrayString::<8, TruncateWithMarker>::new();
+//!     assert_eq!(preview.push_str("Hello World"), Err(OutOfBounds::new(11, 8)));
+//!     assert_eq!(preview.as_str(), "Hello…");
+//!
+//!     let mut code = ArrayString::<4, Reject>::try_from_str("ab")?;
+//!     assert_eq!(code.push_str("cde"), Err(OutOfBounds::new(5, 4)));
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Your own newtypes get the same methods and traits as [`CacheString`] by deriving [`ArrayStringNewtype`], it also implements the enabled integrations. `#[arraystring(validator = ...)]` makes it a validated newtype and `#[arraystring(align = N)]` checks its `#[repr(align(N))]`:
+//!
+//! ```rust
+//! use arraystring::{ArrayString, ArrayStringNewtype, Error, validated::Ascii};
+//!
+//! #[derive(Copy, Clone, ArrayStringNewtype)]
+//! #[arraystring(validator = Ascii)]
+//! pub struct Ticker(ArrayString<8>);
+//!
+//! fn main() -> Result<(), Error> {
+//!     let mut ticker = Ticker::try_from_str("ACME")?;
+//!     assert_eq!(ticker.try_push('é'), Err(Error::Invalid { rule: "ascii" }));
+//!     assert_eq!(ticker, "ACME");
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`Validated`]: ./validated/struct.Validated.html
+//! [`BoundedString`]: ./validated/type.BoundedString.html
+//! [`CharBounded`]: ./struct.CharBounded.html
+//! [`policy`]: ./policy/index.html
+//! [`Reject`]: ./policy/struct.Reject.html
+//! [`ArrayStringNewtype`]: ./derive.ArrayStringNewtype.html
+//!
 //! ## Licenses
 //!
 //! `MIT` and `Apache-2.0`
@@ -96,7 +222,6 @@
     overflowing_literals,
     path_statements,
     patterns_in_fns_without_body,
-    private_in_public,
     unconditional_recursion,
     unused_allocation,
     unused_comparisons,
@@ -117,596 +242,636 @@ mod mock {
     macro_rules! error(($($x:tt)*) => ());
 }
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod arraystring;
+mod char_bounded;
 mod drain;
 pub mod error;
+mod fast_hash;
 mod implementations;
-#[cfg(any(feature = "serde-traits", feature = "diesel-traits"))]
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+mod inline_or_heap;
+#[cfg(any(
+    feature = "serde-traits",
+    feature = "diesel-traits",
+    feature = "arbitrary",
+    feature = "proptest",
+    feature = "quickcheck",
+    feature = "schemars",
+    feature = "defmt",
+    feature = "ufmt",
+    feature = "heapless",
+    feature = "arrayvec",
+    feature = "smol_str",
+    feature = "compact_str",
+    feature = "zeroize",
+    feature = "subtle",
+    feature = "unicode-segmentation",
+    feature = "unicode-width",
+    feature = "ryu"
+))]
 mod integration;
+mod into_chars;
+mod newtype;
+mod number;
+pub mod policy;
+#[cfg_attr(docs_rs_workaround, doc(cfg(feature = "zeroize")))]
+#[cfg(feature = "zeroize")]
+mod secret;
 #[doc(hidden)]
 pub mod utils;
+pub mod validated;
 
 /// All structs defined by this crate
 pub mod prelude {
     pub use crate::arraystring::ArrayString;
+    pub use crate::char_bounded::CharBounded;
     pub use crate::drain::Drain;
-    pub use crate::error::{OutOfBounds, Utf16, Utf8};
+    pub use crate::error::{CapacityError, CharLimit, Invalid, OutOfBounds, Utf16, Utf8};
+    pub use crate::fast_hash::FastHash;
+    #[cfg(feature = "alloc")]
+    pub use crate::inline_or_heap::{InlineOrHeap, InlineOrHeapDrain};
+    pub use crate::into_chars::IntoChars;
+    #[cfg(feature = "zeroize")]
+    pub use crate::secret::SecretArrayString;
+    pub use crate::validated::{BoundedString, Validated};
     pub use crate::{CacheString, MaxString, SmallString};
 }
 
-pub use crate::arraystring::ArrayString;
+pub use crate::arraystring::{sealed::ValidCapacity, ArrayString, ExtendItem};
+pub use crate::char_bounded::CharBounded;
 pub use crate::drain::Drain;
 pub use crate::error::Error;
+pub use crate::fast_hash::FastHash;
+#[cfg(feature = "alloc")]
+pub use crate::inline_or_heap::{InlineOrHeap, InlineOrHeapDrain};
+pub use crate::into_chars::IntoChars;
+#[cfg(feature = "zeroize")]
+pub use crate::secret::SecretArrayString;
+pub use crate::validated::{BoundedString, Validated};
+pub use arraystring_derive::ArrayStringNewtype;
+#[doc(hidden)]
+pub use newtype::__private;
 
 /// String with the same `core::mem::size_of` of a `String` (`core::mem::size_of::<usize> * 3`)
 ///
 /// 24 bytes in 64 bits architecture
 ///
 /// 12 bytes in 32 bits architecture
-pub type SmallString = ArrayString<{ core::mem::size_of::<usize>() * 3 }>;
+pub type SmallString = ArrayString<{ size_of::<usize>() * 3 }>;
 
 /// Biggest `ArrayString<N>` supported (255 bytes of text)
 pub type MaxString = ArrayString<255>;
 
 mod cache_string {
-    use crate::{prelude::*, Error};
-    use core::fmt::{self, Debug, Display, Formatter, Write};
-    use core::{borrow::Borrow, borrow::BorrowMut, ops::*};
-    use core::{cmp::Ordering, hash::Hash, hash::Hasher, str::FromStr};
+    use crate::{prelude::*, ArrayStringNewtype};
 
     const CACHE_STRING_SIZE: usize = 63;
     /// Newtype string that occupies 64 bytes in memory and is 64 bytes aligned (full cache line)
     ///
     /// 63 bytes of text
+    ///
+    /// Its methods and traits are generated by [`ArrayStringNewtype`], they forward to the inner `ArrayString<63>`
+    ///
+    /// [`ArrayStringNewtype`]: ./derive.ArrayStringNewtype.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(CacheString::capacity(), 63);
+    ///
+    /// let mut string = CacheString::try_from_str("My String")?;
+    /// string.try_push_str(" My Other String")?;
+    /// assert_eq!(string.as_str(), "My String My Other String");
+    /// assert_eq!(string.split_off(9)?.as_str(), " My Other String");
+    ///
+    /// let out_of_bounds = "0".repeat(CacheString::capacity() + 1);
+    /// assert!(CacheString::try_from_str(&out_of_bounds).is_err());
+    /// assert_eq!(CacheString::from_str_truncate(&out_of_bounds).len(), 63);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[repr(align(64))]
-    #[derive(Copy, Clone, Default)]
+    #[derive(Copy, Clone, Default, ArrayStringNewtype)]
+    #[arraystring(align = 64, crate = "crate")]
     #[cfg_attr(
         feature = "diesel-traits",
         derive(diesel::AsExpression, diesel::FromSqlRow)
     )]
     #[cfg_attr(feature = "diesel-traits", diesel(sql_type = diesel::sql_types::Text))]
     pub struct CacheString(pub(crate) ArrayString<CACHE_STRING_SIZE>);
+}
+pub use cache_string::*;
 
-    impl CacheString {
-        /// Creates new empty `CacheString`.
-        ///
-        /// ```rust
-        /// # use arraystring::prelude::*;
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let string = CacheString::new();
-        /// assert!(string.is_empty());
-        /// ```
-        #[inline]
-        pub const fn new() -> Self {
-            Self(ArrayString::<CACHE_STRING_SIZE>::new())
-        }
-
-        /// Creates new `CacheString` from string slice if length is lower or equal to [`capacity`], otherwise returns an error.
-        ///
-        /// [`capacity`]: ./struct.CacheString.html#method.capacity
-        /// ```rust
-        /// # use arraystring::{Error, prelude::*};
-        /// # fn main() -> Result<(), Error> {
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let string = CacheString::try_from_str("My String")?;
-        /// assert_eq!(string.as_str(), "My String");
-        ///
-        /// assert_eq!(CacheString::try_from_str("")?.as_str(), "");
-        ///
-        /// let out_of_bounds = "0".repeat(CacheString::capacity() + 1);
-        /// assert!(CacheString::try_from_str(out_of_bounds).is_err());
-        /// # Ok(())
-        /// # }
-        /// ```
-        #[inline]
-        pub fn try_from_str(s: impl AsRef<str>) -> Result<Self, OutOfBounds> {
-            Ok(Self(ArrayString::try_from_str(s)?))
-        }
-
-        /// Creates new `CacheString` from string slice truncating size if bigger than [`capacity`].
-        ///
-        /// [`capacity`]: ./struct.CacheString.html#method.capacity
-        ///
-        /// ```rust
-        /// # use arraystring::prelude::*;
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let string = CacheString::from_str_truncate("My String");
-        /// # assert_eq!(string.as_str(), "My String");
-        /// println!("{}", string);
-        ///
-        /// let truncate = "0".repeat(CacheString::capacity() + 1);
-        /// let truncated = "0".repeat(CacheString::capacity());
-        /// let string = CacheString::from_str_truncate(&truncate);
-        /// assert_eq!(string.as_str(), truncated);
-        /// ```
-        #[inline]
-        pub fn from_str_truncate(string: impl AsRef<str>) -> Self {
-            Self(ArrayString::from_str_truncate(string))
-        }
-
-        /// Creates new `CacheString` from string slice iterator if total length is lower or equal to [`capacity`], otherwise returns an error.
-        ///
-        /// [`capacity`]: ./struct.CacheString.html#method.capacity
-        ///
-        /// ```rust
-        /// # use arraystring::prelude::*;
-        /// # fn main() -> Result<(), OutOfBounds> {
-        /// let string = CacheString::try_from_iterator(&["My String", " My Other String"][..])?;
-        /// assert_eq!(string.as_str(), "My String My Other String");
-        ///
-        /// let out_of_bounds = (0..100).map(|_| "000");
-        /// assert!(CacheString::try_from_iterator(out_of_bounds).is_err());
-        /// # Ok(())
-        /// # }
-        /// ```
-        #[inline]
-        pub fn try_from_iterator(
-            iter: impl IntoIterator<Item = impl AsRef<str>>,
-        ) -> Result<Self, OutOfBounds> {
-            Ok(Self(ArrayString::try_from_iterator(iter)?))
-        }
-
-        /// Creates new `CacheString` from string slice iterator truncating size if bigger than [`capacity`].
-        ///
-        /// [`capacity`]: ./struct.CacheString.html#method.capacity
-        ///
-        /// ```rust
-        /// # use arraystring::prelude::*;
-        /// # fn main() -> Result<(), OutOfBounds> {
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let string = CacheString::from_iterator_truncate(&["My String", " Other String"][..]);
-        /// assert_eq!(string.as_str(), "My String Other String");
-        ///
-        /// let out_of_bounds = (0..400).map(|_| "000");
-        /// let truncated = "0".repeat(CacheString::capacity());
-        ///
-        /// let truncate = CacheString::from_iterator_truncate(out_of_bounds);
-        /// assert_eq!(truncate.as_str(), truncated.as_str());
-        /// # Ok(())
-        /// # }
-        /// ```
-        #[inline]
-        pub fn from_iterator_truncate(iter: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
-            Self(ArrayString::from_iterator_truncate(iter))
-        }
-
-        /// Creates new `CacheString` from char iterator if total length is lower or equal to [`capacity`], otherwise returns an error.
-        ///
-        /// [`capacity`]: ./struct.CacheString.html#method.capacity
-        ///
-        /// ```rust
-        /// # use arraystring::{Error, prelude::*};
-        /// # fn main() -> Result<(), Error> {
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let string = CacheString::try_from_chars("My String".chars())?;
-        /// assert_eq!(string.as_str(), "My String");
-        ///
-        /// let out_of_bounds = "0".repeat(CacheString::capacity() + 1);
-        /// assert!(CacheString::try_from_chars(out_of_bounds.chars()).is_err());
-        /// # Ok(())
-        /// # }
-        /// ```
-        #[inline]
-        pub fn try_from_chars(iter: impl IntoIterator<Item = char>) -> Result<Self, OutOfBounds> {
-            Ok(Self(ArrayString::try_from_chars(iter)?))
-        }
-
-        /// Creates new `CacheString` from char iterator truncating size if bigger than [`capacity`].
-        ///
-        /// [`capacity`]: ./struct.CacheString.html#method.capacity
-        ///
-        /// ```rust
-        /// # use arraystring::prelude::*;
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let string = CacheString::from_chars_truncate("My String".chars());
-        /// assert_eq!(string.as_str(), "My String");
-        ///
-        /// let out_of_bounds = "0".repeat(CacheString::capacity() + 1);
-        /// let truncated = "0".repeat(CacheString::capacity());
-        ///
-        /// let truncate = CacheString::from_chars_truncate(out_of_bounds.chars());
-        /// assert_eq!(truncate.as_str(), truncated.as_str());
-        /// ```
-        #[inline]
-        pub fn from_chars_truncate(iter: impl IntoIterator<Item = char>) -> Self {
-            Self(ArrayString::from_chars_truncate(iter))
-        }
-
-        /// Creates new `CacheString` from `u16` slice, returning [`Utf16`] on invalid utf-16 data or [`OutOfBounds`] if bigger than [`capacity`]
-        ///
-        /// [`Utf16`]: ./error/enum.Error.html#variant.Utf16
-        /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
-        /// [`capacity`]: ./struct.CacheString.html#method.capacity
-        ///
-        /// ```rust
-        /// # use arraystring::{Error, prelude::*};
-        /// # fn main() -> Result<(), Error> {
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let music = [0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069, 0x0063];
-        /// let string = CacheString::try_from_utf16(music)?;
-        /// assert_eq!(string.as_str(), "𝄞music");
-        ///
-        /// let invalid_utf16 = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
-        /// assert_eq!(CacheString::try_from_utf16(invalid_utf16), Err(Error::Utf16));
-        ///
-        /// let out_of_bounds: Vec<_> = (0..300).map(|_| 0).collect();
-        /// assert_eq!(CacheString::try_from_utf16(out_of_bounds), Err(Error::OutOfBounds));
-        /// # Ok(())
-        /// # }
-        /// ```
-        #[inline]
-        pub fn try_from_utf16(slice: impl AsRef<[u16]>) -> Result<Self, Error> {
-            Ok(Self(ArrayString::try_from_utf16(slice)?))
-        }
-
-        /// Creates new `CacheString` from `u16` slice, returning [`Utf16`] on invalid utf-16 data, truncating if bigger than [`capacity`].
-        ///
-        /// [`Utf16`]: ./error/struct.Utf16.html
-        /// [`capacity`]: ./struct.CacheString.html#method.capacity
-        ///
-        /// ```rust
-        /// # use arraystring::{Error, prelude::*};
-        /// # fn main() -> Result<(), Error> {
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let music = [0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069, 0x0063];
-        /// let string = CacheString::from_utf16_truncate(music)?;
-        /// assert_eq!(string.as_str(), "𝄞music");
-        ///
-        /// let invalid_utf16 = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
-        /// assert_eq!(CacheString::from_utf16_truncate(invalid_utf16), Err(Utf16));
-        ///
-        /// let out_of_bounds: Vec<u16> = (0..300).map(|_| 0).collect();
-        /// assert_eq!(CacheString::from_utf16_truncate(out_of_bounds)?.as_str(),
-        ///            "\0".repeat(CacheString::capacity()).as_str());
-        /// # Ok(())
-        /// # }
-        /// ```
-        #[inline]
-        pub fn from_utf16_truncate(slice: impl AsRef<[u16]>) -> Result<Self, Utf16> {
-            Ok(Self(ArrayString::from_utf16_truncate(slice)?))
-        }
-
-        /// Creates new `CacheString` from `u16` slice, replacing invalid utf-16 data with `REPLACEMENT_CHARACTER` (\u{FFFD}) and truncating size if bigger than [`capacity`]
-        ///
-        /// [`capacity`]: ./struct.CacheString.html#method.capacity
-        ///
-        /// ```rust
-        /// # use arraystring::{Error, prelude::*};
-        /// # fn main() -> Result<(), Error> {
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let music = [0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069, 0x0063];
-        /// let string = CacheString::from_utf16_lossy_truncate(music);
-        /// assert_eq!(string.as_str(), "𝄞music");
-        ///
-        /// let invalid_utf16 = [0xD834, 0xDD1E, 0x006d, 0x0075, 0xD800, 0x0069, 0x0063];
-        /// assert_eq!(CacheString::from_utf16_lossy_truncate(invalid_utf16).as_str(), "𝄞mu\u{FFFD}ic");
-        ///
-        /// let out_of_bounds: Vec<u16> = (0..300).map(|_| 0).collect();
-        /// assert_eq!(CacheString::from_utf16_lossy_truncate(&out_of_bounds).as_str(),
-        ///            "\0".repeat(CacheString::capacity()).as_str());
-        /// # Ok(())
-        /// # }
-        /// ```
-        #[inline]
-        pub fn from_utf16_lossy_truncate(slice: impl AsRef<[u16]>) -> Self {
-            Self(ArrayString::from_utf16_lossy_truncate(slice))
-        }
-
-        /// Returns maximum string capacity, defined at compile time, it will never change
-        ///
-        /// Should always return 63 bytes
-        ///
-        /// ```rust
-        /// # use arraystring::prelude::*;
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// assert_eq!(CacheString::capacity(), 63);
-        /// ```
-        #[inline]
-        pub const fn capacity() -> usize {
-            CACHE_STRING_SIZE
-        }
-
-        /// Splits `CacheString` in two if `at` is smaller than `self.len()`.
-        ///
-        /// Returns [`Utf8`] if `at` does not lie at a valid utf-8 char boundary and [`OutOfBounds`] if it's out of bounds
-        ///
-        /// [`OutOfBounds`]: ./error/enum.Error.html#variant.OutOfBounds
-        /// [`Utf8`]: ./error/enum.Error.html#variant.Utf8
-        ///
-        /// ```rust
-        /// # use arraystring::{Error, prelude::*};
-        /// # fn main() -> Result<(), Error> {
-        /// # #[cfg(not(miri))] let _ = env_logger::try_init();
-        /// let mut s = CacheString::try_from_str("AB🤔CD")?;
-        /// assert_eq!(s.split_off(6)?.as_str(), "CD");
-        /// assert_eq!(s.as_str(), "AB🤔");
-        /// assert_eq!(s.split_off(20), Err(Error::OutOfBounds));
-        /// assert_eq!(s.split_off(4), Err(Error::Utf8));
-        /// # Ok(())
-        /// # }
-        /// ```
-        #[inline]
-        pub fn split_off(&mut self, at: usize) -> Result<Self, Error> {
-            Ok(Self(self.0.split_off(at)?))
-        }
-    }
-
-    impl Debug for CacheString {
-        #[inline]
-        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-            f.debug_tuple("CacheString").field(&self.0).finish()
-        }
-    }
-
-    impl Hash for CacheString {
-        #[inline]
-        fn hash<H: Hasher>(&self, hasher: &mut H) {
-            self.0.hash(hasher);
-        }
-    }
-
-    impl PartialEq for CacheString {
-        #[inline]
-        fn eq(&self, other: &Self) -> bool {
-            self.0.eq(&other.0)
-        }
-    }
-    impl Eq for CacheString {}
-
-    impl Ord for CacheString {
-        #[inline]
-        fn cmp(&self, other: &Self) -> Ordering {
-            self.0.cmp(&other.0)
-        }
-    }
-
-    impl PartialOrd for CacheString {
-        #[inline]
-        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-            Some(self.cmp(other))
-        }
-    }
-
-    impl Deref for CacheString {
-        type Target = ArrayString<CACHE_STRING_SIZE>;
-
-        #[inline]
-        fn deref(&self) -> &Self::Target {
-            &self.0
-        }
-    }
-
-    impl DerefMut for CacheString {
-        #[inline]
-        fn deref_mut(&mut self) -> &mut ArrayString<CACHE_STRING_SIZE> {
-            &mut self.0
-        }
-    }
-
-    impl FromIterator<char> for CacheString {
-        fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
-            Self(ArrayString::<CACHE_STRING_SIZE>::from_iter(iter))
-        }
-    }
-
-    impl<'a> FromIterator<&'a str> for CacheString {
-        fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
-            Self(ArrayString::<CACHE_STRING_SIZE>::from_iter(iter))
-        }
-    }
-
-    impl Extend<char> for CacheString {
-        fn extend<I: IntoIterator<Item = char>>(&mut self, iterable: I) {
-            self.0.extend(iterable);
-        }
-    }
-
-    impl<'a> Extend<&'a char> for CacheString {
-        fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
-            self.0.extend(iter)
-        }
-    }
-
-    impl<'a> Extend<&'a str> for CacheString {
-        fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iterable: I) {
-            self.0.extend(iterable);
-        }
-    }
-
-    impl Display for CacheString {
-        #[inline]
-        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-            Display::fmt(&self.0, f)
-        }
-    }
-
-    impl AsRef<str> for CacheString {
-        #[inline]
-        fn as_ref(&self) -> &str {
-            self.0.as_ref()
-        }
-    }
-
-    impl AsMut<str> for CacheString {
-        #[inline]
-        fn as_mut(&mut self) -> &mut str {
-            self.0.as_mut()
-        }
-    }
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
 
-    impl AsRef<[u8]> for CacheString {
-        #[inline]
-        fn as_ref(&self) -> &[u8] {
-            self.0.as_ref()
-        }
+    #[test]
+    fn size_of_cache() {
+        assert_eq!(size_of::<CacheString>(), 64);
     }
 
-    impl FromStr for CacheString {
-        type Err = OutOfBounds;
-
-        #[inline]
-        fn from_str(s: &str) -> Result<Self, Self::Err> {
-            Ok(Self(ArrayString::try_from_str(s)?))
+    #[test]
+    fn tail_zeroed() {
+        let full = || ArrayString::<16>::try_from_str("ab🤔cdefghijkl").unwrap();
+        let ops: [fn(&mut ArrayString<16>); 9] = [
+            |s| s.truncate(6).unwrap(),
+            |s| {
+                let _ = s.pop();
+            },
+            |s| {
+                let _ = s.remove(2).unwrap();
+            },
+            |s| s.replace_range(1..8, "x").unwrap(),
+            |s| s.clear(),
+            |s| {
+                let _ = s.drain(..6).unwrap();
+            },
+            |s| {
+                let _ = s.split_off(6).unwrap();
+            },
+            |s| s.retain(|c| c != 'a'),
+            |s| {
+                s.replace_range(..2, "  ").unwrap();
+                s.trim();
+            },
+        ];
+        for op in ops {
+            let mut s = full();
+            op(&mut s);
+            assert!(s.is_tail_zeroed(), "{s:?}");
+            assert_eq!(s, ArrayString::<16>::try_from_str(s.as_str()).unwrap());
         }
     }
 
-    impl PartialEq<str> for CacheString {
-        #[inline]
-        fn eq(&self, other: &str) -> bool {
-            self.0.eq(other)
-        }
+    #[test]
+    fn char_bounded_count() {
+        type Name = CharBounded<6, 16>;
+        let full = || Name::try_from_str("aé🤔").unwrap();
+        let ops: [fn(&mut Name); 8] = [
+            |s| s.try_push('ü').unwrap(),
+            |s| s.try_push_str("ça").unwrap(),
+            |s| assert!(s.try_push_str("🤔🤔🤔").is_err()),
+            |s| s.push_str_truncate("日本語のテキスト"),
+            |s| s.push_str_truncate("🤔🤔🤔"),
+            |s| s.truncate_chars(2),
+            |s| {
+                let _ = s.pop();
+            },
+            |s| s.clear(),
+        ];
+        for op in ops {
+            let mut s = full();
+            op(&mut s);
+            assert_eq!(s.chars_len(), s.chars().count(), "{s:?}");
+            assert!(s.chars_len() <= 6 && s.len() <= 16, "{s:?}");
+            assert_eq!(s, Name::try_from_str(s.as_str()).unwrap());
+        }
+        assert_eq!(Name::from_str_truncate("日本語のテキスト"), "日本語のテ");
+        assert_eq!(Name::from_str_truncate("🤔🤔🤔🤔🤔"), "🤔🤔🤔🤔");
     }
 
-    impl PartialEq<&str> for CacheString {
-        #[inline]
-        fn eq(&self, other: &&str) -> bool {
-            self.0.eq(other)
-        }
+    #[test]
+    #[cfg(not(feature = "strict"))]
+    fn overflow_policies() {
+        use crate::policy::{Reject, Saturate, TruncateWithMarker};
+
+        let words = ["alpha ", "beta ", "gamma"];
+        let truncate: ArrayString<13> = words.into_iter().collect();
+        assert_eq!(truncate, "alpha beta ga");
+        let marker: ArrayString<13, TruncateWithMarker> = words.into_iter().collect();
+        assert_eq!(marker, "alpha beta…");
+        let saturate: ArrayString<13, Saturate> = words.into_iter().collect();
+        assert_eq!(saturate, "alpha beta ");
+
+        let chars: ArrayString<5, TruncateWithMarker> = "abcdef".chars().collect();
+        assert_eq!(chars, "ab…");
+        let mut chars = chars;
+        chars.extend(['x']);
+        assert_eq!(chars, "ab…");
+        assert_eq!(ArrayString::<2, TruncateWithMarker>::from("abc"), "ab");
+        assert_eq!(ArrayString::<5, Saturate>::from("abcdef"), "");
+        assert_eq!(ArrayString::<5, Saturate>::from("ab") + "cdef" + "g", "abg");
+
+        let mut reject = ArrayString::<5, Reject>::try_from_str("abc").unwrap();
+        assert_eq!(reject.push_str("def"), Err(OutOfBounds::new(6, 5)));
+        reject.push_str_truncate("def");
+        assert_eq!(reject, "abcde");
+        assert_eq!(reject.with_policy::<crate::policy::Truncate>(), "abcde");
+        let split = reject.split_off(3).unwrap();
+        assert_eq!((reject.as_str(), split.as_str()), ("abc", "de"));
+        assert_eq!(reject.drain(..1).unwrap().collect::<String>(), "a");
+        assert_eq!(
+            size_of::<ArrayString<5, Reject>>(),
+            size_of::<ArrayString<5>>()
+        );
     }
 
-    impl Borrow<str> for CacheString {
-        #[inline]
-        fn borrow(&self) -> &str {
-            self.0.borrow()
-        }
-    }
-    impl BorrowMut<str> for CacheString {
-        #[inline]
-        fn borrow_mut(&mut self) -> &mut str {
-            self.0.borrow_mut()
-        }
+    #[test]
+    fn error_details() {
+        let err = ArrayString::<255>::try_from_str("a".repeat(300)).unwrap_err();
+        assert_eq!((err.needed(), err.capacity()), (300, 255));
+        assert_eq!(err.to_string(), "needed 300 bytes but capacity is 255");
+        assert_eq!(
+            crate::Error::from(err),
+            crate::Error::OutOfBounds {
+                needed: 300,
+                capacity: 255
+            }
+        );
+
+        let mut s = ArrayString::<16>::try_from_str("ab🤔cd").unwrap();
+        let err = s.truncate(4).unwrap_err();
+        assert_eq!((err.index(), err.valid_up_to()), (4, 2));
+        assert_eq!(
+            err.to_string(),
+            "byte 4 is not a char boundary, valid up to 2"
+        );
+
+        let bytes = core::hint::black_box([b'a', b'b', 0xFF, b'c']);
+        let utf8 = crate::Error::from(core::str::from_utf8(&bytes).unwrap_err());
+        assert_eq!(
+            utf8,
+            crate::Error::Utf8 {
+                index: 2,
+                valid_up_to: 2
+            }
+        );
+        assert_eq!(utf8.to_string(), "invalid utf-8 at byte 2");
     }
 
-    impl<'a> Add<&'a str> for CacheString {
-        type Output = Self;
+    #[test]
+    fn capacity_error_returns_input() {
+        let mut s = ArrayString::<4>::try_from_str("ab").unwrap();
+        let err = s.try_push_str(String::from("cde")).unwrap_err();
+        assert_eq!(err.error(), OutOfBounds::new(5, 4));
+        assert_eq!(err.into_inner(), "cde");
+        assert_eq!(s.try_insert(9, '🤔').unwrap_err().into_inner(), '🤔');
 
-        #[inline]
-        fn add(self, other: &str) -> Self::Output {
-            Self(self.0.add(other))
-        }
-    }
+        let mut bounded = CharBounded::<3, 16>::try_from_str("ab").unwrap();
+        assert_eq!(bounded.try_push_str("cd").unwrap_err().into_inner(), "cd");
+        assert_eq!(bounded.chars_len(), 2);
 
-    impl Write for CacheString {
-        #[inline]
-        fn write_str(&mut self, slice: &str) -> fmt::Result {
-            self.0.write_str(slice)
-        }
+        let text = "a".repeat(70);
+        let err = CacheString::try_from_chars(text.chars()).unwrap_err();
+        let (partial, rest) = err.into_inner();
+        assert_eq!(partial.len(), 63);
+        assert_eq!(rest.count(), 7);
     }
 
-    impl From<ArrayString<CACHE_STRING_SIZE>> for CacheString {
-        fn from(array: ArrayString<CACHE_STRING_SIZE>) -> Self {
-            Self(array)
-        }
+    #[test]
+    fn char_limit_errors() {
+        use crate::Error;
+
+        type Name = CharBounded<5, 20>;
+        let err = Name::try_from_str("abcdef").unwrap_err();
+        assert_eq!(
+            err,
+            Error::CharLimit {
+                needed: 6,
+                limit: 5
+            }
+        );
+        assert_eq!(err.to_string(), "needed 6 chars but limit is 5");
+        assert_eq!(
+            CharBounded::<5, 8>::try_from_str("日本語")
+                .unwrap_err()
+                .to_string(),
+            "needed 9 bytes but capacity is 8"
+        );
+
+        let mut name = Name::try_from_str("abcd").unwrap();
+        assert_eq!(
+            name.try_push_str("ef").unwrap_err().error(),
+            CharLimit::new(6, 5).into()
+        );
+        name.try_push('e').unwrap();
+        assert_eq!(
+            name.try_push('f').unwrap_err().to_string(),
+            "needed 6 chars but limit is 5"
+        );
     }
 
-    impl From<&str> for CacheString {
-        fn from(s: &str) -> Self {
-            Self(ArrayString::<CACHE_STRING_SIZE>::from(s))
-        }
+    #[test]
+    fn index_errors() {
+        use crate::{error::Index, Error};
+        use core::ops::Bound;
+
+        let mut s = ArrayString::<8>::try_from_str("abc").unwrap();
+        let err = s.remove(10).unwrap_err();
+        assert_eq!(err, Error::Index { index: 10, len: 3 });
+        assert_eq!(err.to_string(), "index 10 is out of bounds of length 3");
+        assert_eq!(
+            s.try_insert(4, 'd').unwrap_err().error(),
+            Index::new(4, 3).into()
+        );
+        assert_eq!(s.split_off(5), Err(Error::Index { index: 5, len: 3 }));
+        assert_eq!(s.drain(4..).err(), Some(Error::Index { index: 4, len: 3 }));
+        let reversed = (Bound::Included(2), Bound::Excluded(1));
+        assert_eq!(
+            s.drain(reversed).err(),
+            Some(Error::Index { index: 2, len: 1 })
+        );
+        assert_eq!(
+            s.replace_range(1..9, ""),
+            Err(Error::Index { index: 9, len: 3 })
+        );
+        assert_eq!(
+            s.replace_range(1..2, "0".repeat(8)),
+            Err(Error::OutOfBounds {
+                needed: 10,
+                capacity: 8
+            })
+        );
+        assert_eq!(s.as_str(), "abc");
     }
 
-    impl IndexMut<RangeFrom<u8>> for CacheString {
-        #[inline]
-        fn index_mut(&mut self, index: RangeFrom<u8>) -> &mut str {
-            self.0.index_mut(index)
-        }
-    }
+    #[test]
+    #[cfg(not(feature = "strict"))]
+    #[allow(clippy::unnecessary_fallible_conversions)]
+    fn blanket_try_from_truncates() {
+        use crate::policy::Reject;
+        use core::convert::Infallible;
 
-    impl IndexMut<RangeTo<u8>> for CacheString {
-        #[inline]
-        fn index_mut(&mut self, index: RangeTo<u8>) -> &mut str {
-            self.0.index_mut(index)
-        }
+        let truncated: Result<ArrayString<4>, Infallible> = ArrayString::try_from("toolong");
+        assert_eq!(truncated.unwrap(), "tool");
+        assert_eq!(
+            ArrayString::<4, Reject>::try_from("toolong"),
+            Err(OutOfBounds::new(7, 4))
+        );
     }
 
-    impl IndexMut<RangeFull> for CacheString {
-        #[inline]
-        fn index_mut(&mut self, index: RangeFull) -> &mut str {
-            self.0.index_mut(index)
-        }
+    #[test]
+    #[cfg(feature = "strict")]
+    fn strict_try_from_rejects() {
+        assert_eq!(
+            ArrayString::<4>::try_from("toolong"),
+            Err(OutOfBounds::new(7, 4))
+        );
     }
 
-    impl IndexMut<Range<u8>> for CacheString {
-        #[inline]
-        fn index_mut(&mut self, index: Range<u8>) -> &mut str {
-            self.0.index_mut(index)
-        }
+    #[test]
+    fn try_from_conversions() {
+        use crate::{policy::Reject, Error};
+        use std::borrow::Cow;
+
+        type Small = ArrayString<4>;
+        assert_eq!(Small::try_from(&b"ab\xC3\xA9"[..]).unwrap(), "abé");
+        assert_eq!(
+            Small::try_from(&b"abcde"[..]),
+            Err(Error::OutOfBounds {
+                needed: 5,
+                capacity: 4
+            })
+        );
+        assert!(matches!(
+            Small::try_from(&b"a\xFF"[..]),
+            Err(Error::Utf8 { index: 1, .. })
+        ));
+        let utf16: Vec<u16> = "ab🤔".encode_utf16().collect();
+        assert_eq!(
+            Small::try_from(&utf16[..]),
+            Err(Error::OutOfBounds {
+                needed: 6,
+                capacity: 4
+            })
+        );
+        assert_eq!(Small::try_from('🤔').unwrap(), "🤔");
+        assert_eq!(
+            ArrayString::<3>::try_from('🤔').unwrap_err().into_inner(),
+            '🤔'
+        );
+
+        let long = String::from("abcde");
+        assert_eq!(
+            Small::try_from(long.clone()).unwrap_err().into_inner(),
+            long
+        );
+        let cow = Cow::Borrowed("abcde");
+        assert_eq!(Small::try_from(cow).unwrap_err().element(), "abcde");
+        assert_eq!(CacheString::try_from(String::from("abc")).unwrap(), "abc");
+        assert_eq!(
+            ArrayString::<4, Reject>::try_from("abcde"),
+            Err(OutOfBounds::new(5, 4))
+        );
     }
 
-    impl IndexMut<RangeToInclusive<u8>> for CacheString {
-        #[inline]
-        fn index_mut(&mut self, index: RangeToInclusive<u8>) -> &mut str {
-            self.0.index_mut(index)
-        }
+    #[test]
+    fn into_chars() {
+        let string = ArrayString::<16>::try_from_str("aé🤔z").unwrap();
+        let mut chars = string.into_iter();
+        assert_eq!(chars.size_hint(), (2, Some(8)));
+        assert_eq!(chars.next_back(), Some('z'));
+        assert_eq!(chars.next(), Some('a'));
+        assert_eq!(chars.as_str(), "é🤔");
+        assert_eq!(chars.clone().rev().collect::<String>(), "🤔é");
+        assert_eq!(chars.next_back(), Some('🤔'));
+        assert_eq!(chars.next_back(), Some('é'));
+        assert_eq!(
+            (chars.next(), chars.next_back(), chars.as_str()),
+            (None, None, "")
+        );
+
+        let mut borrowed = string;
+        assert!((&string).into_iter().eq(string.chars()));
+        assert!((&mut borrowed).into_iter().eq(string.chars()));
+
+        let cache = CacheString::try_from_str("aé🤔z").unwrap();
+        assert!((&cache).into_iter().eq(cache.into_iter()));
+        let mut collected = String::new();
+        for ch in cache {
+            collected.push(ch);
+        }
+        assert_eq!(collected, "aé🤔z");
     }
 
-    impl IndexMut<RangeInclusive<u8>> for CacheString {
-        #[inline]
-        fn index_mut(&mut self, index: RangeInclusive<u8>) -> &mut str {
-            self.0.index_mut(index)
-        }
+    #[test]
+    // Dropping the rest of owned items isn't always proven panic free across codegen units
+    #[cfg(not(feature = "no-panic"))]
+    fn try_extend() {
+        use std::borrow::Cow;
+
+        let mut s = ArrayString::<8>::new();
+        s.try_extend(&['a', 'b']).unwrap();
+        s.try_extend([String::from("c"), String::from("d")])
+            .unwrap();
+        let err = s.try_extend("é🤔".chars()).unwrap_err();
+        assert_eq!(err.error(), OutOfBounds::new(10, 8));
+        assert_eq!(err.into_inner().collect::<String>(), "🤔");
+        assert_eq!(s, "abcdé");
+        assert!(s.is_tail_zeroed());
+
+        let words = [Cow::Borrowed("x"), Cow::Owned(String::from("yz"))];
+        let err = s.try_extend(words.clone()).unwrap_err();
+        assert_eq!(err.error(), OutOfBounds::new(9, 8));
+        assert_eq!(err.into_inner().collect::<Vec<_>>(), &words[1..]);
+        assert_eq!(s, "abcdéx");
+
+        let mut cache = CacheString::new();
+        cache.try_extend(["ab", "cd"]).unwrap();
+        assert_eq!(cache, "abcd");
     }
 
-    impl Index<RangeFrom<u8>> for CacheString {
-        type Output = str;
-
-        #[inline]
-        fn index(&self, index: RangeFrom<u8>) -> &Self::Output {
-            self.0.index(index)
-        }
+    #[test]
+    fn number_formatting() {
+        let unsigned = [0, 9, 10, 99, 100, 101, 999, 1000, 12345678, u64::MAX];
+        for n in unsigned {
+            assert_eq!(ArrayString::<20>::try_from_u64(n).unwrap(), n.to_string());
+            let signed = n as i64;
+            assert_eq!(
+                ArrayString::<20>::try_from_i64(signed).unwrap(),
+                signed.to_string()
+            );
+            let wide = u128::from(n) * u128::from(n) + 7;
+            assert_eq!(
+                ArrayString::<39>::try_from_u128(wide).unwrap(),
+                wide.to_string()
+            );
+            assert_eq!(
+                ArrayString::<64>::try_from_u64_radix::<2>(n).unwrap(),
+                format!("{n:b}")
+            );
+            assert_eq!(
+                ArrayString::<32>::try_from_u64_radix::<8>(n).unwrap(),
+                format!("{n:o}")
+            );
+            assert_eq!(
+                ArrayString::<16>::try_from_u64_radix::<16>(n).unwrap(),
+                format!("{n:x}")
+            );
+            for width in [0, 1, 3, 21] {
+                let padded = ArrayString::<32>::try_from_u64_padded(n, width).unwrap();
+                assert_eq!(padded, format!("{n:0width$}"));
+                let padded = ArrayString::<32>::try_from_i64_padded(-signed, width).unwrap();
+                assert_eq!(padded, format!("{:0width$}", -signed));
+            }
+        }
+        assert_eq!(
+            ArrayString::<39>::try_from_u128(u128::MAX).unwrap(),
+            u128::MAX.to_string()
+        );
+        assert_eq!(
+            ArrayString::<20>::try_from_i64(i64::MIN).unwrap(),
+            i64::MIN.to_string()
+        );
+        assert_eq!(
+            ArrayString::<16>::try_from_u64_radix::<36>(35).unwrap(),
+            "z"
+        );
+
+        let mut s = ArrayString::<4>::try_from_str("ab").unwrap();
+        assert_eq!(s.try_push_i64(-10), Err(OutOfBounds::new(5, 4)));
+        assert_eq!(
+            s.try_push_u64_padded(1, usize::MAX),
+            Err(OutOfBounds::new(usize::MAX, 4))
+        );
+        assert_eq!(s, "ab");
+        assert!(s.is_tail_zeroed());
     }
 
-    impl Index<RangeTo<u8>> for CacheString {
-        type Output = str;
+    #[test]
+    fn number_pushes() {
+        let mut s = ArrayString::<64>::try_from_str("n=").unwrap();
+        s.try_push_u64(u64::MAX).unwrap();
+        s.try_push(',').unwrap();
+        s.try_push_u128(u128::MAX).unwrap();
+        assert_eq!(s.as_str(), format!("n={},{}", u64::MAX, u128::MAX));
+        assert_eq!(s.try_push_u64(100), Err(OutOfBounds::new(65, 64)));
+        assert_eq!(s.try_push_u128(100), Err(OutOfBounds::new(65, 64)));
 
-        #[inline]
-        fn index(&self, index: RangeTo<u8>) -> &Self::Output {
-            self.0.index(index)
+        #[cfg(feature = "ryu")]
+        {
+            assert_eq!(ArrayString::<4>::try_from_f64(0.5).unwrap().as_str(), "0.5");
+            assert_eq!(
+                ArrayString::<2>::try_from_f64(0.5),
+                Err(OutOfBounds::new(3, 2))
+            );
         }
     }
 
-    impl Index<RangeFull> for CacheString {
-        type Output = str;
-
-        #[inline]
-        fn index(&self, index: RangeFull) -> &Self::Output {
-            self.0.index(index)
-        }
-    }
+    #[test]
+    fn marker_in_text_is_not_a_cut() {
+        use crate::policy::TruncateWithMarker;
 
-    impl Index<Range<u8>> for CacheString {
-        type Output = str;
+        let mut s = ArrayString::<16, TruncateWithMarker>::try_from_str("Wait…").unwrap();
+        assert_eq!(s.push_str("!"), Ok(()));
+        assert_eq!(s, "Wait…!");
+        assert_eq!(s.push_str(" more text"), Err(OutOfBounds::new(18, 16)));
+        assert_eq!(s, "Wait…! more…");
 
-        #[inline]
-        fn index(&self, index: Range<u8>) -> &Self::Output {
-            self.0.index(index)
-        }
+        let mut cut = ArrayString::<8, TruncateWithMarker>::try_from_str("ab…").unwrap();
+        assert_eq!(cut.push_str("cdefgh"), Err(OutOfBounds::new(11, 8)));
+        assert_eq!(cut, "ab…");
     }
 
-    impl Index<RangeToInclusive<u8>> for CacheString {
-        type Output = str;
-
-        #[inline]
-        fn index(&self, index: RangeToInclusive<u8>) -> &Self::Output {
-            self.0.index(index)
-        }
+    #[test]
+    fn cmp_trailing_nul() {
+        let a = ArrayString::<8>::try_from_str("a").unwrap();
+        let b = ArrayString::<8>::try_from_str("a\0").unwrap();
+        let c = ArrayString::<8>::try_from_str("a\0b").unwrap();
+        assert_ne!(a, b);
+        assert!(a < b && b < c);
+        assert_eq!(a.cmp(&b), a.as_str().cmp(b.as_str()));
+        assert_ne!(FastHash(a), FastHash(b));
     }
 
-    impl Index<RangeInclusive<u8>> for CacheString {
-        type Output = str;
-
-        #[inline]
-        fn index(&self, index: RangeInclusive<u8>) -> &Self::Output {
-            self.0.index(index)
-        }
+    #[test]
+    fn usize_index() {
+        let mut s = ArrayString::<16>::try_from_str("ab🤔cd").unwrap();
+        let idx = s.find('c').unwrap();
+        assert_eq!(&s[..idx], "ab🤔");
+        assert_eq!(&s[idx..=idx], "c");
+        assert_eq!(&s[..], "ab🤔cd");
+        s[idx..].make_ascii_uppercase();
+        assert_eq!(s, "ab🤔CD");
+        assert_eq!(s.get(idx..20), None);
     }
-}
-pub use cache_string::*;
 
-#[cfg(test)]
-mod tests {
     #[test]
-    fn size_of_cache() {
-        assert_eq!(core::mem::size_of::<super::CacheString>(), 64);
+    fn cross_type_cmp() {
+        use std::{borrow::Cow, cmp::Ordering};
+
+        let small = ArrayString::<8>::try_from_str("abc").unwrap();
+        let big = ArrayString::<64>::try_from_str("abc").unwrap();
+        let cache = CacheString::try_from_str("abd").unwrap();
+        assert_eq!(small, big);
+        assert_eq!(big, small);
+        assert!(small < cache && cache > big);
+        assert!(cache != small && big != cache);
+        assert_eq!(small.partial_cmp(&big), Some(Ordering::Equal));
+        assert_eq!(big.partial_cmp(&small), Some(Ordering::Equal));
+
+        let with_nul = ArrayString::<64>::try_from_str("abc\0").unwrap();
+        assert_ne!(with_nul, small);
+        assert!(small < with_nul);
+        assert!(with_nul > small);
+
+        let string = String::from("abc");
+        let cow = Cow::Borrowed("abc");
+        let boxed: Box<str> = "abc".into();
+        assert_eq!(small, "abc");
+        assert_eq!("abc", small);
+        assert_eq!(small, *"abc");
+        assert_eq!(*"abc", small);
+        assert_eq!(small, string);
+        assert_eq!(string, small);
+        assert_eq!(small, cow);
+        assert_eq!(cow, small);
+        assert_eq!(small, boxed);
+        assert_eq!(boxed, small);
+        assert_eq!("abd", cache);
+        assert!(cache > string);
+        assert!(string < cache);
+        assert!(cache > cow);
+        assert!(cow < cache);
+        assert!(cache > boxed);
+        assert!(boxed < cache);
+        assert!("abb" < cache);
+        assert!(cache > *"abb");
     }
 }
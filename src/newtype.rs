@@ -0,0 +1,702 @@
+//! Integrations generated by [`ArrayStringNewtype`], each macro has an empty version when its feature is disabled
+//!
+//! They are called with `plain` or `validated`, validated newtypes only get the read-only traits and check the rule when decoding
+//!
+//! [`ArrayStringNewtype`]: ../derive.ArrayStringNewtype.html
+
+/// Dependencies used by the generated code, so downstream crates don't need to depend on them
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::arraystring::sealed::ValidCapacity;
+    #[cfg(feature = "alloc")]
+    pub use alloc::{borrow::Cow, boxed::Box, string::String};
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+    #[cfg(feature = "diesel-traits")]
+    pub use diesel;
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+    #[cfg(feature = "quickcheck")]
+    pub use quickcheck;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+    #[cfg(feature = "serde-traits")]
+    pub use serde;
+    #[cfg(feature = "subtle")]
+    pub use subtle;
+    #[cfg(feature = "ufmt")]
+    pub use ufmt;
+    #[cfg(feature = "zeroize")]
+    pub use zeroize;
+}
+
+/// Comparisons with every string type, in both directions
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_cmp {
+    (@str $name:ident, $other:ty) => {
+        impl ::core::cmp::PartialEq<$other> for $name {
+            #[inline]
+            fn eq(&self, other: &$other) -> bool {
+                self.0.as_str() == &other[..]
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$name> for $other {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                &self[..] == other.0.as_str()
+            }
+        }
+
+        impl ::core::cmp::PartialOrd<$other> for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &$other) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.0.as_str().partial_cmp(&other[..])
+            }
+        }
+
+        impl ::core::cmp::PartialOrd<$name> for $other {
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> ::core::option::Option<::core::cmp::Ordering> {
+                self[..].partial_cmp(other.0.as_str())
+            }
+        }
+    };
+    ($mode:ident, $name:ident, $inner:ty) => {
+        $crate::__newtype_cmp!(@str $name, str);
+        $crate::__newtype_cmp!(@str $name, &str);
+        $crate::__newtype_alloc_cmp!($name);
+
+        impl<const M: usize> ::core::cmp::PartialEq<$crate::ArrayString<M>> for $name
+        where
+            $crate::ArrayString<M>: $crate::__private::ValidCapacity,
+        {
+            #[inline]
+            fn eq(&self, other: &$crate::ArrayString<M>) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<const M: usize> ::core::cmp::PartialEq<$name> for $crate::ArrayString<M>
+        where
+            $crate::ArrayString<M>: $crate::__private::ValidCapacity,
+        {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl<const M: usize> ::core::cmp::PartialOrd<$crate::ArrayString<M>> for $name
+        where
+            $crate::ArrayString<M>: $crate::__private::ValidCapacity,
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &$crate::ArrayString<M>) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+
+        impl<const M: usize> ::core::cmp::PartialOrd<$name> for $crate::ArrayString<M>
+        where
+            $crate::ArrayString<M>: $crate::__private::ValidCapacity,
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.partial_cmp(&other.0)
+            }
+        }
+    };
+}
+
+/// Validated newtypes only get `Index`
+#[cfg(not(feature = "no-panicking-index"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_index {
+    (plain, $name:ident, $inner:ty) => {
+        $crate::__newtype_index!(validated, $name, $inner);
+
+        impl<I: ::core::slice::SliceIndex<str>> ::core::ops::IndexMut<I> for $name {
+            #[inline]
+            fn index_mut(&mut self, index: I) -> &mut I::Output {
+                ::core::ops::IndexMut::index_mut(&mut self.0, index)
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {
+        impl<I: ::core::slice::SliceIndex<str>> ::core::ops::Index<I> for $name {
+            type Output = I::Output;
+
+            #[inline]
+            fn index(&self, index: I) -> &Self::Output {
+                ::core::ops::Index::index(&self.0, index)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "no-panicking-index")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_index {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_alloc_cmp {
+    ($name:ident) => {
+        $crate::__newtype_cmp!(@str $name, $crate::__private::String);
+        $crate::__newtype_cmp!(@str $name, $crate::__private::Cow<'_, str>);
+        $crate::__newtype_cmp!(@str $name, $crate::__private::Box<str>);
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_alloc_cmp {
+    ($($tt:tt)*) => {};
+}
+
+/// Conversions that truncate, the `strict` feature replaces them with `TryFrom<&str>`
+#[cfg(not(feature = "strict"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_lossy {
+    (plain, $name:ident, $inner:ty) => {
+        impl ::core::convert::From<&str> for $name {
+            #[inline]
+            fn from(s: &str) -> Self {
+                Self(<$inner>::from(s))
+            }
+        }
+
+        impl ::core::iter::FromIterator<char> for $name {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = char>>(iter: I) -> Self {
+                Self(<$inner as ::core::iter::FromIterator<char>>::from_iter(
+                    iter,
+                ))
+            }
+        }
+
+        impl<'a> ::core::iter::FromIterator<&'a str> for $name {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = &'a str>>(iter: I) -> Self {
+                Self(<$inner as ::core::iter::FromIterator<&'a str>>::from_iter(
+                    iter,
+                ))
+            }
+        }
+
+        impl ::core::iter::Extend<char> for $name {
+            fn extend<I: ::core::iter::IntoIterator<Item = char>>(&mut self, iter: I) {
+                self.0.extend(iter);
+            }
+        }
+
+        impl<'a> ::core::iter::Extend<&'a char> for $name {
+            fn extend<I: ::core::iter::IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+                self.0.extend(iter);
+            }
+        }
+
+        impl<'a> ::core::iter::Extend<&'a str> for $name {
+            fn extend<I: ::core::iter::IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+                self.0.extend(iter);
+            }
+        }
+
+        impl ::core::ops::Add<&str> for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: &str) -> Self::Output {
+                Self(self.0 + other)
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {};
+}
+
+#[cfg(feature = "strict")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_lossy {
+    (plain, $name:ident, $inner:ty) => {
+        impl ::core::convert::TryFrom<&str> for $name {
+            type Error = $crate::error::OutOfBounds;
+
+            #[inline]
+            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_from_str(s)
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {};
+}
+
+/// Conversions from owned strings, they hand back the input if it doesn't fit
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_alloc_try_from {
+    (plain, $name:ident, $inner:ty) => {
+        impl ::core::convert::TryFrom<$crate::__private::String> for $name {
+            type Error = $crate::error::CapacityError<$crate::__private::String>;
+
+            #[inline]
+            fn try_from(s: $crate::__private::String) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self(<$inner>::try_from(s)?))
+            }
+        }
+
+        impl<'a> ::core::convert::TryFrom<$crate::__private::Cow<'a, str>> for $name {
+            type Error = $crate::error::CapacityError<$crate::__private::Cow<'a, str>>;
+
+            #[inline]
+            fn try_from(
+                s: $crate::__private::Cow<'a, str>,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self(<$inner>::try_from(s)?))
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {};
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_alloc_try_from {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "serde-traits")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_serde {
+    (plain, $name:ident, $inner:ty) => {
+        $crate::__newtype_serde!(serialize, $name, $inner);
+
+        impl<'a> $crate::__private::serde::Deserialize<'a> for $name {
+            #[inline]
+            fn deserialize<D: $crate::__private::serde::Deserializer<'a>>(
+                des: D,
+            ) -> ::core::result::Result<Self, D::Error> {
+                ::core::result::Result::Ok(Self(
+                    <$inner as $crate::__private::serde::Deserialize<'a>>::deserialize(des)?,
+                ))
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {
+        $crate::__newtype_serde!(serialize, $name, $inner);
+
+        impl<'a> $crate::__private::serde::Deserialize<'a> for $name {
+            #[inline]
+            fn deserialize<D: $crate::__private::serde::Deserializer<'a>>(
+                des: D,
+            ) -> ::core::result::Result<Self, D::Error> {
+                let string = <&str as $crate::__private::serde::Deserialize<'a>>::deserialize(des)?;
+                Self::try_from_str(string)
+                    .map_err(<D::Error as $crate::__private::serde::de::Error>::custom)
+            }
+        }
+    };
+    (serialize, $name:ident, $inner:ty) => {
+        impl $crate::__private::serde::Serialize for $name {
+            #[inline]
+            fn serialize<S: $crate::__private::serde::Serializer>(
+                &self,
+                ser: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::__private::serde::Serialize::serialize(self.0.as_str(), ser)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde-traits"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_serde {
+    ($($tt:tt)*) => {};
+}
+
+/// `AsExpression` and `FromSqlRow` can't be forwarded, derive them in the newtype
+#[cfg(feature = "diesel-traits")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_diesel {
+    (plain, $name:ident, $inner:ty) => {
+        $crate::__newtype_diesel!(to_sql, $name, $inner);
+
+        impl<ST, DB> $crate::__private::diesel::deserialize::FromSql<ST, DB> for $name
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            *const str: $crate::__private::diesel::deserialize::FromSql<ST, DB>,
+        {
+            #[inline]
+            fn from_sql(
+                bytes: $crate::__private::diesel::backend::RawValue<'_, DB>,
+            ) -> $crate::__private::diesel::deserialize::Result<Self> {
+                ::core::result::Result::Ok(Self(
+                    <$inner as $crate::__private::diesel::deserialize::FromSql<ST, DB>>::from_sql(
+                        bytes,
+                    )?,
+                ))
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {
+        $crate::__newtype_diesel!(to_sql, $name, $inner);
+
+        impl<ST, DB> $crate::__private::diesel::deserialize::FromSql<ST, DB> for $name
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            *const str: $crate::__private::diesel::deserialize::FromSql<ST, DB>,
+        {
+            #[inline]
+            fn from_sql(
+                bytes: $crate::__private::diesel::backend::RawValue<'_, DB>,
+            ) -> $crate::__private::diesel::deserialize::Result<Self> {
+                let ptr = <*const str as $crate::__private::diesel::deserialize::FromSql<
+                    ST,
+                    DB,
+                >>::from_sql(bytes)?;
+                // Safety: We know that the pointer impl will never return null. We copied diesel's implementation for String
+                ::core::debug_assert!(!ptr.is_null());
+                ::core::result::Result::Ok(Self::try_from_str(unsafe { &*ptr })?)
+            }
+        }
+    };
+    (to_sql, $name:ident, $inner:ty) => {
+        impl<DB>
+            $crate::__private::diesel::serialize::ToSql<
+                $crate::__private::diesel::sql_types::Text,
+                DB,
+            > for $name
+        where
+            DB: $crate::__private::diesel::backend::Backend,
+            str: $crate::__private::diesel::serialize::ToSql<
+                $crate::__private::diesel::sql_types::Text,
+                DB,
+            >,
+        {
+            #[inline]
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut $crate::__private::diesel::serialize::Output<'b, '_, DB>,
+            ) -> $crate::__private::diesel::serialize::Result {
+                $crate::__private::diesel::serialize::ToSql::<
+                    $crate::__private::diesel::sql_types::Text,
+                    DB,
+                >::to_sql(self.0.as_str(), out)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "diesel-traits"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_diesel {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_arbitrary {
+    (plain, $name:ident, $inner:ty) => {
+        impl<'a> $crate::__private::arbitrary::Arbitrary<'a> for $name {
+            #[inline]
+            fn arbitrary(
+                u: &mut $crate::__private::arbitrary::Unstructured<'a>,
+            ) -> $crate::__private::arbitrary::Result<Self> {
+                ::core::result::Result::Ok(Self(
+                    <$inner as $crate::__private::arbitrary::Arbitrary<'a>>::arbitrary(u)?,
+                ))
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {};
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_arbitrary {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_proptest {
+    (plain, $name:ident, $inner:ty) => {
+        impl $crate::__private::proptest::arbitrary::Arbitrary for $name {
+            type Parameters = ();
+            type Strategy = $crate::__private::proptest::strategy::BoxedStrategy<Self>;
+
+            #[inline]
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                $crate::__private::proptest::strategy::Strategy::boxed(
+                    $crate::__private::proptest::strategy::Strategy::prop_map(
+                        $crate::__private::proptest::arbitrary::any::<$inner>(),
+                        Self,
+                    ),
+                )
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {};
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_proptest {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_quickcheck {
+    (plain, $name:ident, $inner:ty) => {
+        impl $crate::__private::quickcheck::Arbitrary for $name {
+            #[inline]
+            fn arbitrary(g: &mut $crate::__private::quickcheck::Gen) -> Self {
+                Self(<$inner as $crate::__private::quickcheck::Arbitrary>::arbitrary(g))
+            }
+
+            #[inline]
+            fn shrink(&self) -> $crate::__private::Box<dyn ::core::iter::Iterator<Item = Self>> {
+                let shrink = <$inner as $crate::__private::quickcheck::Arbitrary>::shrink(&self.0);
+                $crate::__private::Box::new(::core::iter::Iterator::map(shrink, Self))
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {};
+}
+
+#[cfg(not(feature = "quickcheck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_quickcheck {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "schemars")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_schemars {
+    ($mode:ident, $name:ident, $inner:ty) => {
+        impl $crate::__private::schemars::JsonSchema for $name {
+            #[inline]
+            fn inline_schema() -> bool {
+                true
+            }
+
+            #[inline]
+            fn schema_name() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::stringify!($name))
+            }
+
+            #[inline]
+            fn schema_id() -> $crate::__private::Cow<'static, str> {
+                $crate::__private::Cow::Borrowed(::core::concat!(
+                    ::core::module_path!(),
+                    "::",
+                    ::core::stringify!($name)
+                ))
+            }
+
+            #[inline]
+            fn json_schema(
+                generator: &mut $crate::__private::schemars::SchemaGenerator,
+            ) -> $crate::__private::schemars::Schema {
+                <$inner as $crate::__private::schemars::JsonSchema>::json_schema(generator)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "schemars"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_schemars {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_defmt {
+    ($mode:ident, $name:ident, $inner:ty) => {
+        impl $crate::__private::defmt::Format for $name {
+            #[inline]
+            fn format(&self, f: $crate::__private::defmt::Formatter<'_>) {
+                <$inner as $crate::__private::defmt::Format>::format(&self.0, f);
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "defmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_defmt {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "ufmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_ufmt {
+    (plain, $name:ident, $inner:ty) => {
+        $crate::__newtype_ufmt!(display, $name, $inner);
+
+        impl $crate::__private::ufmt::uWrite for $name {
+            type Error = $crate::error::OutOfBounds;
+
+            #[inline]
+            fn write_str(&mut self, slice: &str) -> ::core::result::Result<(), Self::Error> {
+                self.0.try_push_str(slice).map_err(|err| err.error())
+            }
+
+            #[inline]
+            fn write_char(&mut self, ch: char) -> ::core::result::Result<(), Self::Error> {
+                self.0.try_push(ch).map_err(|err| err.error())
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {
+        $crate::__newtype_ufmt!(display, $name, $inner);
+    };
+    (display, $name:ident, $inner:ty) => {
+        impl $crate::__private::ufmt::uDisplay for $name {
+            #[inline]
+            fn fmt<W: $crate::__private::ufmt::uWrite + ?Sized>(
+                &self,
+                f: &mut $crate::__private::ufmt::Formatter<'_, W>,
+            ) -> ::core::result::Result<(), W::Error> {
+                f.write_str(self.0.as_str())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "ufmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_ufmt {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "zeroize")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_zeroize {
+    (plain, $name:ident, $inner:ty) => {
+        impl $crate::__private::zeroize::Zeroize for $name {
+            #[inline]
+            fn zeroize(&mut self) {
+                <$inner as $crate::__private::zeroize::Zeroize>::zeroize(&mut self.0);
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {};
+}
+
+#[cfg(not(feature = "zeroize"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_zeroize {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "subtle")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_subtle {
+    ($mode:ident, $name:ident, $inner:ty) => {
+        impl $crate::__private::subtle::ConstantTimeEq for $name {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> $crate::__private::subtle::Choice {
+                <$inner as $crate::__private::subtle::ConstantTimeEq>::ct_eq(&self.0, &other.0)
+            }
+        }
+
+        impl $name {
+            /// Compares with external string slice in constant time, for tokens and digests
+            #[inline]
+            pub fn ct_eq_str(&self, other: &str) -> $crate::__private::subtle::Choice {
+                self.0.ct_eq_str(other)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "subtle"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_subtle {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "unicode-segmentation")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_unicode_segmentation {
+    (plain, $name:ident, $inner:ty) => {
+        impl $name {
+            /// Creates new string from string slice truncating size if bigger than capacity, without splitting grapheme clusters
+            #[inline]
+            pub fn from_str_truncate_graphemes(string: impl ::core::convert::AsRef<str>) -> Self {
+                Self(<$inner>::from_str_truncate_graphemes(string))
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {};
+}
+
+#[cfg(not(feature = "unicode-segmentation"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_unicode_segmentation {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "unicode-width")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_unicode_width {
+    (plain, $name:ident, $inner:ty) => {
+        impl $name {
+            /// Creates new string from string slice that fits in `max_cols` terminal columns and in capacity bytes, ending with `ellipsis` if it had to be cut
+            #[inline]
+            pub fn from_str_fit_width(
+                string: impl ::core::convert::AsRef<str>,
+                max_cols: usize,
+                ellipsis: &str,
+            ) -> Self {
+                Self(<$inner>::from_str_fit_width(string, max_cols, ellipsis))
+            }
+        }
+    };
+    (validated, $name:ident, $inner:ty) => {};
+}
+
+#[cfg(not(feature = "unicode-width"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_unicode_width {
+    ($($tt:tt)*) => {};
+}
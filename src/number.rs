@@ -0,0 +1,449 @@
+//! Number formatting for [`ArrayString`], without `core::fmt`
+//!
+//! [`ArrayString`]: ./struct.ArrayString.html
+
+use crate::{arraystring::sealed::ValidCapacity, prelude::*, utils::is_inside_boundary};
+use core::str;
+#[cfg(feature = "logs")]
+use log::trace;
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// Every pair of decimal digits, so each division by 100 writes two digits at once
+const PAIRS: &[u8; 200] = b"\
+    0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+/// Digits of every supported radix
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Biggest power of 10 that fits in `u64`, `u128` is formatted in chunks of 19 digits
+const TEN_POW_19: u64 = 10_000_000_000_000_000_000;
+
+/// Checks at compile time that the radix `R` is between 2 and 36
+struct Radix<const R: u32>;
+
+impl<const R: u32> Radix<R> {
+    /// `R` as divisor, fails to compile if it's not a supported radix
+    const DIVISOR: u64 = {
+        assert!(2 <= R && R <= 36, "radix must be between 2 and 36");
+        R as u64
+    };
+}
+
+/// Digits written back to front in a stack buffer, big enough for `u64::MAX` in base 2
+struct Digits {
+    /// Only `start..` holds digits
+    buf: [u8; 64],
+    /// Index of the most significant digit
+    start: usize,
+}
+
+impl Digits {
+    /// Formats `n` in base 10
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn decimal(n: u64) -> Self {
+        let mut digits = Self::empty();
+        digits.push_decimal(n);
+        digits
+    }
+
+    /// Formats `n` in base 10, a chunk of 19 digits at a time while it doesn't fit in `u64`
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn decimal_u128(mut n: u128) -> Self {
+        let mut digits = Self::empty();
+        loop {
+            match u64::try_from(n) {
+                Ok(n) => {
+                    digits.push_decimal(n);
+                    return digits;
+                }
+                Err(_) => {
+                    let end = digits.start;
+                    digits.push_decimal((n % u128::from(TEN_POW_19)) as u64);
+                    n /= u128::from(TEN_POW_19);
+                    for _ in end - digits.start..19 {
+                        digits.push_front(b"0");
+                    }
+                }
+            }
+        }
+    }
+
+    /// Formats `n` in base `R`, with lowercase letters after 9
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn radix<const R: u32>(mut n: u64) -> Self {
+        let mut digits = Self::empty();
+        loop {
+            let digit = (n % Radix::<R>::DIVISOR) as usize;
+            digits.push_front(DIGITS.get(digit..=digit).unwrap_or_default());
+            n /= Radix::<R>::DIVISOR;
+            if n == 0 {
+                return digits;
+            }
+        }
+    }
+
+    /// Creates buffer without digits
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn empty() -> Self {
+        Self {
+            buf: [0; 64],
+            start: 64,
+        }
+    }
+
+    /// Writes `n` in base 10 before the current digits, two at a time
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn push_decimal(&mut self, mut n: u64) {
+        while n >= 100 {
+            self.push_pair((n % 100) as usize);
+            n /= 100;
+        }
+        if n >= 10 {
+            self.push_pair(n as usize);
+        } else {
+            self.push_front(&[b'0' + n as u8]);
+        }
+    }
+
+    /// Writes the two digits of `n`, that must be smaller than 100, before the current digits
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn push_pair(&mut self, n: usize) {
+        self.push_front(PAIRS.get(n * 2..n * 2 + 2).unwrap_or_default());
+    }
+
+    /// Writes `digits` before the current digits, ignoring them if the buffer is full
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn push_front(&mut self, digits: &[u8]) {
+        let Some(start) = self.start.checked_sub(digits.len()) else {
+            return;
+        };
+        if let Some(slot) = self.buf.get_mut(start..self.start) {
+            slot.iter_mut()
+                .zip(digits)
+                .for_each(|(slot, digit)| *slot = *digit);
+            self.start = start;
+        }
+    }
+
+    /// Extracts string slice with the digits
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn as_str(&self) -> &str {
+        let digits = self.buf.get(self.start..).unwrap_or_default();
+        // Safety: only ascii digits and letters are written to the buffer
+        unsafe { str::from_utf8_unchecked(digits) }
+    }
+}
+
+impl<const N: usize, P> ArrayString<N, P>
+where
+    Self: ValidCapacity,
+{
+    /// Creates new `ArrayString` from `u64` in base 10 if it fits in [`capacity`], otherwise returns an error.
+    ///
+    /// Doesn't go through `core::fmt`, like the other number formatting methods
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(ArrayString::<20>::try_from_u64(u64::MAX)?, "18446744073709551615");
+    /// assert_eq!(ArrayString::<3>::try_from_u64(1000), Err(OutOfBounds::new(4, 3)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_u64(n: u64) -> Result<Self, OutOfBounds> {
+        let mut s = Self::new();
+        s.try_push_u64(n)?;
+        Ok(s)
+    }
+
+    /// Creates new `ArrayString` from `i64` in base 10 if it fits in [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(ArrayString::<20>::try_from_i64(i64::MIN)?, "-9223372036854775808");
+    /// assert_eq!(ArrayString::<3>::try_from_i64(-100), Err(OutOfBounds::new(4, 3)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_i64(n: i64) -> Result<Self, OutOfBounds> {
+        let mut s = Self::new();
+        s.try_push_i64(n)?;
+        Ok(s)
+    }
+
+    /// Creates new `ArrayString` from `u128` in base 10 if it fits in [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let max = ArrayString::<39>::try_from_u128(u128::MAX)?;
+    /// assert_eq!(max, "340282366920938463463374607431768211455");
+    /// assert_eq!(ArrayString::<39>::try_from_u128(10u128.pow(20))?, "100000000000000000000");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_u128(n: u128) -> Result<Self, OutOfBounds> {
+        let mut s = Self::new();
+        s.try_push_u128(n)?;
+        Ok(s)
+    }
+
+    /// Creates new `ArrayString` from `u64` in base `R` if it fits in [`capacity`], otherwise returns an error.
+    ///
+    /// Letters after 9 are lowercase, a radix outside of `2..=36` fails to compile
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(ArrayString::<8>::try_from_u64_radix::<16>(0xc0ffee)?, "c0ffee");
+    /// assert_eq!(ArrayString::<8>::try_from_u64_radix::<2>(0b101)?, "101");
+    /// assert_eq!(ArrayString::<4>::try_from_u64_radix::<8>(0o12345), Err(OutOfBounds::new(5, 4)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// An unsupported radix fails to compile:
+    ///
+    /// ```rust,compile_fail
+    /// # use arraystring::prelude::*;
+    /// let _ = ArrayString::<8>::try_from_u64_radix::<37>(36);
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_u64_radix<const R: u32>(n: u64) -> Result<Self, OutOfBounds> {
+        let mut s = Self::new();
+        s.try_push_u64_radix::<R>(n)?;
+        Ok(s)
+    }
+
+    /// Creates new `ArrayString` from `u64` in base 10, with leading zeros up to `width` digits, if it fits in [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(ArrayString::<8>::try_from_u64_padded(42, 6)?, "000042");
+    /// assert_eq!(ArrayString::<8>::try_from_u64_padded(1234, 2)?, "1234");
+    /// assert_eq!(ArrayString::<8>::try_from_u64_padded(7, 9), Err(OutOfBounds::new(9, 8)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_u64_padded(n: u64, width: usize) -> Result<Self, OutOfBounds> {
+        let mut s = Self::new();
+        s.try_push_u64_padded(n, width)?;
+        Ok(s)
+    }
+
+    /// Creates new `ArrayString` from `i64` in base 10, with zeros between the sign and the digits up to `width` bytes, if it fits in [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(ArrayString::<8>::try_from_i64_padded(-42, 5)?, "-0042");
+    /// assert_eq!(ArrayString::<8>::try_from_i64_padded(42, 5)?, "00042");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_i64_padded(n: i64, width: usize) -> Result<Self, OutOfBounds> {
+        let mut s = Self::new();
+        s.try_push_i64_padded(n, width)?;
+        Ok(s)
+    }
+
+    /// Pushes `u64` in base 10 to the end of the `ArrayString` if it fits in [`capacity`], otherwise returns an error and leaves it untouched.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<8>::try_from_str("id-")?;
+    /// s.try_push_u64(1024)?;
+    /// assert_eq!(s, "id-1024");
+    /// assert_eq!(s.try_push_u64(10), Err(OutOfBounds::new(9, 8)));
+    /// assert_eq!(s, "id-1024");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_u64(&mut self, n: u64) -> Result<(), OutOfBounds> {
+        trace!("Push u64: {n}");
+        self.try_push_number(false, &Digits::decimal(n), 0)
+    }
+
+    /// Pushes `i64` in base 10 to the end of the `ArrayString` if it fits in [`capacity`], otherwise returns an error and leaves it untouched.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<8>::try_from_str("t=")?;
+    /// s.try_push_i64(-40)?;
+    /// assert_eq!(s, "t=-40");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_i64(&mut self, n: i64) -> Result<(), OutOfBounds> {
+        trace!("Push i64: {n}");
+        self.try_push_number(n < 0, &Digits::decimal(n.unsigned_abs()), 0)
+    }
+
+    /// Pushes `u128` in base 10 to the end of the `ArrayString` if it fits in [`capacity`], otherwise returns an error and leaves it untouched.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<32>::try_from_str("#")?;
+    /// s.try_push_u128(u128::from(u64::MAX) + 1)?;
+    /// assert_eq!(s, "#18446744073709551616");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_u128(&mut self, n: u128) -> Result<(), OutOfBounds> {
+        trace!("Push u128: {n}");
+        self.try_push_number(false, &Digits::decimal_u128(n), 0)
+    }
+
+    /// Pushes `u64` in base `R` to the end of the `ArrayString` if it fits in [`capacity`], otherwise returns an error and leaves it untouched.
+    ///
+    /// Letters after 9 are lowercase, a radix outside of `2..=36` fails to compile
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<8>::try_from_str("0x")?;
+    /// s.try_push_u64_radix::<16>(255)?;
+    /// assert_eq!(s, "0xff");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_u64_radix<const R: u32>(&mut self, n: u64) -> Result<(), OutOfBounds> {
+        trace!("Push u64 in radix {R}: {n}");
+        self.try_push_number(false, &Digits::radix::<R>(n), 0)
+    }
+
+    /// Pushes `u64` in base 10, with leading zeros up to `width` digits, to the end of the `ArrayString` if it fits in [`capacity`], otherwise returns an error and leaves it untouched.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<8>::try_from_u64(12)?;
+    /// s.try_push_str(":")?;
+    /// s.try_push_u64_padded(5, 2)?;
+    /// assert_eq!(s, "12:05");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_u64_padded(&mut self, n: u64, width: usize) -> Result<(), OutOfBounds> {
+        trace!("Push u64 padded to {width}: {n}");
+        self.try_push_number(false, &Digits::decimal(n), width)
+    }
+
+    /// Pushes `i64` in base 10, with zeros between the sign and the digits up to `width` bytes, to the end of the `ArrayString` if it fits in [`capacity`], otherwise returns an error and leaves it untouched.
+    ///
+    /// [`capacity`]: ./struct.ArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # fn main() -> Result<(), OutOfBounds> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut s = ArrayString::<8>::try_from_str("x")?;
+    /// s.try_push_i64_padded(-7, 4)?;
+    /// assert_eq!(s, "x-007");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_i64_padded(&mut self, n: i64, width: usize) -> Result<(), OutOfBounds> {
+        trace!("Push i64 padded to {width}: {n}");
+        self.try_push_number(n < 0, &Digits::decimal(n.unsigned_abs()), width)
+    }
+
+    /// Pushes the sign, the zeros up to `width` bytes and the digits, or nothing if they don't fit together
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn try_push_number(
+        &mut self,
+        negative: bool,
+        digits: &Digits,
+        width: usize,
+    ) -> Result<(), OutOfBounds> {
+        let sign = if negative { "-" } else { "" };
+        let digits = digits.as_str();
+        let unpadded = sign.len() + digits.len();
+        let zeros = width.saturating_sub(unpadded);
+        is_inside_boundary(
+            self.len().saturating_add(unpadded).saturating_add(zeros),
+            Self::capacity(),
+        )?;
+
+        let _ = self.try_push_str(sign);
+        for _ in 0..zeros {
+            let _ = self.try_push_str("0");
+        }
+        self.try_push_str(digits).map_err(|err| err.error())
+    }
+}
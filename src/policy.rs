@@ -0,0 +1,265 @@
+//! What [`ArrayString`] does when text doesn't fit in its capacity
+//!
+//! The policy is the second type parameter of [`ArrayString`], it defaults to [`Truncate`]. It drives [`push_str`], [`push`] and the traits that can't return an error (`From<&str>`, `FromIterator`, `Extend` and `Add`), those are only implemented for [`Lossy`] policies, so `ArrayString<N, Reject>` has to go through the fallible methods
+//!
+//! Methods that name their behavior, like [`try_push_str`] and [`push_str_truncate`], behave the same with every policy
+//!
+//! [`ArrayString`]: ../struct.ArrayString.html
+//! [`push_str`]: ../struct.ArrayString.html#method.push_str
+//! [`push`]: ../struct.ArrayString.html#method.push
+//! [`try_push_str`]: ../struct.ArrayString.html#method.try_push_str
+//! [`push_str_truncate`]: ../struct.ArrayString.html#method.push_str_truncate
+//! [`Truncate`]: ./struct.Truncate.html
+//! [`Lossy`]: ./trait.Lossy.html
+
+use crate::arraystring::sealed::ValidCapacity;
+use crate::{prelude::*, utils::truncate_str};
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+#[cfg(feature = "logs")]
+use log::trace;
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// Handles text that doesn't fit in an [`ArrayString`]
+///
+/// [`ArrayString`]: ../struct.ArrayString.html
+///
+/// ```rust
+/// # use arraystring::{policy::OverflowPolicy, prelude::*, ValidCapacity};
+/// /// Empties the string instead of keeping a partial text
+/// struct Clear;
+///
+/// impl OverflowPolicy for Clear {
+///     fn push_str<const N: usize, P>(string: &mut ArrayString<N, P>, slice: &str) -> Result<(), OutOfBounds>
+///     where
+///         ArrayString<N, P>: ValidCapacity,
+///     {
+///         string.try_push_str(slice).map_err(|err| {
+///             string.clear();
+///             err.error()
+///         })
+///     }
+/// }
+///
+/// let mut s = ArrayString::<5, Clear>::try_from_str("abc")?;
+/// assert!(s.push_str("def").is_err());
+/// assert_eq!(s.as_str(), "");
+/// # Ok::<(), OutOfBounds>(())
+/// ```
+pub trait OverflowPolicy {
+    /// Appends `slice` to `string`, returning [`OutOfBounds`] if it didn't fit whole, after handling it
+    ///
+    /// [`OutOfBounds`]: ../error/struct.OutOfBounds.html
+    fn push_str<const N: usize, P>(
+        string: &mut ArrayString<N, P>,
+        slice: &str,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity;
+}
+
+/// Policy that always leaves a usable string, even if part of the text was lost
+///
+/// Only lossy policies get `From<&str>`, `FromIterator`, `Extend` and `Add`, since those can't report an error
+pub trait Lossy: OverflowPolicy {}
+
+/// Text that doesn't fit is an error, the string is left untouched
+///
+/// ```rust
+/// # use arraystring::{policy::Reject, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut s = ArrayString::<5, Reject>::try_from_str("abc")?;
+/// assert_eq!(s.push_str("def"), Err(OutOfBounds::new(6, 5)));
+/// assert_eq!(s.as_str(), "abc");
+/// # Ok::<(), OutOfBounds>(())
+/// ```
+///
+/// The traits that would truncate silently aren't implemented:
+///
+/// ```rust,compile_fail
+/// # use arraystring::{policy::Reject, prelude::*};
+/// let s: ArrayString<5, Reject> = "abcdef".into();
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Reject;
+
+impl OverflowPolicy for Reject {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn push_str<const N: usize, P>(
+        string: &mut ArrayString<N, P>,
+        slice: &str,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        trace!("Reject push str: {}", slice);
+        let len = string.len();
+        string
+            .replace_range(len..len, slice)
+            .map_err(|_| OutOfBounds::new(len.saturating_add(slice.len()), N))
+    }
+}
+
+/// Text is cut at the last char boundary that fits, the default policy
+///
+/// ```rust
+/// # use arraystring::prelude::*;
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut s = ArrayString::<5>::try_from_str("abc")?;
+/// assert_eq!(s.push_str("d🤔"), Err(OutOfBounds::new(8, 5)));
+/// assert_eq!(s.as_str(), "abcd");
+///
+/// # #[cfg(not(feature = "strict"))] {
+/// let s: ArrayString<5> = "abcdef".into();
+/// assert_eq!(s.as_str(), "abcde");
+/// # }
+/// # Ok::<(), OutOfBounds>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Truncate;
+
+impl OverflowPolicy for Truncate {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn push_str<const N: usize, P>(
+        string: &mut ArrayString<N, P>,
+        slice: &str,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        trace!("Truncate push str: {}", slice);
+        let needed = string.len().saturating_add(slice.len());
+        let available = N.saturating_sub(string.len());
+        let _ = Reject::push_str(string, truncate_str(slice, available));
+        (needed <= N)
+            .then_some(())
+            .ok_or(OutOfBounds::new(needed, N))
+    }
+}
+
+impl Lossy for Truncate {}
+
+/// Text to append to strings cut by [`TruncateWithMarker`]
+///
+/// [`TruncateWithMarker`]: ./struct.TruncateWithMarker.html
+pub trait Marker {
+    /// Appended to the cut text
+    const MARKER: &'static str;
+}
+
+/// Marks cut text with `…`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ellipsis;
+
+impl Marker for Ellipsis {
+    const MARKER: &'static str = "…";
+}
+
+/// Text is cut so it fits with the marker after it, the marker shows that text was lost
+///
+/// Text that fits is always appended, even after a marker that was part of the text. Text that doesn't fit after a string that already ends with the marker is dropped, so it isn't marked twice. If the marker doesn't fit in the capacity, text is cut without it
+///
+/// ```rust
+/// # use arraystring::{policy::{Ellipsis, TruncateWithMarker}, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// type Preview = ArrayString<8, TruncateWithMarker<Ellipsis>>;
+///
+/// let mut s = Preview::try_from_str("Hello")?;
+/// assert_eq!(s.push_str(" World"), Err(OutOfBounds::new(11, 8)));
+/// assert_eq!(s.as_str(), "Hello…");
+///
+/// assert_eq!(s.push_str("!"), Err(OutOfBounds::new(9, 8)));
+/// assert_eq!(s.as_str(), "Hello…");
+///
+/// let mut s = Preview::try_from_str("Hm…")?;
+/// s.push_str("!")?;
+/// assert_eq!(s.as_str(), "Hm…!");
+/// # Ok::<(), OutOfBounds>(())
+/// ```
+pub struct TruncateWithMarker<M = Ellipsis>(PhantomData<fn() -> M>);
+
+impl<M> Debug for TruncateWithMarker<M> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("TruncateWithMarker").finish()
+    }
+}
+
+impl<M: Marker> OverflowPolicy for TruncateWithMarker<M> {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn push_str<const N: usize, P>(
+        string: &mut ArrayString<N, P>,
+        slice: &str,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        trace!("Truncate with marker push str: {}", slice);
+        let needed = string.len().saturating_add(slice.len());
+        if Reject::push_str(string, slice).is_ok() {
+            return Ok(());
+        }
+        if !M::MARKER.is_empty() && string.ends_with(M::MARKER) {
+            return Err(OutOfBounds::new(needed, N));
+        }
+
+        let Some(budget) = N.checked_sub(M::MARKER.len()) else {
+            return Truncate::push_str(string, slice);
+        };
+        match budget.checked_sub(string.len()) {
+            Some(available) => {
+                let _ = Reject::push_str(string, truncate_str(slice, available));
+            }
+            None => {
+                let size = truncate_str(string.as_str(), budget).len();
+                let _ = string.truncate(size);
+            }
+        }
+        let _ = Reject::push_str(string, M::MARKER);
+        Err(OutOfBounds::new(needed, N))
+    }
+}
+
+impl<M: Marker> Lossy for TruncateWithMarker<M> {}
+
+/// Text that doesn't fit is dropped whole, so the string only keeps complete pieces
+///
+/// `Extend` and `FromIterator` stop at the first piece that doesn't fit, so a later, smaller, piece isn't appended out of order
+///
+/// ```rust
+/// # use arraystring::{policy::Saturate, prelude::*};
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut s = ArrayString::<12, Saturate>::try_from_str("alpha ")?;
+/// s.push_str("beta ")?;
+/// assert_eq!(s.push_str("gamma "), Err(OutOfBounds::new(17, 12)));
+/// assert_eq!(s.as_str(), "alpha beta ");
+///
+/// # #[cfg(not(feature = "strict"))] {
+/// let s: ArrayString<12, Saturate> = ["alpha ", "beta ", "gamma ", "pi"].into_iter().collect();
+/// assert_eq!(s.as_str(), "alpha beta ");
+/// # }
+/// # Ok::<(), OutOfBounds>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Saturate;
+
+impl OverflowPolicy for Saturate {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn push_str<const N: usize, P>(
+        string: &mut ArrayString<N, P>,
+        slice: &str,
+    ) -> Result<(), OutOfBounds>
+    where
+        ArrayString<N, P>: ValidCapacity,
+    {
+        trace!("Saturate push str: {}", slice);
+        Reject::push_str(string, slice)
+    }
+}
+
+impl Lossy for Saturate {}
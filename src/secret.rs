@@ -0,0 +1,346 @@
+//! Non-`Copy` string for passwords, tokens and keys, that wipes its buffer
+//!
+//! [`ArrayString`] is `Copy`, so every move may leave a copy of the text behind, [`SecretArrayString`] can't be copied and zeroizes itself on drop
+
+use crate::arraystring::sealed::ValidCapacity;
+use crate::{prelude::*, Error};
+use core::fmt::{self, Debug, Display, Formatter};
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Fixed capacity string for secrets, it wipes the whole buffer on drop and the removed bytes on every shrinking operation
+///
+/// The text is only reachable through [`expose_secret`], `Debug` and `Display` print `[REDACTED]`.
+///
+/// Prefer creating it directly from the secret source, converting from an [`ArrayString`] leaves the original copy untouched. The `logs` feature traces string contents, don't enable it in builds that handle secrets.
+///
+/// [`expose_secret`]: ./struct.SecretArrayString.html#method.expose_secret
+/// [`ArrayString`]: ./struct.ArrayString.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// let mut token = SecretArrayString::<32>::try_from_str("hunter2")?;
+/// assert_eq!(format!("{token:?}"), "SecretArrayString([REDACTED])");
+/// assert_eq!(token.to_string(), "[REDACTED]");
+///
+/// token.truncate(6)?;
+/// assert_eq!(token.expose_secret(), "hunter");
+/// # Ok(())
+/// # }
+/// ```
+pub struct SecretArrayString<const N: usize>(pub(crate) ArrayString<N>)
+where
+    ArrayString<N>: ValidCapacity;
+
+impl<const N: usize> SecretArrayString<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    /// Creates new empty secret.
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let secret = SecretArrayString::<23>::new();
+    /// assert!(secret.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self(ArrayString::new())
+    }
+
+    /// Creates new secret from string slice if length is lower or equal to [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.SecretArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let secret = SecretArrayString::<23>::try_from_str("My Secret")?;
+    /// assert_eq!(secret.expose_secret(), "My Secret");
+    ///
+    /// let out_of_bounds = "0".repeat(SecretArrayString::<23>::capacity() + 1);
+    /// assert!(SecretArrayString::<23>::try_from_str(out_of_bounds).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_str(string: impl AsRef<str>) -> Result<Self, OutOfBounds> {
+        let mut s = Self::new();
+        s.try_push_str(string)?;
+        Ok(s)
+    }
+
+    /// Exposes the secret as a string slice
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let secret = SecretArrayString::<23>::try_from_str("My Secret")?;
+    /// assert_eq!(secret.expose_secret(), "My Secret");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn expose_secret(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns maximum string capacity, defined at compile time, it will never change
+    ///
+    /// ```rust
+    /// # use arraystring::prelude::*;
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(SecretArrayString::<32>::capacity(), 32);
+    /// ```
+    #[inline]
+    pub const fn capacity() -> usize {
+        N
+    }
+
+    /// Returns secret length in bytes
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let secret = SecretArrayString::<23>::try_from_str("🤔")?;
+    /// assert_eq!(secret.len(), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks if secret is empty.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut secret = SecretArrayString::<23>::try_from_str("My Secret")?;
+    /// assert!(!secret.is_empty());
+    /// secret.clear();
+    /// assert!(secret.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Pushes string slice to the end of the secret if total size is lower or equal to [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.SecretArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut secret = SecretArrayString::<9>::try_from_str("My")?;
+    /// secret.try_push_str(" Secret")?;
+    /// assert_eq!(secret.expose_secret(), "My Secret");
+    ///
+    /// assert!(secret.try_push_str("!").is_err());
+    /// assert_eq!(secret.expose_secret(), "My Secret");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_str<S: AsRef<str>>(&mut self, string: S) -> Result<(), CapacityError<S>> {
+        self.0.try_push_str(string)
+    }
+
+    /// Pushes character to the end of the secret if total size is lower or equal to [`capacity`], otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.SecretArrayString.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut secret = SecretArrayString::<2>::new();
+    /// secret.try_push('a')?;
+    /// secret.try_push('b')?;
+    /// assert!(secret.try_push('c').is_err());
+    /// assert_eq!(secret.expose_secret(), "ab");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError<char>> {
+        self.0.try_push(ch)
+    }
+
+    /// Truncates secret to specified size (if smaller than current size and a valid utf-8 char index), wiping the removed bytes.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut secret = SecretArrayString::<23>::try_from_str("My Secret")?;
+    /// secret.truncate(5)?;
+    /// assert_eq!(secret.expose_secret(), "My Se");
+    ///
+    /// // Index is not at a valid char
+    /// let mut secret = SecretArrayString::<23>::try_from_str("🤔")?;
+    /// assert!(secret.truncate(1).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn truncate(&mut self, size: usize) -> Result<(), Utf8> {
+        self.0.truncate(size)?;
+        self.wipe_tail();
+        Ok(())
+    }
+
+    /// Removes last character from secret, if any, wiping its bytes.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut secret = SecretArrayString::<23>::try_from_str("A🤔")?;
+    /// assert_eq!(secret.pop(), Some('🤔'));
+    /// assert_eq!(secret.pop(), Some('A'));
+    /// assert_eq!(secret.pop(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.0.pop();
+        self.wipe_tail();
+        ch
+    }
+
+    /// Removes specified char from secret, wiping the bytes left after the shifted text.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut secret = SecretArrayString::<23>::try_from_str("ABCD🤔")?;
+    /// assert_eq!(secret.remove(0), Ok('A'));
+    /// assert_eq!(secret.expose_secret(), "BCD🤔");
+    ///
+    /// assert_eq!(secret.remove(10), Err(Error::Index { index: 10, len: 7 }));
+    /// assert_eq!(secret.remove(4), Err(Error::Utf8 { index: 4, valid_up_to: 3 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn remove(&mut self, idx: usize) -> Result<char, Error> {
+        let ch = self.0.remove(idx)?;
+        self.wipe_tail();
+        Ok(ch)
+    }
+
+    /// Empties secret, wiping the whole buffer.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut secret = SecretArrayString::<23>::try_from_str("My Secret")?;
+    /// secret.clear();
+    /// assert_eq!(secret.expose_secret(), "");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn clear(&mut self) {
+        self.0.zeroize();
+    }
+
+    /// Wipes bytes after the string with writes that can't be optimized away
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn wipe_tail(&mut self) {
+        let len = self.0.len();
+        if let Some(tail) = self.0.array.get_mut(len..) {
+            tail.zeroize();
+        }
+    }
+}
+
+impl<const N: usize> Default for SecretArrayString<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> From<ArrayString<N>> for SecretArrayString<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn from(string: ArrayString<N>) -> Self {
+        Self(string)
+    }
+}
+
+impl<const N: usize> Zeroize for SecretArrayString<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const N: usize> Drop for SecretArrayString<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const N: usize> ZeroizeOnDrop for SecretArrayString<N> where ArrayString<N>: ValidCapacity {}
+
+impl<const N: usize> Debug for SecretArrayString<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretArrayString([REDACTED])")
+    }
+}
+
+impl<const N: usize> Display for SecretArrayString<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
@@ -1,6 +1,9 @@
 //! Misc functions to improve readability
 
-use crate::{arraystring::sealed::ValidCapacity, prelude::*};
+use crate::{arraystring::sealed::ValidCapacity, error::Index, prelude::*};
+use core::cmp::Ordering;
+#[cfg(feature = "alloc")]
+use core::ops::{Bound, RangeBounds};
 #[cfg(feature = "logs")]
 use log::trace;
 #[cfg(all(feature = "no-panic", not(debug_assertions)))]
@@ -15,21 +18,96 @@ pub(crate) trait IntoLossy<T>: Sized {
 #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
 pub(crate) fn is_inside_boundary(size: usize, limit: usize) -> Result<(), OutOfBounds> {
     trace!("Out of bounds: ensures {} <= {}", size, limit);
-    (size <= limit).then_some(()).ok_or(OutOfBounds)
+    (size <= limit)
+        .then_some(())
+        .ok_or(OutOfBounds::new(size, limit))
+}
+
+/// Returns error if index is past `len`
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+pub(crate) fn is_inside_len(idx: usize, len: usize) -> Result<(), Index> {
+    trace!("Index: ensures {} <= {}", idx, len);
+    (idx <= len).then_some(()).ok_or(Index::new(idx, len))
 }
 
 /// Returns error if index is not at a valid utf-8 char boundary
 #[inline]
 #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
-pub(crate) fn is_char_boundary<const N: usize>(s: &ArrayString<N>, idx: usize) -> Result<(), Utf8>
+pub(crate) fn is_char_boundary<const N: usize, P>(
+    s: &ArrayString<N, P>,
+    idx: usize,
+) -> Result<(), Utf8>
 where
-    ArrayString<N>: ValidCapacity,
+    ArrayString<N, P>: ValidCapacity,
 {
     trace!("Is char boundary: {} at {}", s.as_str(), idx);
-    if s.as_str().is_char_boundary(idx) {
+    is_str_char_boundary(s.as_str(), idx)
+}
+
+/// Returns error if index is not at a valid utf-8 char boundary of `s`, reporting where the valid text ends
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+pub(crate) fn is_str_char_boundary(s: &str, idx: usize) -> Result<(), Utf8> {
+    if s.is_char_boundary(idx) {
         return Ok(());
     }
-    Err(Utf8)
+    Err(Utf8::new(idx, s.floor_char_boundary(idx)))
+}
+
+/// Counts the chars of `s`, `str::chars().count()` isn't panic free under `no-panic`
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+pub(crate) fn count_chars(s: &str) -> usize {
+    // Every char starts with a byte that isn't a continuation byte (`0b10xx_xxxx`)
+    s.bytes()
+        .filter(|byte| !matches!(byte, 0x80..=0xBF))
+        .count()
+}
+
+/// Converts range bounds to `start..end` indexes, unbounded end is `len`
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn range_bounds(range: &impl RangeBounds<usize>, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(t) => *t,
+        Bound::Excluded(t) => t.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(t) => t.saturating_add(1),
+        Bound::Excluded(t) => *t,
+        Bound::Unbounded => len,
+    };
+    (start, end)
+}
+
+/// Compares two arrays in machine words, the size is known at compile time so the loop is unrolled
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+pub(crate) fn words_eq<const N: usize>(left: &[u8; N], right: &[u8; N]) -> bool {
+    let (left_words, left_rest) = left.as_chunks::<8>();
+    let (right_words, right_rest) = right.as_chunks::<8>();
+    left_words.iter().zip(right_words).fold(true, |eq, (l, r)| {
+        eq & (u64::from_ne_bytes(*l) == u64::from_ne_bytes(*r))
+    }) && left_rest == right_rest
+}
+
+/// Lexicographically compares two arrays in machine words
+///
+/// Big endian words order like their bytes, so the first different word decides
+#[inline]
+#[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+pub(crate) fn words_cmp<const N: usize>(left: &[u8; N], right: &[u8; N]) -> Ordering {
+    let (left_words, left_rest) = left.as_chunks::<8>();
+    let (right_words, right_rest) = right.as_chunks::<8>();
+    for (l, r) in left_words.iter().zip(right_words) {
+        let (l, r) = (u64::from_be_bytes(*l), u64::from_be_bytes(*r));
+        if l != r {
+            return l.cmp(&r);
+        }
+    }
+    left_rest.cmp(right_rest)
 }
 
 /// Truncates string to specified size (ignoring last bytes if they form a partial `char`)
@@ -60,6 +138,37 @@ pub(crate) fn truncate_str(slice: &str, mut size: usize) -> &str {
     }
 }
 
+/// Maps random values to a char, 3 out of 4 classes are multi-byte chars (2, 3 and 4 bytes)
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+#[inline]
+pub(crate) fn char_from_class(class: u8, raw: u32) -> char {
+    let (start, len) = match class % 4 {
+        0 => (0, 0x80),
+        1 => (0x80, 0x800 - 0x80),
+        // Skips surrogates, they are not valid chars
+        2 => (0xE000, 0x10000 - 0xE000),
+        _ => (0x10000, 0x110000 - 0x10000),
+    };
+    char::from_u32(start + raw % len).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// Builds string from generated chars, stopping at the first char that doesn't fit
+///
+/// If `exact` is set the remaining space is filled with ASCII, so capacity is reached even if the last multi-byte char didn't fit
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+#[inline]
+pub(crate) fn generate<const N: usize, P>(
+    exact: bool,
+    chars: impl IntoIterator<Item = char>,
+) -> ArrayString<N, P>
+where
+    ArrayString<N, P>: ValidCapacity,
+{
+    let mut out = ArrayString::from_chars_truncate(chars);
+    while exact && out.try_push('a').is_ok() {}
+    out
+}
+
 impl IntoLossy<u8> for usize {
     #[allow(clippy::cast_possible_truncation)]
     #[inline]
@@ -73,10 +182,38 @@ impl IntoLossy<u8> for usize {
 mod tests {
     use super::*;
 
+    #[test]
+    fn words() {
+        let mut left = [0; 19];
+        let mut right = [0; 19];
+        assert!(words_eq(&left, &right));
+        for (idx, l, r) in [(0, 0, 1), (7, 9, 8), (8, 1, 0), (16, 3, 4), (18, 2, 1)] {
+            left[idx] = l;
+            right[idx] = r;
+            assert!(!words_eq(&left, &right));
+            assert_eq!(words_cmp(&left, &right), left.cmp(&right));
+            assert_eq!(words_cmp(&right, &left), right.cmp(&left));
+            right[idx] = l;
+            assert!(words_eq(&left, &right));
+        }
+    }
+
     #[test]
     fn truncate() {
         assert_eq!(truncate_str("i", 10), "i");
         assert_eq!(truncate_str("iiiiii", 3), "iii");
         assert_eq!(truncate_str("🤔🤔🤔", 5), "🤔");
     }
+
+    #[test]
+    #[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+    fn generate_exact() {
+        let chars = ['🤔', '🤔', 'a'];
+        let short: ArrayString<6> = generate(false, chars);
+        let exact: ArrayString<6> = generate(true, chars);
+        assert_eq!(short.as_str(), "🤔");
+        assert_eq!(exact.as_str(), "🤔aa");
+        assert_eq!(char_from_class(0, 0x41), 'A');
+        assert_eq!(char_from_class(3, 0).len_utf8(), 4);
+    }
 }
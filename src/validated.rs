@@ -0,0 +1,736 @@
+//! Strings that are checked by a [`Validator`] on creation and on every mutation
+//!
+//! Built-in validators can be combined with tuples, the first rule that fails is reported in [`Invalid`]
+//!
+//! [`Invalid`]: ../error/struct.Invalid.html
+
+use crate::arraystring::sealed::ValidCapacity;
+use crate::{error::Invalid, prelude::*, utils::count_chars, Error};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::{borrow::Borrow, cmp::Ordering, hash::Hash, hash::Hasher};
+use core::{marker::PhantomData, ops::Deref, ops::RangeBounds, str::FromStr};
+#[cfg(feature = "logs")]
+use log::trace;
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+use no_panic::no_panic;
+
+/// Rule that text stored in [`Validated`] must follow
+///
+/// [`Validated`]: ./struct.Validated.html
+///
+/// ```rust
+/// # use arraystring::{error::Invalid, prelude::*, validated::Validator};
+/// struct Lowercase;
+///
+/// impl Validator for Lowercase {
+///     fn validate(string: &str) -> Result<(), Invalid> {
+///         if string.chars().any(char::is_uppercase) {
+///             return Err(Invalid::new("lowercase"));
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// assert!(Validated::<10, Lowercase>::try_from_str("abc").is_ok());
+/// assert!(Validated::<10, Lowercase>::try_from_str("aBc").is_err());
+/// ```
+pub trait Validator {
+    /// Fewest bytes a valid string can have, a [`Validated`] with a smaller capacity fails to compile
+    ///
+    /// [`Validated`]: ./struct.Validated.html
+    const MIN_LEN: usize = 0;
+
+    /// Returns error naming the failed rule if string is invalid
+    fn validate(string: &str) -> Result<(), Invalid>;
+}
+
+macro_rules! impl_tuple_validator {
+    ($($validator:ident),+) => {
+        impl<$($validator: Validator),+> Validator for ($($validator,)+) {
+            const MIN_LEN: usize = {
+                let mut min = 0;
+                $(if $validator::MIN_LEN > min {
+                    min = $validator::MIN_LEN;
+                })+
+                min
+            };
+
+            #[inline]
+            #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+            fn validate(string: &str) -> Result<(), Invalid> {
+                $($validator::validate(string)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tuple_validator!(A);
+impl_tuple_validator!(A, B);
+impl_tuple_validator!(A, B, C);
+impl_tuple_validator!(A, B, C, D);
+
+/// Only ASCII characters, rule `"ascii"`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ascii;
+
+impl Validator for Ascii {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        string.is_ascii().then_some(()).ok_or(Invalid::new("ascii"))
+    }
+}
+
+/// Only alphanumeric characters (as in `char::is_alphanumeric`, combine with [`Ascii`] to reject non-ASCII letters), rule `"alphanumeric"`
+///
+/// [`Ascii`]: ./struct.Ascii.html
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Alphanumeric;
+
+impl Validator for Alphanumeric {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        string
+            .chars()
+            .all(char::is_alphanumeric)
+            .then_some(())
+            .ok_or(Invalid::new("alphanumeric"))
+    }
+}
+
+/// No control characters (as in `char::is_control`), rule `"no-control"`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NoControl;
+
+impl Validator for NoControl {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        (!string.chars().any(char::is_control))
+            .then_some(())
+            .ok_or(Invalid::new("no-control"))
+    }
+}
+
+/// No leading or trailing whitespace (as in `str::trim`), rule `"trimmed"`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Trimmed;
+
+impl Validator for Trimmed {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        (string.trim().len() == string.len())
+            .then_some(())
+            .ok_or(Invalid::new("trimmed"))
+    }
+}
+
+/// At least `MIN` chars, rule `"min-length"`
+///
+/// Used by [`BoundedString`], the maximum is the capacity
+///
+/// [`BoundedString`]: ./type.BoundedString.html
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MinLen<const MIN: usize>;
+
+impl<const MIN: usize> Validator for MinLen<MIN> {
+    /// Every char takes at least one byte
+    const MIN_LEN: usize = MIN;
+
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        (count_chars(string) >= MIN)
+            .then_some(())
+            .ok_or(Invalid::new("min-length"))
+    }
+}
+
+/// List of allowed characters, used by [`InCharset`]
+///
+/// [`InCharset`]: ./struct.InCharset.html
+pub trait Charset {
+    /// Every allowed character
+    const CHARS: &'static str;
+    /// Rule name reported when a character is not in the list
+    const RULE: &'static str = "charset";
+}
+
+/// Only characters from the [`Charset`] list, rule [`Charset::RULE`]
+///
+/// [`Charset`]: ./trait.Charset.html
+/// [`Charset::RULE`]: ./trait.Charset.html#associatedconstant.RULE
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*, validated::{Charset, InCharset}};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// struct Digits;
+///
+/// impl Charset for Digits {
+///     const CHARS: &'static str = "+0123456789";
+///     const RULE: &'static str = "phone";
+/// }
+///
+/// type Phone = Validated<16, InCharset<Digits>>;
+/// assert_eq!(Phone::try_from_str("+5521999999999")?.as_str(), "+5521999999999");
+/// assert_eq!(Phone::try_from_str("21 9999-9999"), Err(Error::Invalid { rule: "phone" }));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InCharset<C>(PhantomData<C>);
+
+impl<C: Charset> Validator for InCharset<C> {
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn validate(string: &str) -> Result<(), Invalid> {
+        string
+            .chars()
+            .all(|ch| C::CHARS.contains(ch))
+            .then_some(())
+            .ok_or(Invalid::new(C::RULE))
+    }
+}
+
+/// [`ArrayString`] that always follows the `V` rule
+///
+/// Constructors (and the serde and diesel decoders) validate the text, mutations are applied to a copy that replaces the string only if it's still valid. Read access goes through `Deref<Target = str>`
+///
+/// Unlike [`ArrayString`] decoders, text that doesn't fit is an error instead of being truncated
+///
+/// [`ArrayString`]: ../struct.ArrayString.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*, validated::{Alphanumeric, Ascii}};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// type Username = Validated<20, (Ascii, Alphanumeric)>;
+///
+/// let mut username = Username::try_from_str("user")?;
+/// username.try_push_str("42")?;
+/// assert_eq!(username.as_str(), "user42");
+///
+/// assert_eq!(username.try_push('!'), Err(Error::Invalid { rule: "alphanumeric" }));
+/// assert_eq!(username.as_str(), "user42");
+/// assert_eq!(Username::try_from_str("usér"), Err(Error::Invalid { rule: "ascii" }));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(
+    all(feature = "diesel-traits", feature = "std"),
+    derive(diesel::AsExpression, diesel::FromSqlRow)
+)]
+#[cfg_attr(
+    all(feature = "diesel-traits", feature = "std"),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct Validated<const N: usize, V>(ArrayString<N>, PhantomData<fn() -> V>)
+where
+    ArrayString<N>: ValidCapacity;
+
+impl<const N: usize, V> Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    /// Extracts the inner [`ArrayString`]
+    ///
+    /// [`ArrayString`]: ../struct.ArrayString.html
+    #[inline]
+    pub fn into_inner(self) -> ArrayString<N> {
+        self.0
+    }
+
+    /// Extracts a string slice containing the entire string
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Ascii};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = Validated::<20, Ascii>::try_from_str("My String")?;
+    /// assert_eq!(string.as_str(), "My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns maximum string capacity, defined at compile time, it will never change
+    ///
+    /// ```rust
+    /// # use arraystring::{prelude::*, validated::Ascii};
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// assert_eq!(Validated::<32, Ascii>::capacity(), 32);
+    /// ```
+    #[inline]
+    pub const fn capacity() -> usize {
+        N
+    }
+}
+
+impl<const N: usize, V: Validator> Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    /// Creates new validated string from string slice if it fits in [`capacity`] and follows the rule, otherwise returns an error.
+    ///
+    /// [`capacity`]: ./struct.Validated.html#method.capacity
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Trimmed};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = Validated::<10, Trimmed>::try_from_str("My String")?;
+    /// assert_eq!(string.as_str(), "My String");
+    ///
+    /// assert_eq!(Validated::<10, Trimmed>::try_from_str(" My String"), Err(Error::Invalid { rule: "trimmed" }));
+    /// assert_eq!(Validated::<10, Trimmed>::try_from_str("My String!!"), Err(Error::OutOfBounds { needed: 11, capacity: 10 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_str(string: impl AsRef<str>) -> Result<Self, Error> {
+        Self::try_from_array_string(ArrayString::try_from_str(string)?)
+    }
+
+    /// Validates [`ArrayString`], returning the error if it doesn't follow the rule
+    ///
+    /// [`ArrayString`]: ../struct.ArrayString.html
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Ascii};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let string = ArrayString::<10>::try_from_str("My String")?;
+    /// let validated = Validated::<10, Ascii>::try_from_array_string(string)?;
+    /// assert_eq!(validated.into_inner(), string);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_from_array_string(string: ArrayString<N>) -> Result<Self, Error> {
+        const {
+            assert!(
+                V::MIN_LEN <= N,
+                "Validated capacity is smaller than the minimum length of its validator"
+            )
+        };
+        trace!("Validate: {}", string);
+        V::validate(string.as_str())?;
+        Ok(Self(string, PhantomData))
+    }
+
+    /// Pushes string slice to the end of the string if it fits and the result is valid, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::NoControl};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = Validated::<10, NoControl>::try_from_str("My")?;
+    /// string.try_push_str(" String")?;
+    /// assert_eq!(string.as_str(), "My String");
+    ///
+    /// assert_eq!(string.try_push_str("\n"), Err(Error::Invalid { rule: "no-control" }));
+    /// assert_eq!(string.try_push_str("!!"), Err(Error::OutOfBounds { needed: 11, capacity: 10 }));
+    /// assert_eq!(string.as_str(), "My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push_str(&mut self, string: impl AsRef<str>) -> Result<(), Error> {
+        self.update(|s| s.try_push_str(string))
+    }
+
+    /// Pushes character to the end of the string if it fits and the result is valid, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Ascii};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = Validated::<2, Ascii>::try_from_str("a")?;
+    /// string.try_push('b')?;
+    /// assert_eq!(string.try_push('c'), Err(Error::OutOfBounds { needed: 3, capacity: 2 }));
+    /// assert_eq!(string.as_str(), "ab");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_push(&mut self, ch: char) -> Result<(), Error> {
+        self.update(|s| s.try_push(ch))
+    }
+
+    /// Inserts string slice at specified index if it fits and the result is valid, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Trimmed};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = Validated::<10, Trimmed>::try_from_str("MyString")?;
+    /// string.try_insert_str(2, " ")?;
+    /// assert_eq!(string.as_str(), "My String");
+    ///
+    /// assert_eq!(string.try_insert_str(0, " "), Err(Error::Invalid { rule: "trimmed" }));
+    /// assert_eq!(string.as_str(), "My String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn try_insert_str(&mut self, idx: usize, string: impl AsRef<str>) -> Result<(), Error> {
+        self.update(|s| s.try_insert_str(idx, string))
+    }
+
+    /// Replaces range with string slice if it fits and the result is valid, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Alphanumeric};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = Validated::<10, Alphanumeric>::try_from_str("user42")?;
+    /// string.replace_range(4.., "43")?;
+    /// assert_eq!(string.as_str(), "user43");
+    ///
+    /// assert_eq!(string.replace_range(..4, "us-r"), Err(Error::Invalid { rule: "alphanumeric" }));
+    /// assert_eq!(string.as_str(), "user43");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn replace_range(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        with: impl AsRef<str>,
+    ) -> Result<(), Error> {
+        self.update(|s| s.replace_range(range, with))
+    }
+
+    /// Truncates string to specified size if it's a valid utf-8 char index and the result is valid, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Trimmed};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = Validated::<10, Trimmed>::try_from_str("My String")?;
+    /// assert_eq!(string.truncate(3), Err(Error::Invalid { rule: "trimmed" }));
+    /// string.truncate(2)?;
+    /// assert_eq!(string.as_str(), "My");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn truncate(&mut self, size: usize) -> Result<(), Error> {
+        self.update(|s| s.truncate(size))
+    }
+
+    /// Removes last character, if any and if the result is valid, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Trimmed};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = Validated::<10, Trimmed>::try_from_str("A 🤔")?;
+    /// assert_eq!(string.pop(), Err(Error::Invalid { rule: "trimmed" }));
+    /// assert_eq!(string.as_str(), "A 🤔");
+    ///
+    /// let mut string = Validated::<10, Trimmed>::try_from_str("A")?;
+    /// assert_eq!(string.pop(), Ok(Some('A')));
+    /// assert_eq!(string.pop(), Ok(None));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn pop(&mut self) -> Result<Option<char>, Error> {
+        self.update(|s| Ok::<_, Error>(s.pop()))
+    }
+
+    /// Removes character at specified index if the result is valid, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Trimmed};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = Validated::<10, Trimmed>::try_from_str("My String")?;
+    /// assert_eq!(string.remove(0), Ok('M'));
+    /// assert_eq!(string.remove(0), Err(Error::Invalid { rule: "trimmed" }));
+    /// assert_eq!(string.remove(20), Err(Error::Index { index: 20, len: 8 }));
+    /// assert_eq!(string.as_str(), "y String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn remove(&mut self, idx: usize) -> Result<char, Error> {
+        self.update(|s| s.remove(idx))
+    }
+
+    /// Creates a draining iterator that removes the specified range and yields the removed chars, if the result is valid, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Trimmed};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = Validated::<10, Trimmed>::try_from_str("My String")?;
+    /// assert_eq!(string.drain(..2), Err(Error::Invalid { rule: "trimmed" }));
+    /// assert_eq!(string.drain(..3)?.collect::<String>(), "My ");
+    /// assert_eq!(string.as_str(), "String");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Result<Drain<N>, Error> {
+        self.update(|s| s.drain(range))
+    }
+
+    /// Empties string if the empty string is valid, otherwise returns an error.
+    ///
+    /// ```rust
+    /// # use arraystring::{Error, prelude::*, validated::Ascii};
+    /// # fn main() -> Result<(), Error> {
+    /// # #[cfg(not(miri))] let _ = env_logger::try_init();
+    /// let mut string = Validated::<10, Ascii>::try_from_str("My String")?;
+    /// string.clear()?;
+    /// assert_eq!(string.as_str(), "");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    pub fn clear(&mut self) -> Result<(), Error> {
+        self.update(|s| {
+            s.clear();
+            Ok::<_, Error>(())
+        })
+    }
+
+    /// Applies the operation to a copy, replacing the string only if the operation succeeds and the copy is valid
+    #[inline]
+    #[cfg_attr(all(feature = "no-panic", not(debug_assertions)), no_panic)]
+    fn update<T, E: Into<Error>>(
+        &mut self,
+        f: impl FnOnce(&mut ArrayString<N>) -> Result<T, E>,
+    ) -> Result<T, Error> {
+        let mut string = self.0;
+        let out = f(&mut string).map_err(Into::into)?;
+        *self = Self::try_from_array_string(string)?;
+        Ok(out)
+    }
+}
+
+impl<const N: usize, V> Clone for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, V> Copy for Validated<N, V> where ArrayString<N>: ValidCapacity {}
+
+impl<const N: usize, V> Debug for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Validated").field(&self.0).finish()
+    }
+}
+
+impl<const N: usize, V> Display for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize, V> PartialEq for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<const N: usize, V> Eq for Validated<N, V> where ArrayString<N>: ValidCapacity {}
+
+impl<const N: usize, V> PartialEq<str> for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0.as_str() == other
+    }
+}
+
+impl<const N: usize, V> PartialEq<&str> for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.0.as_str() == *other
+    }
+}
+
+impl<const N: usize, V> Ord for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<const N: usize, V> PartialOrd for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, V> Hash for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.0.hash(hasher);
+    }
+}
+
+impl<const N: usize, V> Deref for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0.as_str()
+    }
+}
+
+impl<const N: usize, V> AsRef<str> for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<const N: usize, V> Borrow<str> for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<const N: usize, V: Validator> FromStr for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const N: usize, V: Validator> TryFrom<&str> for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    type Error = Error;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const N: usize, V: Validator> TryFrom<ArrayString<N>> for Validated<N, V>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    type Error = Error;
+
+    #[inline]
+    fn try_from(s: ArrayString<N>) -> Result<Self, Self::Error> {
+        Self::try_from_array_string(s)
+    }
+}
+
+impl<const N: usize, V> From<Validated<N, V>> for ArrayString<N>
+where
+    ArrayString<N>: ValidCapacity,
+{
+    #[inline]
+    fn from(s: Validated<N, V>) -> Self {
+        s.0
+    }
+}
+
+/// String with at least `MIN` chars and at most `MAX` bytes, stored in an [`ArrayString<MAX>`]
+///
+/// Shrinking operations (like `pop`, `truncate`, `remove` and `drain`) return `Error::Invalid { rule: "min-length" }` instead of going below `MIN`, text bigger than `MAX` is `Error::OutOfBounds`. `Deserialize`, `FromStr` and diesel's `FromSql` enforce both bounds
+///
+/// [`ArrayString<MAX>`]: ../struct.ArrayString.html
+///
+/// ```rust
+/// # use arraystring::{Error, prelude::*};
+/// # fn main() -> Result<(), Error> {
+/// # #[cfg(not(miri))] let _ = env_logger::try_init();
+/// type Password = BoundedString<8, 64>;
+///
+/// let mut password = Password::try_from_str("hunter22")?;
+/// assert_eq!(password.pop(), Err(Error::Invalid { rule: "min-length" }));
+/// assert_eq!(password.truncate(4), Err(Error::Invalid { rule: "min-length" }));
+/// assert_eq!(password.as_str(), "hunter22");
+///
+/// assert_eq!(Password::try_from_str("hunter2"), Err(Error::Invalid { rule: "min-length" }));
+/// // 8 bytes but only 4 chars
+/// assert_eq!(Password::try_from_str("éééé"), Err(Error::Invalid { rule: "min-length" }));
+/// assert_eq!("0".repeat(65).parse::<Password>(), Err(Error::OutOfBounds { needed: 65, capacity: 64 }));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// A minimum above the capacity fails to compile:
+///
+/// ```rust,compile_fail
+/// # use arraystring::prelude::*;
+/// let never = BoundedString::<10, 5>::try_from_str("abc");
+/// ```
+pub type BoundedString<const MIN: usize, const MAX: usize> = Validated<MAX, MinLen<MIN>>;
@@ -0,0 +1,147 @@
+use arraystring::prelude::*;
+use proptest::prelude::*;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Small enough capacity that generated operations move it to the heap and back often
+type TestString = InlineOrHeap<8>;
+
+fn unwind<R, F>(func: F) -> Result<R, ()>
+where
+    F: FnOnce() -> R,
+{
+    catch_unwind(AssertUnwindSafe(func)).map_err(|_| ())
+}
+
+#[derive(Clone, Debug)]
+enum Op {
+    PushStr(String),
+    Push(char),
+    InsertStr(usize, String),
+    ReplaceRange(usize, usize, String),
+    Truncate(usize),
+    Pop,
+    Remove(usize),
+    Retain(char),
+    Trim,
+    Clear,
+    SplitOff(usize),
+    Drain(usize, usize),
+    ShrinkInline,
+}
+
+fn text() -> impl Strategy<Value = String> {
+    any::<ArrayString<12>>().prop_map(|s| s.as_str().to_owned())
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let idx = 0..24usize;
+    prop_oneof![
+        text().prop_map(Op::PushStr),
+        any::<char>().prop_map(Op::Push),
+        (idx.clone(), text()).prop_map(|(i, s)| Op::InsertStr(i, s)),
+        (idx.clone(), idx.clone(), text()).prop_map(|(a, b, s)| Op::ReplaceRange(a, b, s)),
+        idx.clone().prop_map(Op::Truncate),
+        Just(Op::Pop),
+        idx.clone().prop_map(Op::Remove),
+        any::<char>().prop_map(Op::Retain),
+        Just(Op::Trim),
+        Just(Op::Clear),
+        idx.clone().prop_map(Op::SplitOff),
+        (idx.clone(), idx).prop_map(|(a, b)| Op::Drain(a, b)),
+        Just(Op::ShrinkInline),
+    ]
+}
+
+/// Applies operation to both, `String` panics are expected to be errors in `InlineOrHeap`
+fn apply(st: &mut String, ms: &mut TestString, op: Op) -> Result<(), TestCaseError> {
+    match op {
+        Op::PushStr(s) => {
+            st.push_str(&s);
+            prop_assert!(ms.try_push_str(&s).is_ok());
+        }
+        Op::Push(ch) => {
+            st.push(ch);
+            prop_assert!(ms.try_push(ch).is_ok());
+        }
+        Op::InsertStr(idx, s) => {
+            let expected = unwind(|| st.insert_str(idx, &s));
+            prop_assert_eq!(expected.is_ok(), ms.try_insert_str(idx, &s).is_ok());
+        }
+        Op::ReplaceRange(start, end, s) => {
+            let expected = unwind(|| st.replace_range(start..end, &s));
+            prop_assert_eq!(expected.is_ok(), ms.replace_range(start..end, &s).is_ok());
+        }
+        Op::Truncate(size) => {
+            let expected = unwind(|| st.truncate(size));
+            prop_assert_eq!(expected.is_ok(), ms.truncate(size).is_ok());
+        }
+        Op::Pop => prop_assert_eq!(st.pop(), ms.pop()),
+        Op::Remove(idx) => {
+            let expected = unwind(|| st.remove(idx));
+            prop_assert_eq!(expected.ok(), ms.remove(idx).ok());
+        }
+        Op::Retain(ch) => {
+            st.retain(|c| c != ch);
+            ms.retain(|c| c != ch);
+        }
+        Op::Trim => {
+            *st = st.trim().to_owned();
+            ms.trim();
+        }
+        Op::Clear => {
+            st.clear();
+            ms.clear();
+        }
+        Op::SplitOff(at) => {
+            let expected = unwind(|| st.split_off(at));
+            let split = ms.split_off(at);
+            prop_assert_eq!(expected.ok(), split.ok().map(String::from));
+        }
+        Op::Drain(start, end) => {
+            let expected = unwind(|| st.drain(start..end).collect::<String>());
+            let drained = ms.drain(start..end).map(|d| d.collect::<String>());
+            prop_assert_eq!(expected.ok(), drained.ok());
+        }
+        Op::ShrinkInline => {
+            prop_assert_eq!(ms.shrink_inline(), st.len() <= TestString::capacity());
+        }
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(if cfg!(miri) { 4 } else { 256 }))]
+
+    #[test]
+    fn operations(init in text(), ops in proptest::collection::vec(op(), 0..32)) {
+        #[cfg(not(miri))]
+        let _ = env_logger::try_init();
+        let mut st = init.clone();
+        let mut ms = TestString::try_from_str(&init).unwrap();
+        for op in ops {
+            let was_inline = ms.is_inline();
+            apply(&mut st, &mut ms, op)?;
+            prop_assert_eq!(ms.as_str(), st.as_str());
+            // Only `shrink_inline` moves it back inline
+            prop_assert!(ms.is_inline() || ms.len() > TestString::capacity() || !was_inline);
+        }
+    }
+
+    #[test]
+    fn from_str(s in text()) {
+        let ms = TestString::from_str_truncate(&s);
+        prop_assert_eq!(ms.as_str(), s.as_str());
+        prop_assert_eq!(ms.is_inline(), s.len() <= TestString::capacity());
+        prop_assert_eq!(String::from(ms), s);
+    }
+
+    #[test]
+    fn from_utf16(s in text()) {
+        let utf16 = s.encode_utf16().collect::<Vec<_>>();
+        prop_assert_eq!(TestString::try_from_utf16(&utf16).unwrap().into_string(), s);
+        prop_assert_eq!(
+            TestString::from_utf16_lossy_truncate(&utf16).into_string(),
+            String::from_utf16_lossy(&utf16)
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use arraystring::validated::{Ascii, MinLen};
+use arraystring::{prelude::*, ArrayStringNewtype, Error};
+#[cfg(not(feature = "no-panic"))]
+use proptest::prelude::*;
+#[cfg(not(feature = "no-panic"))]
+use std::fmt::Write;
+#[cfg(not(feature = "no-panic"))]
+use std::hash::{BuildHasher, RandomState};
+
+/// Fits in both, with room to grow
+#[cfg(not(feature = "no-panic"))]
+fn text() -> impl Strategy<Value = String> {
+    any::<ArrayString<40>>().prop_map(|s| s.as_str().to_owned())
+}
+
+/// `CacheString` is generated by the derive, it must behave like the `ArrayString<63>` it wraps
+#[cfg(not(feature = "no-panic"))]
+fn assert_parity(cache: &CacheString, array: &ArrayString<63>) -> Result<(), TestCaseError> {
+    let hasher = RandomState::new();
+    prop_assert_eq!(cache.as_str(), array.as_str());
+    prop_assert_eq!(**cache, *array);
+    prop_assert_eq!(format!("{cache}"), format!("{array}"));
+    prop_assert_eq!(format!("{cache:?}"), format!("CacheString({array:?})"));
+    prop_assert_eq!(hasher.hash_one(cache), hasher.hash_one(array));
+    Ok(())
+}
+
+// Proptest hands `no_panic` functions closures and writers that may panic
+#[cfg(not(feature = "no-panic"))]
+proptest! {
+    #[test]
+    fn constructors(s in text(), t in text()) {
+        let joined = [s.as_str(), t.as_str()];
+        assert_parity(&CacheString::from_str_truncate(&s), &ArrayString::from_str_truncate(&s))?;
+        assert_parity(&CacheString::from_chars_truncate(s.chars()), &ArrayString::from_chars_truncate(s.chars()))?;
+        assert_parity(&CacheString::from_iterator_truncate(joined), &ArrayString::from_iterator_truncate(joined))?;
+        #[cfg(not(feature = "strict"))]
+        assert_parity(&joined.into_iter().collect(), &joined.into_iter().collect())?;
+        prop_assert_eq!(CacheString::try_from_str(&s).map(|s| *s), ArrayString::try_from_str(&s));
+        prop_assert_eq!(
+            CacheString::try_from_iterator(joined).map(|s| *s).map_err(|err| err.map(|(s, rest)| (*s, rest.collect::<Vec<_>>()))),
+            ArrayString::try_from_iterator(joined).map_err(|err| err.map(|(s, rest)| (s, rest.collect::<Vec<_>>())))
+        );
+        prop_assert_eq!(s.parse::<CacheString>().map(|s| *s), s.parse::<ArrayString<63>>());
+
+        let utf16: Vec<u16> = s.encode_utf16().chain(t.encode_utf16()).collect();
+        prop_assert_eq!(CacheString::try_from_utf16(&utf16).map(|s| *s), ArrayString::try_from_utf16(&utf16));
+        assert_parity(&CacheString::from_utf16_lossy_truncate(&utf16), &ArrayString::from_utf16_lossy_truncate(&utf16))?;
+    }
+
+    #[test]
+    fn mutations(s in text(), t in text(), at in 0..64usize) {
+        let mut cache = CacheString::from_str_truncate(&s);
+        let mut array = ArrayString::<63>::from_str_truncate(&s);
+        prop_assert_eq!(cache.split_off(at).map(|s| *s), array.split_off(at));
+        assert_parity(&cache, &array)?;
+
+        #[cfg(not(feature = "strict"))]
+        {
+            cache.extend(t.chars());
+            array.extend(t.chars());
+            assert_parity(&cache, &array)?;
+        }
+
+        prop_assert_eq!(write!(cache, "{t}").is_ok(), write!(array, "{t}").is_ok());
+        assert_parity(&cache, &array)?;
+
+        #[cfg(not(feature = "strict"))]
+        assert_parity(&(cache + &s), &(array + &s))?;
+        prop_assert_eq!(cache.try_push_str(&t), array.try_push_str(&t));
+        prop_assert_eq!(cache.pop(), array.pop());
+        assert_parity(&cache, &array)?;
+        prop_assert_eq!(cache.cmp(&CacheString::from(array)), std::cmp::Ordering::Equal);
+    }
+}
+
+#[derive(Copy, Clone, Default, ArrayStringNewtype)]
+#[repr(align(16))]
+#[arraystring(align = 16)]
+struct Aligned(ArrayString<15>);
+
+#[derive(Copy, Clone, ArrayStringNewtype)]
+#[arraystring(validator = (Ascii, MinLen<2>))]
+struct Tag(ArrayString<8>);
+
+#[test]
+fn aligned_newtype() {
+    assert_eq!(std::mem::align_of::<Aligned>(), 16);
+    assert_eq!(Aligned::capacity(), 15);
+    let mut s = Aligned::try_from_str("abc").unwrap();
+    s.push_str_truncate("def");
+    assert_eq!(s, "abcdef");
+    assert_eq!(&s[1..3], "bc");
+
+    let wide: ArrayString<64> = s.into();
+    assert_eq!(wide.as_str(), "abcdef");
+    let narrow = Aligned::from(ArrayString::<4>::try_from_str("ab").unwrap());
+    assert_eq!(narrow, "ab");
+}
+
+#[test]
+fn resize_cache_string() {
+    let small = ArrayString::<20>::try_from_str("My String").unwrap();
+    let cache = CacheString::from(small);
+    assert_eq!(cache, "My String");
+    assert_eq!(ArrayString::<255>::from(cache).as_str(), "My String");
+    assert_eq!(cache.resize_truncate::<2>().as_str(), "My");
+    assert!(cache.try_resize::<8>().is_err());
+}
+
+#[test]
+fn validated_newtype() -> Result<(), Error> {
+    assert_eq!(Tag::capacity(), 8);
+    let mut tag = Tag::try_from_str("ab")?;
+    assert_eq!(
+        Tag::try_from_str("á"),
+        Err(Error::Invalid { rule: "ascii" })
+    );
+    assert_eq!(
+        Tag::try_from_str("a"),
+        Err(Error::Invalid { rule: "min-length" })
+    );
+    assert_eq!(
+        Tag::try_from_str("abcdefghi"),
+        Err(Error::OutOfBounds {
+            needed: 9,
+            capacity: 8
+        })
+    );
+
+    tag.try_push_str("cd")?;
+    assert_eq!(tag.try_push('é'), Err(Error::Invalid { rule: "ascii" }));
+    assert_eq!(tag.truncate(1), Err(Error::Invalid { rule: "min-length" }));
+    assert_eq!(tag.clear(), Err(Error::Invalid { rule: "min-length" }));
+    assert_eq!(tag, "abcd");
+
+    assert_eq!(tag.pop()?, Some('d'));
+    assert_eq!(tag.drain(..1)?.collect::<String>(), "a");
+    assert_eq!(tag.as_str(), "bc");
+    assert_eq!(
+        "xyz".parse::<Tag>()?.into_inner(),
+        ArrayString::<8>::try_from_str("xyz")?
+    );
+    assert_eq!(
+        Tag::try_from(ArrayString::try_from_str("é")?).err(),
+        Some(Error::Invalid { rule: "ascii" })
+    );
+    Ok(())
+}
@@ -1,6 +1,12 @@
 use arraystring::prelude::*;
-use std::panic::{catch_unwind, AssertUnwindSafe, RefUnwindSafe};
+#[cfg(not(feature = "no-panic"))]
+use proptest::prelude::*;
+#[cfg(not(feature = "no-panic"))]
+use proptest::test_runner::{Config, TestRunner};
+#[cfg(not(feature = "no-panic"))]
+use std::cmp::Ordering;
 use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe, RefUnwindSafe};
 
 type TestString = ArrayString<255>;
 
@@ -11,6 +17,13 @@ where
     catch_unwind(AssertUnwindSafe(func)).map_err(|_| ())
 }
 
+/// Generated inputs fit twice in `TestString`, so concatenations can be compared too
+#[cfg(not(feature = "no-panic"))]
+type Input = ArrayString<127>;
+
+/// Proptest hands `no_panic` functions closures and writers that may panic,
+/// so that build checks a fixed set of strings instead
+#[cfg(feature = "no-panic")]
 static STRINGS: [&str; 8] = [
     "🤔🤔🤔🤔🤔🤔🤔",
     "ABCDEFGHIJKLMNOPQRSASHUDAHSDIUASH         ",
@@ -22,21 +35,26 @@ static STRINGS: [&str; 8] = [
     "        899saH(8hadhaiuhsidnkandu",
 ];
 
+#[cfg(not(feature = "no-panic"))]
+fn config() -> Config {
+    Config::with_cases(if cfg!(miri) { 4 } else { 256 })
+}
+
 #[test]
 fn try_from_str() {
-    assert(String::from, TestString::try_from_str);
+    assert(str::to_owned, |s| TestString::try_from_str(s));
 }
 
 #[test]
 fn from_str_truncate() {
-    assert(String::from, TestString::from_str_truncate);
+    assert(str::to_owned, |s| TestString::from_str_truncate(s));
 }
 
 #[test]
 fn try_from_chars() {
     assert(
         |s| String::from_iter(s.chars()),
-        |s| TestString::try_from_chars(s.chars()),
+        |s| TestString::try_from_chars(s.chars()).map_err(|err| err.error()),
     );
 }
 
@@ -52,7 +70,7 @@ fn from_chars() {
 fn try_from_iter() {
     assert(
         |s| String::from_iter(vec![s]),
-        |s| TestString::try_from_iterator(vec![s]),
+        |s| TestString::try_from_iterator(vec![s]).map_err(|err| err.error()),
     );
 }
 
@@ -69,7 +87,7 @@ fn try_from_utf16() {
     let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
     assert(
         |s| String::from_utf16(&utf16(s)),
-        |s| TestString::try_from_utf16(&utf16(s)),
+        |s| TestString::try_from_utf16(utf16(s)),
     );
 }
 
@@ -78,7 +96,7 @@ fn from_utf16() {
     let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
     assert(
         |s| String::from_utf16(&utf16(s)),
-        |s| TestString::from_utf16_truncate(&utf16(s)),
+        |s| TestString::from_utf16_truncate(utf16(s)),
     );
 }
 
@@ -87,7 +105,7 @@ fn from_utf16_lossy() {
     let utf16 = |s: &str| s.encode_utf16().collect::<Vec<_>>();
     assert(
         |s| String::from_utf16_lossy(&utf16(s)),
-        |s| TestString::from_utf16_lossy_truncate(&utf16(s)),
+        |s| TestString::from_utf16_lossy_truncate(utf16(s)),
     );
 }
 
@@ -141,7 +159,7 @@ fn try_push_str() {
         },
         |s| {
             let mut ms = TestString::try_from_str(s).unwrap();
-            ms.try_push_str(s).map(|()| ms)
+            ms.try_push_str(s).map(|()| ms).map_err(|err| err.error())
         },
     );
 }
@@ -163,6 +181,7 @@ fn push_str() {
 }
 
 #[test]
+#[cfg(not(feature = "strict"))]
 fn add_str() {
     assert(
         |s| String::from(s) + s,
@@ -292,7 +311,9 @@ fn try_insert_str() {
         },
         |s| {
             let mut ms = TestString::try_from_str(s).unwrap();
-            ms.try_insert_str(2, s).map(|()| ms)
+            ms.try_insert_str(2, s)
+                .map(|()| ms)
+                .map_err(|err| err.error())
         },
     );
 }
@@ -416,6 +437,51 @@ fn new() {
     assert_eq!(String::new().as_str(), TestString::new().as_str());
 }
 
+#[cfg(not(feature = "no-panic"))]
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn try_push_str_at_capacity(a: TestString, b: Input) {
+        let mut ms = a;
+        let result = ms.try_push_str(b.as_str());
+        prop_assert_eq!(result.is_err(), a.len() + b.len() > TestString::capacity());
+        if result.is_err() {
+            prop_assert_eq!(ms, a);
+        } else {
+            prop_assert_eq!(ms.as_str(), format!("{a}{b}"));
+        }
+    }
+
+    #[test]
+    fn push_str_truncate_at_capacity(a: TestString, b: Input) {
+        let mut st = format!("{a}{b}");
+        let mut end = st.len().min(TestString::capacity());
+        while !st.is_char_boundary(end) {
+            end -= 1;
+        }
+        st.truncate(end);
+
+        let mut ms = a;
+        ms.push_str_truncate(b.as_str());
+        prop_assert_eq!(ms.as_str(), st);
+    }
+
+    #[test]
+    fn eq_cmp(a: Input, b: Input) {
+        prop_assert_eq!(a == b, a.as_str() == b.as_str());
+        prop_assert_eq!(a.cmp(&b), a.as_str().cmp(b.as_str()));
+        prop_assert_eq!(a.cmp(&a), Ordering::Equal);
+    }
+
+    #[test]
+    fn try_push_at_capacity(a: TestString, ch: char) {
+        let mut ms = a;
+        let result = ms.try_push(ch);
+        prop_assert_eq!(result.is_err(), a.len() + ch.len_utf8() > TestString::capacity());
+    }
+}
+
 // Internal hackery to make the function `assert` possible
 
 trait Normalize<EQ: PartialEq> {
@@ -440,7 +506,7 @@ impl Normalize<Result<String, ()>> for String {
     }
 }
 
-impl<'a> Normalize<Result<String, ()>> for &'a str {
+impl Normalize<Result<String, ()>> for &str {
     fn normalize(&self) -> Result<String, ()> {
         Ok(self.to_string())
     }
@@ -491,14 +557,24 @@ where
     Q: PartialEq + Debug,
     T: Normalize<Q>,
     U: Normalize<Q>,
-    F: Fn(&'static str) -> T + RefUnwindSafe,
-    G: Fn(&'static str) -> U + RefUnwindSafe,
+    F: Fn(&str) -> T + RefUnwindSafe,
+    G: Fn(&str) -> U + RefUnwindSafe,
 {
     #[cfg(not(miri))]
     let _ = env_logger::try_init();
+    #[cfg(feature = "no-panic")]
     for string in STRINGS.iter() {
         let f = f(string).normalize();
         let g = g(string).normalize();
         assert_eq!(f, g);
     }
+    #[cfg(not(feature = "no-panic"))]
+    TestRunner::new(config())
+        .run(&any::<Input>(), |input| {
+            let f = f(input.as_str()).normalize();
+            let g = g(input.as_str()).normalize();
+            prop_assert_eq!(f, g);
+            Ok(())
+        })
+        .unwrap();
 }